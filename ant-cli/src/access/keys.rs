@@ -7,9 +7,12 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::wallet::load_wallet_private_key;
+use autonomi::client::keyring::{
+    GRAPH_ENTRIES_PATH, POINTERS_PATH, REGISTERS_PATH, SCRATCHPADS_PATH,
+};
 use autonomi::client::register::SecretKey as RegisterSecretKey;
 use autonomi::client::vault::VaultSecretKey;
-use autonomi::{Network, Wallet};
+use autonomi::{Client, Keyring, Network, SecretKey, Wallet};
 use color_eyre::eyre::{eyre, Context, Result};
 use color_eyre::Section;
 use std::env;
@@ -18,8 +21,20 @@ use std::path::PathBuf;
 
 const SECRET_KEY_ENV: &str = "SECRET_KEY";
 const REGISTER_SIGNING_KEY_ENV: &str = "REGISTER_SIGNING_KEY";
+const POINTER_SIGNING_KEY_ENV: &str = "POINTER_SIGNING_KEY";
+const SCRATCHPAD_SIGNING_KEY_ENV: &str = "SCRATCHPAD_SIGNING_KEY";
+const GRAPH_SIGNING_KEY_ENV: &str = "GRAPH_SIGNING_KEY";
 
 const REGISTER_SIGNING_KEY_FILE: &str = "register_signing_key";
+const POINTER_SIGNING_KEY_FILE: &str = "pointer_signing_key";
+const SCRATCHPAD_SIGNING_KEY_FILE: &str = "scratchpad_signing_key";
+const GRAPH_SIGNING_KEY_FILE: &str = "graph_signing_key";
+
+const KEYRING_ROOT_KEY_ENV: &str = "KEYRING_ROOT_KEY";
+const KEYRING_ROOT_KEY_FILE: &str = "keyring_root_key";
+
+/// The app name under which the CLI derives its scratchpad keys from the keyring
+const KEYRING_SCRATCHPAD_APP: &str = "ant";
 
 /// EVM wallet
 pub fn load_evm_wallet_from_env(evm_network: &Network) -> Result<Wallet> {
@@ -43,52 +58,274 @@ pub fn get_vault_secret_key() -> Result<VaultSecretKey> {
         .wrap_err("Failed to derive vault secret key from EVM secret key")
 }
 
+/// The kinds of signing keys the CLI keeps in its data dir
+/// Each kind is a main key from which the keys of named objects are derived
+#[derive(Debug, Clone, Copy)]
+pub enum SigningKeyKind {
+    Register,
+    Pointer,
+    Scratchpad,
+    GraphEntry,
+}
+
+impl SigningKeyKind {
+    fn env_var(&self) -> &'static str {
+        match self {
+            SigningKeyKind::Register => REGISTER_SIGNING_KEY_ENV,
+            SigningKeyKind::Pointer => POINTER_SIGNING_KEY_ENV,
+            SigningKeyKind::Scratchpad => SCRATCHPAD_SIGNING_KEY_ENV,
+            SigningKeyKind::GraphEntry => GRAPH_SIGNING_KEY_ENV,
+        }
+    }
+
+    fn file_name(&self) -> &'static str {
+        match self {
+            SigningKeyKind::Register => REGISTER_SIGNING_KEY_FILE,
+            SigningKeyKind::Pointer => POINTER_SIGNING_KEY_FILE,
+            SigningKeyKind::Scratchpad => SCRATCHPAD_SIGNING_KEY_FILE,
+            SigningKeyKind::GraphEntry => GRAPH_SIGNING_KEY_FILE,
+        }
+    }
+
+    /// The path of the keyring key this kind of signing key is derived from
+    fn keyring_path(&self) -> &'static [&'static str] {
+        match self {
+            SigningKeyKind::Register => &[REGISTERS_PATH],
+            SigningKeyKind::Pointer => &[POINTERS_PATH],
+            SigningKeyKind::Scratchpad => &[SCRATCHPADS_PATH, KEYRING_SCRATCHPAD_APP],
+            SigningKeyKind::GraphEntry => &[GRAPH_ENTRIES_PATH],
+        }
+    }
+
+    pub fn subcommand(&self) -> &'static str {
+        match self {
+            SigningKeyKind::Register => "register",
+            SigningKeyKind::Pointer => "pointer",
+            SigningKeyKind::Scratchpad => "scratchpad",
+            SigningKeyKind::GraphEntry => "graph",
+        }
+    }
+}
+
+impl std::fmt::Display for SigningKeyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SigningKeyKind::Register => write!(f, "register"),
+            SigningKeyKind::Pointer => write!(f, "pointer"),
+            SigningKeyKind::Scratchpad => write!(f, "scratchpad"),
+            SigningKeyKind::GraphEntry => write!(f, "graph entry"),
+        }
+    }
+}
+
 pub fn create_register_signing_key_file(key: RegisterSecretKey) -> Result<PathBuf> {
+    create_signing_key_file(SigningKeyKind::Register, key)
+}
+
+pub fn create_signing_key_file(kind: SigningKeyKind, key: SecretKey) -> Result<PathBuf> {
     let dir = super::data_dir::get_client_data_dir_path()
         .wrap_err("Could not access directory to write key to")?;
-    let file_path = dir.join(REGISTER_SIGNING_KEY_FILE);
+    let file_path = dir.join(kind.file_name());
     fs::write(&file_path, key.to_hex()).wrap_err("Could not write key to file")?;
     Ok(file_path)
 }
 
-fn parse_register_signing_key(key_hex: &str) -> Result<RegisterSecretKey> {
-    RegisterSecretKey::from_hex(key_hex)
-        .wrap_err("Failed to parse register signing key")
+fn parse_signing_key(kind: SigningKeyKind, key_hex: &str) -> Result<SecretKey> {
+    SecretKey::from_hex(key_hex)
+        .wrap_err(format!("Failed to parse {kind} signing key"))
         .with_suggestion(|| {
-            "the register signing key should be a hex encoded string of a bls secret key"
+            format!("the {kind} signing key should be a hex encoded string of a bls secret key")
         })
         .with_suggestion(|| {
-            "you can generate a new secret key with the `register generate-key` subcommand"
+            format!(
+                "you can generate a new secret key with the `{} generate-key` subcommand",
+                kind.subcommand()
+            )
         })
 }
 
 pub fn get_register_signing_key() -> Result<RegisterSecretKey> {
+    get_signing_key(SigningKeyKind::Register)
+}
+
+pub fn get_signing_key(kind: SigningKeyKind) -> Result<SecretKey> {
+    let env_var = kind.env_var();
+    let subcommand = kind.subcommand();
+
     // try env var first
-    let why_env_failed = match env::var(REGISTER_SIGNING_KEY_ENV) {
-        Ok(key) => return parse_register_signing_key(&key),
+    let why_env_failed = match env::var(env_var) {
+        Ok(key) => return parse_signing_key(kind, &key),
         Err(e) => e,
     };
 
     // try from data dir
     let dir = super::data_dir::get_client_data_dir_path()
-        .wrap_err(format!("Failed to obtain register signing key from env var: {why_env_failed}, reading from disk also failed as couldn't access data dir"))
-        .with_suggestion(|| format!("make sure you've provided the {REGISTER_SIGNING_KEY_ENV} env var"))
-        .with_suggestion(|| "you can generate a new secret key with the `register generate-key` subcommand")?;
+        .wrap_err(format!("Failed to obtain {kind} signing key from env var: {why_env_failed}, reading from disk also failed as couldn't access data dir"))
+        .with_suggestion(|| format!("make sure you've provided the {env_var} env var"))
+        .with_suggestion(|| format!("you can generate a new secret key with the `{subcommand} generate-key` subcommand"))?;
 
-    // load the key from file
-    let key_path = dir.join(REGISTER_SIGNING_KEY_FILE);
+    // load the key from file, falling back to the keyring
+    let key_path = dir.join(kind.file_name());
+    if !key_path.exists() {
+        if let Some(keyring) = get_keyring()? {
+            info!("Deriving {kind} signing key from the keyring");
+            return Ok(keyring.derive(kind.keyring_path()));
+        }
+    }
     let key_hex = fs::read_to_string(&key_path)
         .wrap_err("Failed to read secret key from file")
-        .with_suggestion(|| format!("make sure you've provided the {REGISTER_SIGNING_KEY_ENV} env var or have the key in a file at {key_path:?}"))
-        .with_suggestion(|| "you can generate a new secret key with the `register generate-key` subcommand")?;
+        .with_suggestion(|| format!("make sure you've provided the {env_var} env var or have the key in a file at {key_path:?}"))
+        .with_suggestion(|| format!("you can generate a new secret key with the `{subcommand} generate-key` subcommand"))
+        .with_suggestion(|| "or create a keyring to derive all your keys from with the `keyring create` subcommand")?;
 
     // parse the key
-    parse_register_signing_key(&key_hex)
+    parse_signing_key(kind, &key_hex)
 }
 
 pub fn get_register_signing_key_path() -> Result<PathBuf> {
+    get_signing_key_path(SigningKeyKind::Register)
+}
+
+pub fn get_signing_key_path(kind: SigningKeyKind) -> Result<PathBuf> {
     let dir = super::data_dir::get_client_data_dir_path()
-        .wrap_err("Could not access directory for register signing key")?;
-    let file_path = dir.join(REGISTER_SIGNING_KEY_FILE);
+        .wrap_err(format!("Could not access directory for {kind} signing key"))?;
+    let file_path = dir.join(kind.file_name());
     Ok(file_path)
 }
+
+/// Generate a new signing key of the given kind and write it to the data dir
+/// Refuses to overwrite an existing key unless `overwrite` is set
+pub fn generate_signing_key(kind: SigningKeyKind, overwrite: bool) -> Result<PathBuf> {
+    let path = store_signing_key(kind, SecretKey::random(), overwrite)?;
+    info!("Created new {kind} key at: {path:?}");
+    Ok(path)
+}
+
+/// Write a signing key of the given kind to the data dir
+/// Refuses to overwrite an existing key unless `overwrite` is set
+pub fn store_signing_key(kind: SigningKeyKind, key: SecretKey, overwrite: bool) -> Result<PathBuf> {
+    let key_path = get_signing_key_path(kind)?;
+    if key_path.exists() && !overwrite {
+        error!("{kind} key already exists at: {key_path:?}");
+        return Err(eyre!("{kind} key already exists at: {}", key_path.display()))
+            .with_suggestion(|| "if you want to overwrite the existing key, run the command with the --overwrite flag")
+            .with_warning(|| format!("overwriting the existing key might result in loss of access to any existing {kind}s created using that key"));
+    }
+
+    create_signing_key_file(kind, key).wrap_err(format!("Failed to write {kind} key"))
+}
+
+/// Derive the key of a named object from a main signing key
+/// This uses the same derivation as register keys, so names map to keys deterministically
+pub fn key_from_name(main_key: &SecretKey, name: &str) -> SecretKey {
+    Client::register_key_from_name(main_key, name)
+}
+
+/// The keys derived from the keyring for each kind of signing key, used when no key of that kind is set
+pub fn keyring_signing_keys(keyring: &Keyring) -> Vec<(SigningKeyKind, SecretKey)> {
+    [
+        SigningKeyKind::Register,
+        SigningKeyKind::Pointer,
+        SigningKeyKind::Scratchpad,
+        SigningKeyKind::GraphEntry,
+    ]
+    .into_iter()
+    .map(|kind| (kind, keyring.derive(kind.keyring_path())))
+    .collect()
+}
+
+pub fn get_keyring_path() -> Result<PathBuf> {
+    let dir = super::data_dir::get_client_data_dir_path()
+        .wrap_err("Could not access directory for the keyring")?;
+    Ok(dir.join(KEYRING_ROOT_KEY_FILE))
+}
+
+/// Load the keyring from the env var or from the data dir, if there is one
+pub fn get_keyring() -> Result<Option<Keyring>> {
+    let key_hex = match env::var(KEYRING_ROOT_KEY_ENV) {
+        Ok(key) => key,
+        Err(_) => {
+            let key_path = get_keyring_path()?;
+            if !key_path.exists() {
+                return Ok(None);
+            }
+            fs::read_to_string(&key_path).wrap_err(format!(
+                "Failed to read keyring root key from file at {key_path:?}"
+            ))?
+        }
+    };
+    let root = SecretKey::from_hex(key_hex.trim())
+        .wrap_err("Failed to parse keyring root key")
+        .with_suggestion(|| {
+            "the keyring root key should be a hex encoded string of a bls secret key"
+        })
+        .with_suggestion(|| {
+            "you can recover it from your mnemonic with the `keyring recover` subcommand"
+        })?;
+    Ok(Some(Keyring::new(root)))
+}
+
+/// Write the keyring root key to the data dir
+/// Refuses to overwrite an existing keyring unless `overwrite` is set
+pub fn store_keyring(keyring: &Keyring, overwrite: bool) -> Result<PathBuf> {
+    let key_path = get_keyring_path()?;
+    if key_path.exists() && !overwrite {
+        error!("Keyring already exists at: {key_path:?}");
+        return Err(eyre!("Keyring already exists at: {}", key_path.display()))
+            .with_suggestion(|| "if you want to overwrite the existing keyring, run the command with the --overwrite flag")
+            .with_warning(|| "overwriting the existing keyring will result in loss of access to any data derived from it, unless you have its mnemonic");
+    }
+    fs::write(&key_path, keyring.root_secret_key().to_hex())
+        .wrap_err("Could not write keyring root key to file")?;
+    info!("Stored keyring at: {key_path:?}");
+    Ok(key_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_from_name() {
+        let main_key = SecretKey::random();
+        // the same name always gives the same key, which is the one of a register with that name
+        assert_eq!(
+            key_from_name(&main_key, "name"),
+            key_from_name(&main_key, "name")
+        );
+        assert_eq!(
+            key_from_name(&main_key, "name"),
+            Client::register_key_from_name(&main_key, "name")
+        );
+        assert_ne!(
+            key_from_name(&main_key, "name"),
+            key_from_name(&main_key, "other name")
+        );
+        assert_ne!(
+            key_from_name(&main_key, "name"),
+            key_from_name(&SecretKey::random(), "name")
+        );
+    }
+
+    #[test]
+    fn test_signing_key_kinds_are_stored_apart() {
+        let kinds = [
+            SigningKeyKind::Register,
+            SigningKeyKind::Pointer,
+            SigningKeyKind::Scratchpad,
+            SigningKeyKind::GraphEntry,
+        ];
+        for (i, kind) in kinds.iter().enumerate() {
+            for other in &kinds[i + 1..] {
+                assert_ne!(kind.env_var(), other.env_var());
+                assert_ne!(kind.file_name(), other.file_name());
+                assert_ne!(kind.subcommand(), other.subcommand());
+            }
+        }
+
+        let key = SecretKey::random();
+        let parsed = parse_signing_key(SigningKeyKind::Pointer, &key.to_hex());
+        assert_eq!(parsed.ok(), Some(key));
+        assert!(parse_signing_key(SigningKeyKind::Pointer, "not a key").is_err());
+    }
+}
@@ -7,40 +7,52 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use ant_bootstrap::{PeersArgs, ANT_PEERS_ENV};
-use autonomi::Multiaddr;
+use autonomi::{Multiaddr, PrivateNetwork, TransportConfig};
 use color_eyre::eyre::Context;
 use color_eyre::Result;
 use color_eyre::Section;
 
-pub enum NetworkPeers {
-    Local(Vec<Multiaddr>),
-    Public(Vec<Multiaddr>),
+pub struct NetworkPeers {
+    addrs: Vec<Multiaddr>,
+    local: bool,
+    transports: TransportConfig,
+    private_network: PrivateNetwork,
 }
 
 impl NetworkPeers {
     pub fn peers(&self) -> &Vec<Multiaddr> {
-        match self {
-            NetworkPeers::Local(addrs) => addrs,
-            NetworkPeers::Public(addrs) => addrs,
-        }
+        &self.addrs
     }
 
     pub fn is_local(&self) -> bool {
-        matches!(self, NetworkPeers::Local(_))
+        self.local
+    }
+
+    /// The transports to enable alongside QUIC
+    pub fn transports(&self) -> TransportConfig {
+        self.transports
+    }
+
+    /// The restrictions of the network, if private
+    pub fn private_network(&self) -> &PrivateNetwork {
+        &self.private_network
     }
 }
 
-pub async fn get_peers(peers: PeersArgs) -> Result<NetworkPeers> {
+pub async fn get_peers(
+    peers: PeersArgs,
+    transports: TransportConfig,
+    private_network: PrivateNetwork,
+) -> Result<NetworkPeers> {
     let addrs = peers.get_addrs(None, Some(100)).await
         .wrap_err("Please provide valid Network peers to connect to")
         .with_suggestion(|| format!("make sure you've provided network peers using the --peers option or the {ANT_PEERS_ENV} env var"))
         .with_suggestion(|| "a peer address looks like this: /ip4/42.42.42.42/udp/4242/quic-v1/p2p/B64nodePeerIDvdjb3FAJF4ks3moreBase64CharsHere")?;
 
-    let net = if peers.local {
-        NetworkPeers::Local(addrs)
-    } else {
-        NetworkPeers::Public(addrs)
-    };
-
-    Ok(net)
+    Ok(NetworkPeers {
+        addrs,
+        local: peers.local,
+        transports,
+        private_network,
+    })
 }
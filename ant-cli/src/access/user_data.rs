@@ -6,14 +6,16 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use autonomi::client::{
     address::{addr_to_str, str_to_addr},
     files::archive_private::PrivateArchiveAccess,
     files::archive_public::ArchiveAddr,
     register::RegisterAddress,
-    vault::UserData,
+    vault::{EntryTimestamps, UserData, UserDataChange, UserDataEntry},
 };
 use color_eyre::eyre::Result;
 
@@ -27,19 +29,298 @@ struct PrivateFileArchive {
     secret_access: String,
 }
 
+/// What is known locally about the last vault sync, used to merge local user data with the vault
+#[derive(Serialize, Deserialize, Default)]
+struct SyncState {
+    /// Entries present locally right after the last sync, those now missing were removed locally
+    synced: SyncedEntries,
+    /// Tombstones of removed entries, with the time they were removed at
+    removed: RemovedEntries,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct SyncedEntries {
+    file_archives: HashSet<String>,
+    private_file_archives: HashSet<String>,
+    registers: HashSet<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct RemovedEntries {
+    file_archives: HashMap<String, u64>,
+    private_file_archives: HashMap<String, u64>,
+    registers: HashMap<String, u64>,
+}
+
+/// Get the local user data, along with the times entries were last changed and the tombstones of removed entries
 pub fn get_local_user_data() -> Result<UserData> {
     let file_archives = get_local_public_file_archives()?;
     let private_file_archives = get_local_private_file_archives()?;
     let registers = get_local_registers()?;
 
+    let mut modified = EntryTimestamps::default();
+    for addr in file_archives.keys() {
+        if let Some(time) = modified_time(&public_file_archive_path(*addr)?) {
+            modified.file_archives.insert(*addr, time);
+        }
+    }
+    for access in private_file_archives.keys() {
+        if let Some(time) = modified_time(&private_file_archive_path(access)?) {
+            modified.private_file_archives.insert(access.clone(), time);
+        }
+    }
+    for addr in registers.keys() {
+        if let Some(time) = modified_time(&register_path(addr)?) {
+            modified.register_addresses.insert(addr.clone(), time);
+        }
+    }
+
+    let state = read_sync_state()?;
+    let mut removed = EntryTimestamps::default();
+    for (hex, time) in state.removed.file_archives {
+        removed.file_archives.insert(str_to_addr(&hex)?, time);
+    }
+    for (hex, time) in state.removed.private_file_archives {
+        removed
+            .private_file_archives
+            .insert(PrivateArchiveAccess::from_hex(&hex)?, time);
+    }
+    for (hex, time) in state.removed.registers {
+        removed
+            .register_addresses
+            .insert(RegisterAddress::from_hex(&hex)?, time);
+    }
+
+    // entries that were there after the last sync but are now gone were removed locally
+    let now = now();
+    for hex in state.synced.file_archives {
+        let addr = str_to_addr(&hex)?;
+        if !file_archives.contains_key(&addr) {
+            removed.file_archives.entry(addr).or_insert(now);
+        }
+    }
+    for hex in state.synced.private_file_archives {
+        let access = PrivateArchiveAccess::from_hex(&hex)?;
+        if !private_file_archives.contains_key(&access) {
+            removed.private_file_archives.entry(access).or_insert(now);
+        }
+    }
+    for hex in state.synced.registers {
+        let addr = RegisterAddress::from_hex(&hex)?;
+        if !registers.contains_key(&addr) {
+            removed.register_addresses.entry(addr).or_insert(now);
+        }
+    }
+
+    // the other sections are not stored as individual files, they are kept as they were last synced
+    let other = read_other_user_data()?;
+    modified.pointers = other.modified.pointers;
+    modified.scratchpads = other.modified.scratchpads;
+    modified.graph_entries = other.modified.graph_entries;
+    modified.wallet_addresses = other.modified.wallet_addresses;
+    removed.pointers = other.removed.pointers;
+    removed.scratchpads = other.removed.scratchpads;
+    removed.graph_entries = other.removed.graph_entries;
+    removed.wallet_addresses = other.removed.wallet_addresses;
+
     let user_data = UserData {
         file_archives,
         private_file_archives,
         register_addresses: registers,
+        modified,
+        removed,
+        version: other.version,
+        pointers: other.pointers,
+        scratchpads: other.scratchpads,
+        graph_entries: other.graph_entries,
+        wallet_addresses: other.wallet_addresses,
+        app_data: other.app_data,
     };
     Ok(user_data)
 }
 
+/// Remember the user data that was just synced with the vault, so that later local removals can be detected
+/// The sections of the user data that are not stored as individual files are saved as they are
+pub fn write_sync_state(user_data: &UserData) -> Result<()> {
+    let other = UserData {
+        modified: EntryTimestamps {
+            pointers: user_data.modified.pointers.clone(),
+            scratchpads: user_data.modified.scratchpads.clone(),
+            graph_entries: user_data.modified.graph_entries.clone(),
+            wallet_addresses: user_data.modified.wallet_addresses.clone(),
+            ..Default::default()
+        },
+        removed: EntryTimestamps {
+            pointers: user_data.removed.pointers.clone(),
+            scratchpads: user_data.removed.scratchpads.clone(),
+            graph_entries: user_data.removed.graph_entries.clone(),
+            wallet_addresses: user_data.removed.wallet_addresses.clone(),
+            ..Default::default()
+        },
+        pointers: user_data.pointers.clone(),
+        scratchpads: user_data.scratchpads.clone(),
+        graph_entries: user_data.graph_entries.clone(),
+        wallet_addresses: user_data.wallet_addresses.clone(),
+        app_data: user_data.app_data.clone(),
+        ..Default::default()
+    };
+    std::fs::write(other_user_data_path()?, other.to_bytes()?)?;
+
+    let state = SyncState {
+        synced: SyncedEntries {
+            file_archives: user_data
+                .file_archives
+                .keys()
+                .map(|addr| addr_to_str(*addr))
+                .collect(),
+            private_file_archives: user_data
+                .private_file_archives
+                .keys()
+                .map(|access| access.to_hex())
+                .collect(),
+            registers: user_data
+                .register_addresses
+                .keys()
+                .map(|addr| addr.to_hex())
+                .collect(),
+        },
+        removed: RemovedEntries {
+            file_archives: user_data
+                .removed
+                .file_archives
+                .iter()
+                .map(|(addr, time)| (addr_to_str(*addr), *time))
+                .collect(),
+            private_file_archives: user_data
+                .removed
+                .private_file_archives
+                .iter()
+                .map(|(access, time)| (access.to_hex(), *time))
+                .collect(),
+            registers: user_data
+                .removed
+                .register_addresses
+                .iter()
+                .map(|(addr, time)| (addr.to_hex(), *time))
+                .collect(),
+        },
+    };
+    std::fs::write(sync_state_path()?, serde_json::to_string(&state)?)?;
+    Ok(())
+}
+
+/// Apply the changes of a vault merge to the local user data
+pub fn apply_local_changes(changes: &[UserDataChange]) -> Result<()> {
+    for change in changes {
+        match change {
+            UserDataChange::Added { entry, name }
+            | UserDataChange::Renamed {
+                entry, to: name, ..
+            } => match entry {
+                UserDataEntry::FileArchive(addr) => {
+                    write_local_public_file_archive(addr_to_str(*addr), name)?
+                }
+                UserDataEntry::PrivateFileArchive(access) => {
+                    write_local_private_file_archive(access.to_hex(), access.address(), name)?
+                }
+                UserDataEntry::Register(addr) => write_local_register(addr, name)?,
+                // saved along with the sync state
+                UserDataEntry::Pointer(_)
+                | UserDataEntry::Scratchpad(_)
+                | UserDataEntry::GraphEntry(_)
+                | UserDataEntry::WalletAddress(_) => {}
+            },
+            UserDataChange::Removed { entry, .. } => {
+                let path = match entry {
+                    UserDataEntry::FileArchive(addr) => public_file_archive_path(*addr)?,
+                    UserDataEntry::PrivateFileArchive(access) => private_file_archive_path(access)?,
+                    UserDataEntry::Register(addr) => register_path(addr)?,
+                    UserDataEntry::Pointer(_)
+                    | UserDataEntry::Scratchpad(_)
+                    | UserDataEntry::GraphEntry(_)
+                    | UserDataEntry::WalletAddress(_) => continue,
+                };
+                if path.exists() {
+                    std::fs::remove_file(path)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_sync_state() -> Result<SyncState> {
+    let path = sync_state_path()?;
+    if !path.exists() {
+        return Ok(SyncState::default());
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+fn read_other_user_data() -> Result<UserData> {
+    let path = other_user_data_path()?;
+    if !path.exists() {
+        return Ok(UserData::default());
+    }
+    let bytes = std::fs::read(path)?;
+    Ok(UserData::from_bytes(bytes.into())?)
+}
+
+fn other_user_data_path() -> Result<PathBuf> {
+    let data_dir = get_client_data_dir_path()?;
+    let user_data_path = data_dir.join("user_data");
+    std::fs::create_dir_all(&user_data_path)?;
+    Ok(user_data_path.join("other_user_data"))
+}
+
+fn sync_state_path() -> Result<PathBuf> {
+    let data_dir = get_client_data_dir_path()?;
+    let user_data_path = data_dir.join("user_data");
+    std::fs::create_dir_all(&user_data_path)?;
+    Ok(user_data_path.join("vault_sync_state.json"))
+}
+
+fn public_file_archive_path(archive: ArchiveAddr) -> Result<PathBuf> {
+    let data_dir = get_client_data_dir_path()?;
+    Ok(data_dir
+        .join("user_data")
+        .join("file_archives")
+        .join(addr_to_str(archive)))
+}
+
+fn private_file_archive_path(archive: &PrivateArchiveAccess) -> Result<PathBuf> {
+    let data_dir = get_client_data_dir_path()?;
+    Ok(data_dir
+        .join("user_data")
+        .join("private_file_archives")
+        .join(archive.address()))
+}
+
+fn register_path(register: &RegisterAddress) -> Result<PathBuf> {
+    let data_dir = get_client_data_dir_path()?;
+    Ok(data_dir
+        .join("user_data")
+        .join("registers")
+        .join(register.to_hex()))
+}
+
+/// Last modification time of a file in seconds since the UNIX epoch
+fn modified_time(path: &Path) -> Option<u64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    modified
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
 pub fn get_local_private_file_archives() -> Result<HashMap<PrivateArchiveAccess, String>> {
     let data_dir = get_client_data_dir_path()?;
     let user_data_path = data_dir.join("user_data");
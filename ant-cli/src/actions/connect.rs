@@ -6,13 +6,15 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+use crate::access::data_dir::get_client_data_dir_path;
 use crate::network::NetworkPeers;
+use ant_bootstrap::get_network_version;
 use autonomi::client::config::ClientOperatingStrategy;
 use autonomi::{get_evm_network, Client, ClientConfig};
 use color_eyre::eyre::bail;
 use color_eyre::eyre::Result;
 use indicatif::ProgressBar;
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 pub async fn connect_to_network(peers: NetworkPeers) -> Result<Client> {
     connect_to_network_with_config(peers, Default::default()).await
@@ -22,6 +24,12 @@ pub async fn connect_to_network_with_config(
     peers: NetworkPeers,
     operation_config: ClientOperatingStrategy,
 ) -> Result<Client> {
+    if let Some(identity) = &peers.private_network().identity {
+        let peer_id = identity.public().to_peer_id();
+        info!("Connecting with the peer id {peer_id}");
+        println!("Connecting with the peer id {peer_id}");
+    }
+
     let progress_bar = ProgressBar::new_spinner();
     progress_bar.enable_steady_tick(Duration::from_millis(120));
     progress_bar.set_message("Connecting to The Autonomi Network...");
@@ -45,6 +53,9 @@ pub async fn connect_to_network_with_config(
         peers: peers_opt,
         evm_network,
         strategy: operation_config,
+        transports: peers.transports(),
+        routing_table_snapshot: routing_table_snapshot_path(local),
+        private_network: peers.private_network().clone(),
     };
 
     let res = Client::init_with_config(config).await;
@@ -62,3 +73,18 @@ pub async fn connect_to_network_with_config(
         }
     }
 }
+
+/// The routing table is persisted between the commands, so that they connect faster.
+/// Local networks are not persisted, as they are usually recreated between the runs.
+fn routing_table_snapshot_path(local: bool) -> Option<PathBuf> {
+    if local {
+        return None;
+    }
+    match get_client_data_dir_path() {
+        Ok(dir) => Some(dir.join(format!("routing_table_{}", get_network_version()))),
+        Err(err) => {
+            warn!("Failed to obtain the routing table snapshot path: {err}");
+            None
+        }
+    }
+}
@@ -20,15 +20,15 @@ use color_eyre::{
 };
 use std::path::PathBuf;
 
-pub async fn download(addr: &str, dest_path: &str, client: &Client) -> Result<()> {
+pub async fn download(addr: &str, dest_path: &str, client: &Client, verify: bool) -> Result<()> {
     let public_address = str_to_addr(addr).ok();
     let private_address = crate::user_data::get_local_private_archive_access(addr)
         .inspect_err(|e| error!("Failed to get private archive access: {e}"))
         .ok();
 
     match (public_address, private_address) {
-        (Some(public_address), _) => download_public(addr, public_address, dest_path, client).await,
-        (_, Some(private_address)) => download_private(addr, private_address, dest_path, client).await,
+        (Some(public_address), _) => download_public(addr, public_address, dest_path, client, verify).await,
+        (_, Some(private_address)) => download_private(addr, private_address, dest_path, client, verify).await,
         _ => Err(eyre!("Failed to parse data address {addr}"))
             .with_suggestion(|| "Public addresses look like this: 0037cfa13eae4393841cbc00c3a33cade0f98b8c1f20826e5c51f8269e7b09d7")
             .with_suggestion(|| "Private addresses look like this: 1358645341480028172")
@@ -41,6 +41,7 @@ async fn download_private(
     private_address: PrivateArchiveAccess,
     dest_path: &str,
     client: &Client,
+    verify: bool,
 ) -> Result<()> {
     let archive = client
         .archive_get(&private_address)
@@ -49,7 +50,7 @@ async fn download_private(
 
     let progress_bar = get_progress_bar(archive.iter().count() as u64)?;
     let mut all_errs = vec![];
-    for (path, access, _meta) in archive.iter() {
+    for (path, access, meta) in archive.iter() {
         progress_bar.println(format!("Fetching file: {path:?}..."));
         let bytes = match client.data_get(access).await {
             Ok(bytes) => bytes,
@@ -59,6 +60,11 @@ async fn download_private(
                 continue;
             }
         };
+        if verify && !meta.verify_content(&bytes) {
+            let err = format!("Content of file {path:?} does not match its hash in the archive");
+            all_errs.push(err);
+            continue;
+        }
 
         let path = PathBuf::from(dest_path).join(path);
         let here = PathBuf::from(".");
@@ -87,6 +93,7 @@ async fn download_public(
     address: ArchiveAddr,
     dest_path: &str,
     client: &Client,
+    verify: bool,
 ) -> Result<()> {
     let archive = client
         .archive_get_public(&address)
@@ -95,7 +102,7 @@ async fn download_public(
 
     let progress_bar = get_progress_bar(archive.iter().count() as u64)?;
     let mut all_errs = vec![];
-    for (path, addr, _meta) in archive.iter() {
+    for (path, addr, meta) in archive.iter() {
         progress_bar.println(format!("Fetching file: {path:?}..."));
         let bytes = match client.data_get_public(addr).await {
             Ok(bytes) => bytes,
@@ -105,6 +112,11 @@ async fn download_public(
                 continue;
             }
         };
+        if verify && !meta.verify_content(&bytes) {
+            let err = format!("Content of file {path:?} does not match its hash in the archive");
+            all_errs.push(err);
+            continue;
+        }
 
         let path = PathBuf::from(dest_path).join(path);
         let here = PathBuf::from(".");
@@ -0,0 +1,339 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use autonomi::client::data::DataAddr;
+use autonomi::client::files::{
+    archive_public::ArchiveAddr, guess_mime_type, Metadata, Site, SiteError, SiteLookup, SiteRoot,
+};
+use autonomi::Client;
+use color_eyre::eyre::{Context, Result};
+use hyper::header::{self, HeaderValue};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, HeaderMap, Method, Request, Response, Server, StatusCode};
+use percent_encoding::percent_decode_str;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Archives are immutable, so files served from an archive address can be cached forever
+const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+/// Sites behind a pointer or register can change at any time, so caches must revalidate with the ETag
+const MUTABLE_CACHE_CONTROL: &str = "no-cache";
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+/// Number of site archives kept in memory, the cache is cleared when full
+const MAX_CACHED_SITES: usize = 256;
+
+const USAGE: &str = "Autonomi site gateway\n\nUsage: GET /<site-address>/<path>\nwhere <site-address> is one of: <archive-address>, pointer:<pointer-address>, register:<register-address> or name:<name>\n";
+
+/// Serve the websites stored on the Network over HTTP until the server fails.
+pub async fn run_gateway(client: Client, addr: SocketAddr) -> Result<()> {
+    let gateway = Arc::new(Gateway {
+        client,
+        sites: Mutex::new(HashMap::new()),
+    });
+    let make_service = make_service_fn(move |_conn| {
+        let gateway = Arc::clone(&gateway);
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                let gateway = Arc::clone(&gateway);
+                async move { Ok::<_, Infallible>(gateway.handle(req).await) }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)
+        .wrap_err(format!("Failed to listen on {addr}"))?
+        .serve(make_service);
+    info!("Site gateway listening on http://{}", server.local_addr());
+    println!(
+        "✅ Serving sites at: http://{}/<site-address>/",
+        server.local_addr()
+    );
+    server.await.wrap_err("Site gateway failed")
+}
+
+struct Gateway {
+    client: Client,
+    sites: Mutex<HashMap<ArchiveAddr, Arc<Site>>>,
+}
+
+impl Gateway {
+    async fn handle(&self, req: Request<Body>) -> Response<Body> {
+        let head = match *req.method() {
+            Method::GET => false,
+            Method::HEAD => true,
+            _ => {
+                let mut response = text_response(
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    "Only GET and HEAD are supported\n",
+                );
+                response
+                    .headers_mut()
+                    .insert(header::ALLOW, HeaderValue::from_static("GET, HEAD"));
+                return response;
+            }
+        };
+
+        let raw_path = req.uri().path();
+        let Ok(path) = percent_decode_str(raw_path).decode_utf8() else {
+            return text_response(StatusCode::BAD_REQUEST, "Invalid URL encoding\n");
+        };
+        let (root, site_path) = match path.trim_start_matches('/').split_once('/') {
+            Some((root, site_path)) => (root, Some(site_path)),
+            None => (path.trim_start_matches('/'), None),
+        };
+        if root.is_empty() {
+            return text_response(StatusCode::NOT_FOUND, USAGE);
+        }
+        let root: SiteRoot = match root.parse() {
+            Ok(root) => root,
+            Err(err) => return text_response(StatusCode::BAD_REQUEST, &format!("{err}\n")),
+        };
+        // the site root must end with a slash for the relative links of the index page to work
+        let Some(site_path) = site_path else {
+            return redirect(&format!("{raw_path}/"));
+        };
+
+        let site = match self.site(&root).await {
+            Ok(site) => site,
+            Err(err) => {
+                error!("Failed to get site {root}: {err:?}");
+                return text_response(StatusCode::BAD_GATEWAY, &format!("{err}\n"));
+            }
+        };
+
+        match site.lookup(site_path) {
+            SiteLookup::File { path, addr, meta } => {
+                self.serve_file(req.headers(), &root, path, addr, meta, head)
+                    .await
+            }
+            SiteLookup::Redirect => redirect(&format!("{raw_path}/")),
+            SiteLookup::NotFound => {
+                text_response(StatusCode::NOT_FOUND, &format!("Not found: {site_path}\n"))
+            }
+        }
+    }
+
+    /// Resolve the site root to its archive, archives are cached as they never change
+    async fn site(&self, root: &SiteRoot) -> Result<Arc<Site>, SiteError> {
+        let archive_addr = self.client.site_resolve(root).await?;
+        if let Some(site) = self.sites.lock().await.get(&archive_addr) {
+            return Ok(Arc::clone(site));
+        }
+
+        let archive = self.client.archive_get_public(&archive_addr).await?;
+        let site = Arc::new(Site::new(archive_addr, archive));
+        let mut sites = self.sites.lock().await;
+        if sites.len() >= MAX_CACHED_SITES {
+            sites.clear();
+        }
+        sites.insert(archive_addr, Arc::clone(&site));
+        Ok(site)
+    }
+
+    async fn serve_file(
+        &self,
+        headers: &HeaderMap,
+        root: &SiteRoot,
+        path: &Path,
+        addr: &DataAddr,
+        meta: &Metadata,
+        head: bool,
+    ) -> Response<Body> {
+        // files are content addressed, so their address is a strong validator
+        let etag = format!("\"{}\"", hex::encode(addr));
+        let cache_control = if root.is_mutable() {
+            MUTABLE_CACHE_CONTROL
+        } else {
+            IMMUTABLE_CACHE_CONTROL
+        };
+        let mut builder = Response::builder()
+            .header(header::ETAG, &etag)
+            .header(header::CACHE_CONTROL, cache_control)
+            .header(header::ACCEPT_RANGES, "bytes");
+
+        if header_str(headers, header::IF_NONE_MATCH).is_some_and(|tags| {
+            tags.split(',')
+                .any(|tag| tag.trim() == "*" || tag.trim() == etag)
+        }) {
+            return builder
+                .status(StatusCode::NOT_MODIFIED)
+                .body(Body::empty())
+                .unwrap_or_default();
+        }
+
+        if head {
+            let content_type = meta
+                .mime_type
+                .clone()
+                .or_else(|| guess_mime_type(path, &[]))
+                .unwrap_or(DEFAULT_CONTENT_TYPE.to_string());
+            return builder
+                .header(header::CONTENT_TYPE, content_type)
+                .header(header::CONTENT_LENGTH, meta.size)
+                .body(Body::empty())
+                .unwrap_or_default();
+        }
+
+        // the size in the archive is used to resolve the range, so that only the chunks covering it are fetched
+        let len = meta.size;
+        // a range only applies if the representation has not changed since the client got the ETag
+        let if_range_matches =
+            header_str(headers, header::IF_RANGE).is_none_or(|tag| tag.trim() == etag);
+        let range = header_str(headers, header::RANGE)
+            .filter(|_| if_range_matches)
+            .and_then(|range| parse_range(range, len));
+
+        let fetched = match range {
+            None => self.client.data_get_public(addr).await,
+            Some(ByteRange::Satisfiable(start, end)) => {
+                self.client
+                    .data_get_public_range(addr, start, end - start + 1)
+                    .await
+            }
+            Some(ByteRange::Unsatisfiable) => {
+                return builder
+                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                    .header(header::CONTENT_RANGE, format!("bytes */{len}"))
+                    .body(Body::empty())
+                    .unwrap_or_default();
+            }
+        };
+        let content = match fetched {
+            Ok(content) => content,
+            Err(err) => {
+                error!("Failed to fetch {path:?} at {addr:?}: {err:?}");
+                return text_response(
+                    StatusCode::BAD_GATEWAY,
+                    &format!("Failed to fetch {}: {err}\n", path.display()),
+                );
+            }
+        };
+        // only sniff the whole content, the start of a range says nothing about the type of the file
+        let sniffed = if range.is_none() { &content[..] } else { &[] };
+        let content_type = meta
+            .mime_type
+            .clone()
+            .or_else(|| guess_mime_type(path, sniffed))
+            .unwrap_or(DEFAULT_CONTENT_TYPE.to_string());
+        builder = builder.header(header::CONTENT_TYPE, content_type);
+
+        let response = match range {
+            Some(ByteRange::Satisfiable(start, _)) if !content.is_empty() => {
+                // the content can be shorter than the range if the size in the archive is wrong
+                let end = start + content.len() as u64 - 1;
+                builder
+                    .status(StatusCode::PARTIAL_CONTENT)
+                    .header(header::CONTENT_RANGE, format!("bytes {start}-{end}/{len}"))
+                    .header(header::CONTENT_LENGTH, content.len())
+                    .body(Body::from(content))
+            }
+            Some(_) => builder
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .header(header::CONTENT_RANGE, format!("bytes */{len}"))
+                .body(Body::empty()),
+            None => builder
+                .header(header::CONTENT_LENGTH, content.len())
+                .body(Body::from(content)),
+        };
+        response.unwrap_or_default()
+    }
+}
+
+/// A single byte range requested with the `Range` header, bounds are inclusive
+#[derive(Debug, PartialEq, Eq)]
+enum ByteRange {
+    Satisfiable(u64, u64),
+    Unsatisfiable,
+}
+
+/// Parse a `Range` header for content of the given length.
+/// Returns None if the header should be ignored: malformed, not in bytes or with multiple ranges.
+fn parse_range(value: &str, len: u64) -> Option<ByteRange> {
+    let range = value.trim().strip_prefix("bytes=")?;
+    if range.contains(',') {
+        return None;
+    }
+    let (start, end) = range.split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+
+    if start.is_empty() {
+        // suffix range: the last n bytes
+        let suffix: u64 = end.parse().ok()?;
+        if suffix == 0 || len == 0 {
+            return Some(ByteRange::Unsatisfiable);
+        }
+        return Some(ByteRange::Satisfiable(len.saturating_sub(suffix), len - 1));
+    }
+
+    let start: u64 = start.parse().ok()?;
+    let end = match end {
+        "" => None,
+        end => Some(end.parse::<u64>().ok()?),
+    };
+    if end.is_some_and(|end| end < start) {
+        return None;
+    }
+    if start >= len {
+        return Some(ByteRange::Unsatisfiable);
+    }
+    let end = end.map_or(len - 1, |end| end.min(len - 1));
+    Some(ByteRange::Satisfiable(start, end))
+}
+
+fn header_str(headers: &HeaderMap, name: header::HeaderName) -> Option<&str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn text_response(status: StatusCode, text: &str) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+        .body(Body::from(text.to_string()))
+        .unwrap_or_default()
+}
+
+fn redirect(location: &str) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::MOVED_PERMANENTLY)
+        .header(header::LOCATION, location)
+        .body(Body::empty())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        use ByteRange::*;
+
+        assert_eq!(parse_range("bytes=0-99", 1000), Some(Satisfiable(0, 99)));
+        assert_eq!(parse_range("bytes=500-", 1000), Some(Satisfiable(500, 999)));
+        assert_eq!(parse_range("bytes=-100", 1000), Some(Satisfiable(900, 999)));
+        assert_eq!(parse_range("bytes=-2000", 1000), Some(Satisfiable(0, 999)));
+        assert_eq!(
+            parse_range("bytes=900-2000", 1000),
+            Some(Satisfiable(900, 999))
+        );
+
+        assert_eq!(parse_range("bytes=1000-", 1000), Some(Unsatisfiable));
+        assert_eq!(parse_range("bytes=-0", 1000), Some(Unsatisfiable));
+        assert_eq!(parse_range("bytes=0-", 0), Some(Unsatisfiable));
+
+        // ignored ranges
+        assert_eq!(parse_range("bytes=0-1,5-6", 1000), None);
+        assert_eq!(parse_range("items=0-1", 1000), None);
+        assert_eq!(parse_range("bytes=9-1", 1000), None);
+        assert_eq!(parse_range("bytes=a-b", 1000), None);
+    }
+}
@@ -8,8 +8,10 @@
 
 mod connect;
 mod download;
+mod gateway;
 mod progress_bar;
 
 pub use connect::{connect_to_network, connect_to_network_with_config};
 pub use download::download;
+pub use gateway::run_gateway;
 pub use progress_bar::get_progress_bar;
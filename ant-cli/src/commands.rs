@@ -7,13 +7,21 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 mod file;
+mod graph;
+mod key;
+mod keyring;
+mod name;
+mod pointer;
 mod register;
+mod scratchpad;
+mod site;
 mod vault;
 mod wallet;
 
+use crate::keys::SigningKeyKind;
 use crate::opt::Opt;
-use autonomi::ResponseQuorum;
-use clap::{error::ErrorKind, CommandFactory as _, Subcommand};
+use autonomi::{PrivateNetwork, ResponseQuorum, TransportConfig};
+use clap::{error::ErrorKind, CommandFactory as _, Subcommand, ValueEnum};
 use color_eyre::Result;
 
 #[derive(Subcommand, Debug)]
@@ -24,12 +32,54 @@ pub enum SubCmd {
         command: FileCmd,
     },
 
+    /// Operations related to graph entry management.
+    Graph {
+        #[command(subcommand)]
+        command: GraphCmd,
+    },
+
+    /// Back up and restore keys with mnemonic phrases.
+    Key {
+        #[command(subcommand)]
+        command: KeyCmd,
+    },
+
+    /// Operations related to the keyring, from which all your keys can be derived.
+    Keyring {
+        #[command(subcommand)]
+        command: KeyringCmd,
+    },
+
+    /// Human readable names for addresses on the Network.
+    Name {
+        #[command(subcommand)]
+        command: NameCmd,
+    },
+
+    /// Operations related to pointer management.
+    Pointer {
+        #[command(subcommand)]
+        command: PointerCmd,
+    },
+
     /// Operations related to register management.
     Register {
         #[command(subcommand)]
         command: RegisterCmd,
     },
 
+    /// Operations related to scratchpad management.
+    Scratchpad {
+        #[command(subcommand)]
+        command: ScratchpadCmd,
+    },
+
+    /// Host static websites stored as public archives.
+    Site {
+        #[command(subcommand)]
+        command: SiteCmd,
+    },
+
     /// Operations related to vault management.
     Vault {
         #[command(subcommand)]
@@ -76,12 +126,279 @@ pub enum FileCmd {
         /// Possible values are: "one", "majority", "all", n (where n is a number greater than 0)
         #[arg(short, long)]
         quorum: Option<ResponseQuorum>,
+        /// Check the content of each file against the SHA-256 hash stored in the archive.
+        ///
+        /// Files without a hash (uploaded by older clients) are not checked.
+        #[arg(long)]
+        verify: bool,
+        /// Print the trace of every network query made during the download: the peers contacted,
+        /// in what order, their latency, the outcome and the retries.
+        #[arg(long)]
+        trace: bool,
     },
 
     /// List previous uploads
     List,
 }
 
+/// The type of data a pointer points to.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum TargetDataType {
+    #[default]
+    Chunk,
+    Graph,
+    Pointer,
+    Scratchpad,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum NameCmd {
+    /// Register a name pointing to the given target.
+    /// Names are first come, first served and can never be transferred or released.
+    Register {
+        /// The name to register.
+        name: String,
+        /// The hex encoded address the name points to.
+        target: String,
+        /// The type of data the target address refers to.
+        #[arg(short, long, value_enum, default_value_t)]
+        target_type: TargetDataType,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Resolve a name to the address it points to.
+    Resolve {
+        /// The name to resolve.
+        name: String,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Point a name you own to a new target.
+    /// Updating a name is free.
+    Update {
+        /// The name to update.
+        name: String,
+        /// The hex encoded address the name points to.
+        target: String,
+        /// The type of data the target address refers to.
+        #[arg(short, long, value_enum, default_value_t)]
+        target_type: TargetDataType,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PointerCmd {
+    /// Generate a new pointer key.
+    GenerateKey {
+        /// Overwrite existing key if it exists
+        /// Warning: overwriting the existing key will result in loss of access to any existing pointers created using that key
+        #[arg(short, long)]
+        overwrite: bool,
+    },
+
+    /// Estimate cost to create a pointer.
+    Cost {
+        /// The name of the pointer.
+        name: String,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Create a new pointer with the given name and target.
+    /// Note that anyone with the pointer address can read its target.
+    Create {
+        /// The name of the pointer.
+        name: String,
+        /// The hex encoded address the pointer points to.
+        target: String,
+        /// The type of data the target address refers to.
+        #[arg(short, long, value_enum, default_value_t)]
+        target_type: TargetDataType,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Update an existing pointer to point to a new target.
+    /// Updating a pointer is free.
+    Update {
+        /// The name of the pointer.
+        name: String,
+        /// The hex encoded address the pointer points to.
+        target: String,
+        /// The type of data the target address refers to.
+        #[arg(short, long, value_enum, default_value_t)]
+        target_type: TargetDataType,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Get the target of a pointer.
+    Get {
+        /// Use the name of the pointer instead of the address
+        /// Note that only the owner of the pointer can use this shorthand as the address can be generated from the name and pointer key.
+        #[arg(short, long)]
+        name: bool,
+        /// The address of the pointer
+        /// With the name option on the address will be used as a name
+        address: String,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Follow a chain of pointers down to its final target.
+    /// Fails if the chain contains a cycle or is longer than the maximum depth.
+    Resolve {
+        /// The address of the first pointer in the chain.
+        address: String,
+        /// The maximum number of pointers to follow.
+        #[arg(long, default_value = "16")]
+        max_depth: usize,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ScratchpadCmd {
+    /// Generate a new scratchpad key.
+    GenerateKey {
+        /// Overwrite existing key if it exists
+        /// Warning: overwriting the existing key will result in loss of access to any existing scratchpads created using that key
+        #[arg(short, long)]
+        overwrite: bool,
+    },
+
+    /// Estimate cost to create a scratchpad.
+    Cost {
+        /// The name of the scratchpad.
+        name: String,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Create a new scratchpad with the given name and data.
+    /// The data is encrypted so that only the owner can read it.
+    Create {
+        /// The name of the scratchpad.
+        name: String,
+        /// The data to store in the scratchpad.
+        data: String,
+        /// A number identifying the type of content, the choice is up to the user.
+        #[arg(short, long, default_value = "0")]
+        content_type: u64,
+        /// Treat the data as a hex string and convert it to binary before storing
+        #[arg(long)]
+        hex: bool,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Replace the data of an existing scratchpad.
+    /// Updating a scratchpad is free.
+    Update {
+        /// The name of the scratchpad.
+        name: String,
+        /// The new data to store in the scratchpad.
+        data: String,
+        /// A number identifying the type of content, the choice is up to the user.
+        #[arg(short, long, default_value = "0")]
+        content_type: u64,
+        /// Treat the data as a hex string and convert it to binary before storing
+        #[arg(long)]
+        hex: bool,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Get a scratchpad.
+    /// Its data is only decrypted when using the name, as only the owner can decrypt it.
+    Get {
+        /// Use the name of the scratchpad instead of the address
+        /// Note that only the owner of the scratchpad can use this shorthand as the address can be generated from the name and scratchpad key.
+        #[arg(short, long)]
+        name: bool,
+        /// The address of the scratchpad
+        /// With the name option on the address will be used as a name
+        address: String,
+        /// Display the data as a hex string instead of raw bytes
+        #[arg(long)]
+        hex: bool,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum GraphCmd {
+    /// Generate a new graph entry key.
+    GenerateKey {
+        /// Overwrite existing key if it exists
+        /// Warning: overwriting the existing key will result in loss of access to any existing graph entries created using that key
+        #[arg(short, long)]
+        overwrite: bool,
+    },
+
+    /// Estimate cost to create a graph entry.
+    Cost {
+        /// The name of the graph entry.
+        name: String,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Create a new graph entry with the given name and content.
+    /// Graph entries are immutable, they cannot be updated once created.
+    Create {
+        /// The name of the graph entry.
+        name: String,
+        /// The content of the graph entry, up to 32 bytes.
+        content: String,
+        /// The hex encoded public key of a parent entry, can be repeated.
+        #[arg(long = "parent")]
+        parents: Vec<String>,
+        /// A descendant entry as <public_key_hex>:<content_hex>, can be repeated.
+        #[arg(long = "descendant")]
+        descendants: Vec<String>,
+        /// Treat the content as a hex string and convert it to binary before storing
+        #[arg(long)]
+        hex: bool,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Get a graph entry.
+    Get {
+        /// Use the name of the graph entry instead of the address
+        /// Note that only the owner of the graph entry can use this shorthand as the address can be generated from the name and graph entry key.
+        #[arg(short, long)]
+        name: bool,
+        /// The address of the graph entry
+        /// With the name option on the address will be used as a name
+        address: String,
+        /// Print the output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum RegisterCmd {
     /// Generate a new register key.
@@ -159,6 +476,31 @@ pub enum RegisterCmd {
     List,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum SiteCmd {
+    /// Upload a directory as a website and point the pointer with the given name to it.
+    /// The pointer is created on the first publish and updated on the next ones, so the site address never changes.
+    Publish {
+        /// The directory containing the website, with an index.html at its root.
+        dir: String,
+        /// The name of the pointer to the site.
+        name: String,
+    },
+
+    /// Run an HTTP gateway serving websites from the Network.
+    ///
+    /// Sites are served at http://<host>:<port>/<site-address>/<path> where the site address is one of:
+    /// <archive-address>, pointer:<pointer-address>, register:<register-address> or name:<name>
+    Serve {
+        /// The address to listen on.
+        #[arg(long, default_value = "127.0.0.1")]
+        host: std::net::IpAddr,
+        /// The port to listen on.
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum VaultCmd {
     /// Estimate cost to create a vault.
@@ -172,6 +514,9 @@ pub enum VaultCmd {
     /// Pushing an encrypted backup of your local user data to the network
     Create,
 
+    /// Show the capacity and usage of your vault.
+    Info,
+
     /// Load an existing vault from the network.
     /// Use this when loading your user data to a new device.
     /// You need to have your original `SECRET_KEY` to load the vault.
@@ -179,15 +524,92 @@ pub enum VaultCmd {
 
     /// Sync vault with the network, safeguarding local user data.
     /// Loads existing user data from the network and merges it with your local user data.
-    /// Pushes your local user data to the network.
+    /// Entries from both sides are kept, removals on either side are applied to both,
+    /// and entries renamed differently on both sides keep the most recent name.
+    /// Pushes the merged user data to the network.
     Sync {
         /// Force push your local user data to the network.
         /// This will overwrite any existing data in your vault.
         #[arg(short, long)]
         force: bool,
+        /// Only show the changes the sync would make, without making them.
+        #[arg(long, conflicts_with = "force")]
+        dry_run: bool,
     },
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum KeyKind {
+    Register,
+    Pointer,
+    Scratchpad,
+    Graph,
+}
+
+impl From<KeyKind> for SigningKeyKind {
+    fn from(kind: KeyKind) -> Self {
+        match kind {
+            KeyKind::Register => SigningKeyKind::Register,
+            KeyKind::Pointer => SigningKeyKind::Pointer,
+            KeyKind::Scratchpad => SigningKeyKind::Scratchpad,
+            KeyKind::Graph => SigningKeyKind::GraphEntry,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum KeyCmd {
+    /// Print the mnemonic phrase of a key, from which it can be restored.
+    Backup {
+        /// The kind of key to back up.
+        kind: KeyKind,
+    },
+
+    /// Restore a key from its mnemonic phrase.
+    Restore {
+        /// The kind of key to restore.
+        kind: KeyKind,
+        /// The mnemonic of the key, you will be prompted for it if not provided.
+        mnemonic: Option<String>,
+        /// Overwrite the existing key if there is one.
+        /// Note that this will result in the loss of access to any data created with it, unless it is backed up.
+        #[arg(short, long)]
+        overwrite: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum KeyringCmd {
+    /// Create a new keyring and print its mnemonic.
+    ///
+    /// The register, pointer, scratchpad and graph entry keys are derived from the keyring
+    /// unless a key of that kind is set with its env var or `generate-key` subcommand.
+    Create {
+        /// Optional passphrase protecting the mnemonic, it will be required to recover the keyring.
+        #[arg(long)]
+        passphrase: Option<String>,
+        /// Overwrite the existing keyring if there is one.
+        /// Note that this will result in the loss of access to any data derived from it, unless you have its mnemonic.
+        #[arg(short, long)]
+        overwrite: bool,
+    },
+
+    /// Recover a keyring from its mnemonic.
+    Recover {
+        /// The mnemonic of the keyring, you will be prompted for it if not provided.
+        mnemonic: Option<String>,
+        /// The passphrase used when the keyring was created, if any.
+        #[arg(long)]
+        passphrase: Option<String>,
+        /// Overwrite the existing keyring if there is one.
+        #[arg(short, long)]
+        overwrite: bool,
+    },
+
+    /// Show the public keys of the keyring.
+    Show,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum WalletCmd {
     /// Create a wallet.
@@ -220,7 +642,19 @@ pub enum WalletCmd {
 }
 
 pub async fn handle_subcommand(opt: Opt) -> Result<()> {
-    let peers = crate::access::network::get_peers(opt.peers);
+    // the client only dials, so the ports are not used
+    let transports = TransportConfig {
+        tcp: opt.tcp.then_some(0),
+        websocket: opt.websocket.then_some(0),
+    };
+    let mut private_network = PrivateNetwork::from_files(
+        opt.network_key_file.as_deref(),
+        opt.allowed_peers_file.as_deref(),
+    )?;
+    if let Some(path) = opt.peer_key_file.as_deref() {
+        private_network.identity = Some(PrivateNetwork::read_or_create_identity(path)?);
+    }
+    let peers = crate::access::network::get_peers(opt.peers, transports, private_network);
     let cmd = opt.command;
 
     match cmd {
@@ -235,9 +669,80 @@ pub async fn handle_subcommand(opt: Opt) -> Result<()> {
                 addr,
                 dest_file,
                 quorum,
-            } => file::download(&addr, &dest_file, peers.await?, quorum).await,
+                verify,
+                trace,
+            } => file::download(&addr, &dest_file, peers.await?, quorum, verify, trace).await,
             FileCmd::List => file::list(),
         },
+        Some(SubCmd::Graph { command }) => match command {
+            GraphCmd::GenerateKey { overwrite } => graph::generate_key(overwrite),
+            GraphCmd::Cost { name, json } => graph::cost(&name, json, peers.await?).await,
+            GraphCmd::Create {
+                name,
+                content,
+                parents,
+                descendants,
+                hex,
+                json,
+            } => {
+                graph::create(
+                    &name,
+                    &content,
+                    &parents,
+                    &descendants,
+                    hex,
+                    json,
+                    peers.await?,
+                )
+                .await
+            }
+            GraphCmd::Get {
+                name,
+                address,
+                json,
+            } => graph::get(&address, name, json, peers.await?).await,
+        },
+        Some(SubCmd::Name { command }) => match command {
+            NameCmd::Register {
+                name,
+                target,
+                target_type,
+                json,
+            } => name::register(&name, &target, target_type, json, peers.await?).await,
+            NameCmd::Resolve { name, json } => name::resolve(&name, json, peers.await?).await,
+            NameCmd::Update {
+                name,
+                target,
+                target_type,
+                json,
+            } => name::update(&name, &target, target_type, json, peers.await?).await,
+        },
+        Some(SubCmd::Pointer { command }) => match command {
+            PointerCmd::GenerateKey { overwrite } => pointer::generate_key(overwrite),
+            PointerCmd::Cost { name, json } => pointer::cost(&name, json, peers.await?).await,
+            PointerCmd::Create {
+                name,
+                target,
+                target_type,
+                json,
+            } => pointer::create(&name, &target, target_type, json, peers.await?).await,
+            PointerCmd::Update {
+                name,
+                target,
+                target_type,
+                json,
+            } => pointer::update(&name, &target, target_type, json, peers.await?).await,
+            PointerCmd::Get {
+                name,
+                address,
+                json,
+            } => pointer::get(&address, name, json, peers.await?).await,
+            PointerCmd::Resolve {
+                address,
+                max_depth,
+                json,
+            } => pointer::resolve(&address, max_depth, json, peers.await?).await,
+        },
         Some(SubCmd::Register { command }) => match command {
             RegisterCmd::GenerateKey { overwrite } => register::generate_key(overwrite),
             RegisterCmd::Cost { name } => register::cost(&name, peers.await?).await,
@@ -258,13 +763,62 @@ pub async fn handle_subcommand(opt: Opt) -> Result<()> {
             }
             RegisterCmd::List => register::list(),
         },
+        Some(SubCmd::Scratchpad { command }) => match command {
+            ScratchpadCmd::GenerateKey { overwrite } => scratchpad::generate_key(overwrite),
+            ScratchpadCmd::Cost { name, json } => scratchpad::cost(&name, json, peers.await?).await,
+            ScratchpadCmd::Create {
+                name,
+                data,
+                content_type,
+                hex,
+                json,
+            } => scratchpad::create(&name, &data, content_type, hex, json, peers.await?).await,
+            ScratchpadCmd::Update {
+                name,
+                data,
+                content_type,
+                hex,
+                json,
+            } => scratchpad::update(&name, &data, content_type, hex, json, peers.await?).await,
+            ScratchpadCmd::Get {
+                name,
+                address,
+                hex,
+                json,
+            } => scratchpad::get(&address, name, hex, json, peers.await?).await,
+        },
+        Some(SubCmd::Site { command }) => match command {
+            SiteCmd::Publish { dir, name } => site::publish(&dir, &name, peers.await?).await,
+            SiteCmd::Serve { host, port } => site::serve(host, port, peers.await?).await,
+        },
         Some(SubCmd::Vault { command }) => match command {
             VaultCmd::Cost { expected_max_size } => {
                 vault::cost(peers.await?, expected_max_size).await
             }
             VaultCmd::Create => vault::create(peers.await?).await,
             VaultCmd::Load => vault::load(peers.await?).await,
-            VaultCmd::Sync { force } => vault::sync(force, peers.await?).await,
+            VaultCmd::Info => vault::info(peers.await?).await,
+            VaultCmd::Sync { force, dry_run } => vault::sync(force, dry_run, peers.await?).await,
+        },
+        Some(SubCmd::Key { command }) => match command {
+            KeyCmd::Backup { kind } => key::backup(kind.into()),
+            KeyCmd::Restore {
+                kind,
+                mnemonic,
+                overwrite,
+            } => key::restore(kind.into(), mnemonic, overwrite),
+        },
+        Some(SubCmd::Keyring { command }) => match command {
+            KeyringCmd::Create {
+                passphrase,
+                overwrite,
+            } => keyring::create(passphrase, overwrite),
+            KeyringCmd::Recover {
+                mnemonic,
+                passphrase,
+                overwrite,
+            } => keyring::recover(mnemonic, passphrase, overwrite),
+            KeyringCmd::Show => keyring::show(),
         },
         Some(SubCmd::Wallet { command }) => match command {
             WalletCmd::Create {
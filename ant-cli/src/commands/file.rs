@@ -7,7 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::network::NetworkPeers;
-use crate::utils::collect_upload_summary;
+use crate::utils::{collect_query_traces, collect_upload_summary};
 use crate::wallet::load_wallet;
 use autonomi::client::address::addr_to_str;
 use autonomi::ClientOperatingStrategy;
@@ -122,13 +122,36 @@ pub async fn download(
     dest_path: &str,
     peers: NetworkPeers,
     quorum: Option<ResponseQuorum>,
+    verify: bool,
+    trace: bool,
 ) -> Result<()> {
     let mut config = ClientOperatingStrategy::new();
     if let Some(quorum) = quorum {
         config.chunks.get_quorum = quorum;
     }
-    let client = crate::actions::connect_to_network_with_config(peers, config).await?;
-    crate::actions::download(addr, dest_path, &client).await
+    let mut client = crate::actions::connect_to_network_with_config(peers, config).await?;
+    if !trace {
+        return crate::actions::download(addr, dest_path, &client, verify).await;
+    }
+
+    let event_receiver = client.enable_client_events();
+    client.enable_query_tracing();
+    let (traces_thread, download_completed_tx) = collect_query_traces(event_receiver);
+
+    let result = crate::actions::download(addr, dest_path, &client, verify).await;
+
+    if let Err(e) = download_completed_tx.send(()) {
+        error!("Failed to send download completed event: {e:?}");
+    }
+    // the traces are printed even if the download failed, to find out why
+    let traces = traces_thread.await?;
+    println!();
+    println!("Traces of the {} network queries:", traces.len());
+    for trace in traces {
+        println!("{trace}");
+    }
+
+    result
 }
 
 pub fn list() -> Result<()> {
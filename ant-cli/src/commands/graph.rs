@@ -0,0 +1,244 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::keys::SigningKeyKind;
+use crate::network::NetworkPeers;
+use crate::utils::print_json;
+use crate::wallet::load_wallet;
+use autonomi::client::address::str_to_addr;
+use autonomi::client::graph::{GraphContent, GraphEntry, GraphEntryAddress};
+use autonomi::{PublicKey, SecretKey};
+use color_eyre::eyre::{eyre, Context, Result};
+use color_eyre::Section;
+use serde_json::json;
+
+pub fn generate_key(overwrite: bool) -> Result<()> {
+    let path = crate::keys::generate_signing_key(SigningKeyKind::GraphEntry, overwrite)?;
+    println!("✅ Created new graph entry key at: {}", path.display());
+    Ok(())
+}
+
+pub async fn cost(name: &str, json: bool, peers: NetworkPeers) -> Result<()> {
+    let graph_key = get_graph_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    let cost = client
+        .graph_entry_cost(&graph_key.public_key())
+        .await
+        .wrap_err("Failed to get cost for graph entry")?;
+    info!("Estimated cost to create a graph entry with name {name}: {cost}");
+
+    if json {
+        print_json(&json!({ "name": name, "cost": cost.to_string() }))?;
+    } else {
+        println!("✅ The estimated cost to create a graph entry with name {name} is: {cost}");
+    }
+    Ok(())
+}
+
+pub async fn create(
+    name: &str,
+    content: &str,
+    parents: &[String],
+    descendants: &[String],
+    hex: bool,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let graph_key = get_graph_key(name)?;
+    let content = parse_content(content, hex)?;
+    let parents = parents
+        .iter()
+        .map(|p| parse_public_key(p))
+        .collect::<Result<Vec<_>>>()?;
+    let descendants = descendants
+        .iter()
+        .map(|d| parse_descendant(d))
+        .collect::<Result<Vec<_>>>()?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let wallet = load_wallet(client.evm_network())?;
+
+    if !json {
+        println!("Creating graph entry with name: {name}");
+    }
+    info!("Creating graph entry with name: {name}");
+
+    let entry = GraphEntry::new(&graph_key, parents, content, descendants);
+    let (cost, address) = client
+        .graph_entry_put(entry.clone(), wallet.into())
+        .await
+        .wrap_err("Failed to create graph entry")?;
+    info!("Graph entry created at address: {address:?} with name: {name}");
+
+    if json {
+        let mut value = graph_entry_to_json(&entry);
+        value["name"] = json!(name);
+        value["cost"] = json!(cost.to_string());
+        print_json(&value)?;
+    } else {
+        println!("✅ Graph entry created at address: {}", address.to_hex());
+        println!("With name: {name}");
+        println!("Owner: {}", entry.owner.to_hex());
+        println!("Total cost: {cost} AttoTokens");
+    }
+    Ok(())
+}
+
+pub async fn get(address: &str, name: bool, json: bool, peers: NetworkPeers) -> Result<()> {
+    let addr = if name {
+        GraphEntryAddress::from_owner(get_graph_key(address)?.public_key())
+    } else {
+        str_to_addr(address)
+            .map(GraphEntryAddress::new)
+            .wrap_err(format!("Failed to parse graph entry address: {address}"))
+            .with_suggestion(|| {
+                "if you want to use the name as the address, run the command with the --name flag"
+            })?
+    };
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    if !json {
+        println!("Getting graph entry at: {address}");
+    }
+    info!("Getting graph entry at: {address}");
+
+    let entry = client
+        .graph_entry_get(&addr)
+        .await
+        .wrap_err(format!("Error getting graph entry at: {address}"))?;
+    info!("Graph entry found at: {address}");
+
+    if json {
+        print_json(&graph_entry_to_json(&entry))?;
+        return Ok(());
+    }
+
+    println!("✅ Graph entry found at: {}", addr.to_hex());
+    println!("Owner: {}", entry.owner.to_hex());
+    println!("Content: [{}]", hex::encode(entry.content));
+    println!("Parents ({}):", entry.parents.len());
+    for parent in entry.parents.iter() {
+        println!("  {}", parent.to_hex());
+    }
+    println!("Descendants ({}):", entry.descendants.len());
+    for (descendant, content) in entry.descendants.iter() {
+        println!("  {}: [{}]", descendant.to_hex(), hex::encode(content));
+    }
+    Ok(())
+}
+
+fn get_graph_key(name: &str) -> Result<SecretKey> {
+    let main_graph_key = crate::keys::get_signing_key(SigningKeyKind::GraphEntry)
+        .wrap_err("The graph entry key is required to perform this action")?;
+    Ok(crate::keys::key_from_name(&main_graph_key, name))
+}
+
+fn parse_content(content: &str, hex: bool) -> Result<GraphContent> {
+    let bytes = if hex {
+        hex::decode(content.trim_start_matches("0x"))
+            .wrap_err("Failed to decode hex value")
+            .with_suggestion(|| "Make sure the value is a valid hex string")?
+    } else {
+        content.as_bytes().to_vec()
+    };
+    let mut graph_content: GraphContent = [0; 32];
+    if bytes.len() > graph_content.len() {
+        return Err(eyre!(
+            "Graph entry content is {} bytes long, it cannot be longer than {} bytes",
+            bytes.len(),
+            graph_content.len()
+        ));
+    }
+    graph_content[..bytes.len()].copy_from_slice(&bytes);
+    Ok(graph_content)
+}
+
+fn parse_public_key(key: &str) -> Result<PublicKey> {
+    PublicKey::from_hex(key.trim_start_matches("0x"))
+        .wrap_err(format!("Failed to parse public key: {key}"))
+        .with_suggestion(|| "the public key should be a hex encoded bls public key")
+}
+
+fn parse_descendant(descendant: &str) -> Result<(PublicKey, GraphContent)> {
+    let (key, content) = descendant
+        .split_once(':')
+        .ok_or_else(|| eyre!("Invalid descendant: {descendant}"))
+        .with_suggestion(|| "descendants should be given as <public_key_hex>:<content_hex>")?;
+    Ok((parse_public_key(key)?, parse_content(content, true)?))
+}
+
+fn graph_entry_to_json(entry: &GraphEntry) -> serde_json::Value {
+    json!({
+        "address": entry.address().to_hex(),
+        "owner": entry.owner.to_hex(),
+        "content": hex::encode(entry.content),
+        "parents": entry.parents.iter().map(|p| p.to_hex()).collect::<Vec<_>>(),
+        "descendants": entry
+            .descendants
+            .iter()
+            .map(|(key, content)| json!({ "key": key.to_hex(), "content": hex::encode(content) }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_content() -> Result<()> {
+        // text is padded with zeros
+        let content = parse_content("hello", false)?;
+        assert_eq!(&content[..5], b"hello");
+        assert!(content[5..].iter().all(|byte| *byte == 0));
+
+        let content = parse_content("0x0102", true)?;
+        assert_eq!(&content[..2], &[1, 2]);
+
+        assert_eq!(parse_content(&"a".repeat(32), false)?, [b'a'; 32]);
+        assert!(parse_content(&"a".repeat(33), false).is_err());
+        assert!(parse_content("not hex", true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_descendant() -> Result<()> {
+        let key = SecretKey::random().public_key();
+        let (parsed_key, content) = parse_descendant(&format!("{}:ff", key.to_hex()))?;
+        assert_eq!(parsed_key, key);
+        assert_eq!(content[0], 0xff);
+
+        assert!(parse_descendant(&key.to_hex()).is_err());
+        assert!(parse_descendant("not a key:ff").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_entry_to_json() -> Result<()> {
+        let owner = SecretKey::random();
+        let parent = SecretKey::random().public_key();
+        let descendant = SecretKey::random().public_key();
+        let entry = GraphEntry::new(
+            &owner,
+            vec![parent],
+            parse_content("content", false)?,
+            vec![(descendant, parse_content("01", true)?)],
+        );
+        let json = graph_entry_to_json(&entry);
+
+        assert_eq!(json["address"], json!(entry.address().to_hex()));
+        assert_eq!(json["owner"], json!(owner.public_key().to_hex()));
+        assert_eq!(json["content"], json!(hex::encode(entry.content)));
+        assert_eq!(json["parents"], json!([parent.to_hex()]));
+        assert_eq!(
+            json["descendants"],
+            json!([{ "key": descendant.to_hex(), "content": hex::encode(entry.descendants[0].1) }])
+        );
+        Ok(())
+    }
+}
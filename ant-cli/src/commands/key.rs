@@ -0,0 +1,49 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::keys::SigningKeyKind;
+use crate::wallet::input::get_password_input;
+use autonomi::client::key_derivation::{secret_key_from_mnemonic, secret_key_to_mnemonic};
+use color_eyre::eyre::{eyre, Context};
+use color_eyre::{Result, Section};
+
+pub fn backup(kind: SigningKeyKind) -> Result<()> {
+    let key = crate::keys::get_signing_key(kind)
+        .wrap_err(format!("Failed to load the {kind} key to back up"))?;
+    let mnemonic = secret_key_to_mnemonic(&key);
+
+    println!("Mnemonic of your {kind} key, write it down and keep it safe:");
+    println!("{mnemonic}");
+    println!();
+    println!(
+        "You can restore it with the `key restore {}` subcommand",
+        kind.subcommand()
+    );
+    Ok(())
+}
+
+pub fn restore(kind: SigningKeyKind, mnemonic: Option<String>, overwrite: bool) -> Result<()> {
+    let mnemonic = match mnemonic {
+        Some(mnemonic) => mnemonic,
+        None => get_password_input(&format!("Enter the mnemonic of your {kind} key: ")),
+    };
+    if mnemonic.is_empty() {
+        return Err(eyre!("A mnemonic is required to restore a key"));
+    }
+
+    let key = secret_key_from_mnemonic(&mnemonic)
+        .wrap_err(format!("Failed to restore {kind} key from mnemonic"))
+        .with_suggestion(|| "make sure the mnemonic words are correct and in the right order")
+        .with_suggestion(|| {
+            "the 12 words mnemonic of a keyring is recovered with the `keyring recover` subcommand"
+        })?;
+    let path = crate::keys::store_signing_key(kind, key, overwrite)?;
+
+    println!("✅ Restored {kind} key at: {}", path.display());
+    Ok(())
+}
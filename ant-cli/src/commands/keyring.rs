@@ -0,0 +1,86 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::wallet::input::get_password_input;
+use autonomi::Keyring;
+use color_eyre::eyre::{eyre, Context};
+use color_eyre::{Result, Section};
+
+pub fn create(passphrase: Option<String>, overwrite: bool) -> Result<()> {
+    let passphrase = passphrase.unwrap_or_default();
+    let (keyring, mnemonic) =
+        Keyring::random_with_mnemonic(&passphrase).wrap_err("Failed to create keyring")?;
+    let path = crate::keys::store_keyring(&keyring, overwrite)?;
+
+    println!("✅ Created new keyring at: {}", path.display());
+    println!(
+        "Keyring root public key: {}",
+        keyring.root_public_key().to_hex()
+    );
+    println!();
+    println!(
+        "Write down your mnemonic and keep it safe, it is the only way to recover your keyring:"
+    );
+    println!("{mnemonic}");
+    if !passphrase.is_empty() {
+        println!("You will also need your passphrase to recover your keyring.");
+    }
+    Ok(())
+}
+
+pub fn recover(
+    mnemonic: Option<String>,
+    passphrase: Option<String>,
+    overwrite: bool,
+) -> Result<()> {
+    let mnemonic = match mnemonic {
+        Some(mnemonic) => mnemonic,
+        None => get_password_input("Enter your mnemonic: "),
+    };
+    if mnemonic.is_empty() {
+        return Err(eyre!("A mnemonic is required to recover a keyring"));
+    }
+
+    let keyring = Keyring::from_mnemonic(&mnemonic, &passphrase.unwrap_or_default())
+        .wrap_err("Failed to recover keyring from mnemonic")
+        .with_suggestion(|| "make sure the mnemonic words are correct and in the right order")
+        .with_suggestion(|| {
+            "the 24 words mnemonic of a single key is restored with the `key restore` subcommand"
+        })?;
+    let path = crate::keys::store_keyring(&keyring, overwrite)?;
+
+    println!("✅ Recovered keyring at: {}", path.display());
+    println!(
+        "Keyring root public key: {}",
+        keyring.root_public_key().to_hex()
+    );
+    Ok(())
+}
+
+pub fn show() -> Result<()> {
+    let keyring = crate::keys::get_keyring()?
+        .ok_or(eyre!("No keyring found"))
+        .with_suggestion(|| "you can create a keyring with the `keyring create` subcommand")?;
+
+    println!(
+        "Keyring root public key: {}",
+        keyring.root_public_key().to_hex()
+    );
+    for (kind, key) in crate::keys::keyring_signing_keys(&keyring) {
+        let status = if crate::keys::get_signing_key_path(kind)?.exists() {
+            "overridden by key file"
+        } else {
+            "in use"
+        };
+        println!(
+            "{kind} main public key: {} ({status})",
+            key.public_key().to_hex()
+        );
+    }
+    Ok(())
+}
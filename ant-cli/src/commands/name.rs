@@ -0,0 +1,134 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::pointer::{parse_target, target_to_json, target_to_string};
+use crate::commands::TargetDataType;
+use crate::keys::SigningKeyKind;
+use crate::network::NetworkPeers;
+use crate::utils::print_json;
+use crate::wallet::load_wallet;
+use autonomi::name::NameResolution;
+use autonomi::{Client, SecretKey};
+use color_eyre::eyre::{Context, Result};
+use color_eyre::Section;
+use serde_json::json;
+
+pub async fn register(
+    name: &str,
+    target: &str,
+    target_type: TargetDataType,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let owner_key = get_name_owner_key(name)?;
+    let target = parse_target(target, target_type)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let wallet = load_wallet(client.evm_network())?;
+
+    if !json {
+        println!("Registering name: {name}");
+    }
+    info!("Registering name: {name} with target: {target:?}");
+
+    let (cost, resolution) = client
+        .name_register(name, &owner_key, target, wallet.into())
+        .await
+        .wrap_err(format!("Failed to register name: {name}"))
+        .with_suggestion(|| {
+            "names are first come, first served, try another name if it is taken"
+        })?;
+    info!("Name {name} registered at: {:?}", resolution.address);
+
+    if json {
+        let mut value = resolution_to_json(&resolution);
+        value["cost"] = json!(cost.to_string());
+        print_json(&value)?;
+    } else {
+        println!("✅ Name registered: {}", resolution.name);
+        println!("Pointing to: {}", target_to_string(&resolution.target));
+        println!("Total cost: {cost} AttoTokens");
+    }
+    Ok(())
+}
+
+pub async fn resolve(name: &str, json: bool, peers: NetworkPeers) -> Result<()> {
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    if !json {
+        println!("Resolving name: {name}");
+    }
+    info!("Resolving name: {name}");
+    let resolution = client
+        .name_resolve(name)
+        .await
+        .wrap_err(format!("Failed to resolve name: {name}"))?;
+    info!("Name {name} resolved to: {:?}", resolution.target);
+
+    if json {
+        print_json(&resolution_to_json(&resolution))?;
+    } else {
+        println!("✅ Name {} resolved", resolution.name);
+        println!("Owner: {}", resolution.owner.to_hex());
+        println!("Pointing to: {}", target_to_string(&resolution.target));
+    }
+    Ok(())
+}
+
+pub async fn update(
+    name: &str,
+    target: &str,
+    target_type: TargetDataType,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let owner_key = get_name_owner_key(name)?;
+    let target = parse_target(target, target_type)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    if !json {
+        println!("Updating name: {name}");
+    }
+    info!("Updating name: {name} to target: {target:?}");
+
+    client
+        .name_update(name, &owner_key, target.clone())
+        .await
+        .wrap_err(format!("Failed to update name: {name}"))
+        .with_suggestion(|| {
+            "only the owner of a name can update it, with the pointer key used to register it"
+        })?;
+    info!("Successfully updated name: {name}");
+
+    if json {
+        print_json(&json!({
+            "name": name,
+            "target": target_to_json(&target),
+        }))?;
+    } else {
+        println!("✅ Successfully updated name: {name}");
+        println!("Now pointing to: {}", target_to_string(&target));
+    }
+    Ok(())
+}
+
+/// The owner key of a name is derived from the pointer key
+fn get_name_owner_key(name: &str) -> Result<SecretKey> {
+    let main_pointers_key = crate::keys::get_signing_key(SigningKeyKind::Pointer)
+        .wrap_err("The pointer key is required to perform this action")?;
+    Client::name_owner_key(&main_pointers_key, name).wrap_err("Invalid name")
+}
+
+fn resolution_to_json(resolution: &NameResolution) -> serde_json::Value {
+    json!({
+        "name": resolution.name,
+        "address": resolution.address.to_hex(),
+        "owner": resolution.owner.to_hex(),
+        "owner_pointer": resolution.owner_pointer.to_hex(),
+        "target": target_to_json(&resolution.target),
+    })
+}
@@ -0,0 +1,314 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::commands::TargetDataType;
+use crate::keys::SigningKeyKind;
+use crate::network::NetworkPeers;
+use crate::utils::print_json;
+use crate::wallet::load_wallet;
+use autonomi::client::address::str_to_addr;
+use autonomi::client::pointer::{Pointer, PointerAddress, PointerTarget};
+use autonomi::{ChunkAddress, GraphEntryAddress, ScratchpadAddress};
+use color_eyre::eyre::Context;
+use color_eyre::eyre::Result;
+use color_eyre::Section;
+use serde_json::json;
+
+pub fn generate_key(overwrite: bool) -> Result<()> {
+    let path = crate::keys::generate_signing_key(SigningKeyKind::Pointer, overwrite)?;
+    println!("✅ Created new pointer key at: {}", path.display());
+    Ok(())
+}
+
+pub async fn cost(name: &str, json: bool, peers: NetworkPeers) -> Result<()> {
+    let pointer_key = get_pointer_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    let cost = client
+        .pointer_cost(&pointer_key.public_key())
+        .await
+        .wrap_err("Failed to get cost for pointer")?;
+    info!("Estimated cost to create a pointer with name {name}: {cost}");
+
+    if json {
+        print_json(&json!({ "name": name, "cost": cost.to_string() }))?;
+    } else {
+        println!("✅ The estimated cost to create a pointer with name {name} is: {cost}");
+    }
+    Ok(())
+}
+
+pub async fn create(
+    name: &str,
+    target: &str,
+    target_type: TargetDataType,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let pointer_key = get_pointer_key(name)?;
+    let target = parse_target(target, target_type)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let wallet = load_wallet(client.evm_network())?;
+
+    if !json {
+        println!("Creating pointer with name: {name}");
+    }
+    info!("Creating pointer with name: {name} and target: {target:?}");
+
+    let (cost, address) = client
+        .pointer_create(&pointer_key, target.clone(), wallet.into())
+        .await
+        .wrap_err("Failed to create pointer")?;
+    info!("Pointer created at address: {address:?} with name: {name}");
+
+    if json {
+        print_json(&json!({
+            "name": name,
+            "address": address.to_hex(),
+            "target": target_to_json(&target),
+            "cost": cost.to_string(),
+        }))?;
+    } else {
+        println!("✅ Pointer created at address: {}", address.to_hex());
+        println!("With name: {name}");
+        println!("Pointing to: {}", target_to_string(&target));
+        println!("Total cost: {cost} AttoTokens");
+    }
+    Ok(())
+}
+
+pub async fn update(
+    name: &str,
+    target: &str,
+    target_type: TargetDataType,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let pointer_key = get_pointer_key(name)?;
+    let target = parse_target(target, target_type)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let address = PointerAddress::from_owner(pointer_key.public_key());
+
+    if !json {
+        println!("Updating pointer with name: {name}");
+    }
+    info!("Updating pointer with name: {name} to target: {target:?}");
+
+    client
+        .pointer_update(&pointer_key, target.clone())
+        .await
+        .wrap_err(format!("Failed to update pointer with name: {name}"))?;
+    info!("Successfully updated pointer at address: {address:?}");
+
+    if json {
+        print_json(&json!({
+            "name": name,
+            "address": address.to_hex(),
+            "target": target_to_json(&target),
+        }))?;
+    } else {
+        println!("✅ Successfully updated pointer at: {}", address.to_hex());
+        println!("Now pointing to: {}", target_to_string(&target));
+    }
+    Ok(())
+}
+
+pub async fn get(address: &str, name: bool, json: bool, peers: NetworkPeers) -> Result<()> {
+    let addr = if name {
+        PointerAddress::from_owner(get_pointer_key(address)?.public_key())
+    } else {
+        parse_pointer_address(address)?
+    };
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    if !json {
+        println!("Getting pointer at: {address}");
+    }
+    info!("Getting pointer at: {address}");
+
+    let pointer = client
+        .pointer_get(&addr)
+        .await
+        .wrap_err(format!("Error getting pointer at: {address}"))?;
+    info!("Pointer found at: {address}: {pointer:?}");
+
+    if json {
+        print_json(&pointer_to_json(&pointer))?;
+    } else {
+        println!("✅ Pointer found at: {}", addr.to_hex());
+        println!("Owner: {}", pointer.owner().to_hex());
+        println!("Counter: {}", pointer.counter());
+        println!("Pointing to: {}", target_to_string(pointer.target()));
+    }
+    Ok(())
+}
+
+pub async fn resolve(
+    address: &str,
+    max_depth: usize,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let addr = parse_pointer_address(address)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    if !json {
+        println!("Resolving pointer chain at address: {address}");
+    }
+    info!("Resolving pointer chain at address: {address} with max depth: {max_depth}");
+    let resolution = client
+        .pointer_resolve(&addr, max_depth)
+        .await
+        .wrap_err(format!("Failed to resolve pointer at address: {address}"))?;
+    info!(
+        "Pointer chain at {address} resolved to: {:?}",
+        resolution.target
+    );
+
+    if json {
+        print_json(&json!({
+            "path": resolution.path.iter().map(pointer_to_json).collect::<Vec<_>>(),
+            "target": target_to_json(&resolution.target),
+        }))?;
+        return Ok(());
+    }
+
+    println!(
+        "✅ Pointer chain resolved in {} hop(s):",
+        resolution.path.len()
+    );
+    for pointer in resolution.path.iter() {
+        println!(
+            "{} (counter: {}) -> {}",
+            pointer.address().to_hex(),
+            pointer.counter(),
+            target_to_string(pointer.target())
+        );
+    }
+    println!("Final target: {}", target_to_string(&resolution.target));
+    Ok(())
+}
+
+pub fn get_pointer_key(name: &str) -> Result<autonomi::SecretKey> {
+    let main_pointers_key = crate::keys::get_signing_key(SigningKeyKind::Pointer)
+        .wrap_err("The pointer key is required to perform this action")?;
+    Ok(crate::keys::key_from_name(&main_pointers_key, name))
+}
+
+fn parse_pointer_address(address: &str) -> Result<PointerAddress> {
+    str_to_addr(address)
+        .map(PointerAddress::new)
+        .wrap_err(format!("Failed to parse pointer address: {address}"))
+        .with_suggestion(|| "the pointer address should be a hex encoded string")
+        .with_suggestion(|| {
+            "if you want to use the name as the address, run the command with the --name flag"
+        })
+}
+
+pub fn parse_target(target: &str, target_type: TargetDataType) -> Result<PointerTarget> {
+    let target = target.trim_start_matches("0x");
+    let parsed = match target_type {
+        TargetDataType::Chunk => {
+            str_to_addr(target).map(|xor| PointerTarget::ChunkAddress(ChunkAddress::new(xor)))?
+        }
+        TargetDataType::Graph => str_to_addr(target)
+            .map(|xor| PointerTarget::GraphEntryAddress(GraphEntryAddress::new(xor)))?,
+        TargetDataType::Pointer => str_to_addr(target)
+            .map(|xor| PointerTarget::PointerAddress(PointerAddress::new(xor)))?,
+        TargetDataType::Scratchpad => {
+            PointerTarget::ScratchpadAddress(ScratchpadAddress::from_hex(target)?)
+        }
+    };
+    Ok(parsed)
+}
+
+pub fn target_to_string(target: &PointerTarget) -> String {
+    match target {
+        PointerTarget::ChunkAddress(addr) => format!("chunk {}", addr.to_hex()),
+        PointerTarget::GraphEntryAddress(addr) => format!("graph entry {}", addr.to_hex()),
+        PointerTarget::PointerAddress(addr) => format!("pointer {}", addr.to_hex()),
+        PointerTarget::ScratchpadAddress(addr) => format!("scratchpad {}", addr.to_hex()),
+    }
+}
+
+pub fn target_to_json(target: &PointerTarget) -> serde_json::Value {
+    let (target_type, address) = match target {
+        PointerTarget::ChunkAddress(addr) => ("chunk", addr.to_hex()),
+        PointerTarget::GraphEntryAddress(addr) => ("graph", addr.to_hex()),
+        PointerTarget::PointerAddress(addr) => ("pointer", addr.to_hex()),
+        PointerTarget::ScratchpadAddress(addr) => ("scratchpad", addr.to_hex()),
+    };
+    json!({ "type": target_type, "address": address })
+}
+
+fn pointer_to_json(pointer: &Pointer) -> serde_json::Value {
+    json!({
+        "address": pointer.address().to_hex(),
+        "owner": pointer.owner().to_hex(),
+        "counter": pointer.counter(),
+        "target": target_to_json(pointer.target()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomi::SecretKey;
+
+    #[test]
+    fn test_parse_target() -> Result<()> {
+        let xor = "0037cfa13eae4393841cbc00c3a33cade0f98b8c1f20826e5c51f8269e7b09d7";
+        for (target_type, name) in [
+            (TargetDataType::Chunk, "chunk"),
+            (TargetDataType::Graph, "graph"),
+            (TargetDataType::Pointer, "pointer"),
+        ] {
+            let target = parse_target(xor, target_type)?;
+            assert_eq!(
+                target_to_json(&target),
+                json!({ "type": name, "address": xor })
+            );
+            // a 0x prefix is accepted
+            assert_eq!(parse_target(&format!("0x{xor}"), target_type)?, target);
+        }
+
+        let scratchpad = ScratchpadAddress::new(SecretKey::random().public_key());
+        let target = parse_target(&scratchpad.to_hex(), TargetDataType::Scratchpad)?;
+        assert_eq!(target, PointerTarget::ScratchpadAddress(scratchpad));
+        assert_eq!(
+            target_to_string(&target),
+            format!("scratchpad {}", scratchpad.to_hex())
+        );
+
+        assert!(parse_target("not hex", TargetDataType::Chunk).is_err());
+        assert!(parse_target(xor, TargetDataType::Scratchpad).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pointer_to_json() -> Result<()> {
+        let key = SecretKey::random();
+        let target = parse_target(
+            "0037cfa13eae4393841cbc00c3a33cade0f98b8c1f20826e5c51f8269e7b09d7",
+            TargetDataType::Chunk,
+        )?;
+        let pointer = Pointer::new(&key, 3, target.clone());
+        let json = pointer_to_json(&pointer);
+
+        assert_eq!(json["address"], json!(pointer.address().to_hex()));
+        assert_eq!(json["owner"], json!(key.public_key().to_hex()));
+        assert_eq!(json["counter"], json!(3));
+        assert_eq!(json["target"], target_to_json(&target));
+        // the address given back can be used to get the pointer
+        assert_eq!(
+            parse_pointer_address(json["address"].as_str().unwrap_or_default())?,
+            pointer.address()
+        );
+        Ok(())
+    }
+}
@@ -8,31 +8,17 @@
 
 #![allow(deprecated)]
 
+use crate::keys::SigningKeyKind;
 use crate::network::NetworkPeers;
 use crate::wallet::load_wallet;
 use autonomi::client::register::RegisterAddress;
-use autonomi::client::register::SecretKey as RegisterSecretKey;
 use autonomi::Client;
-use color_eyre::eyre::eyre;
 use color_eyre::eyre::Context;
 use color_eyre::eyre::Result;
 use color_eyre::Section;
 
 pub fn generate_key(overwrite: bool) -> Result<()> {
-    // check if the key already exists
-    let key_path = crate::keys::get_register_signing_key_path()?;
-    if key_path.exists() && !overwrite {
-        error!("Register key already exists at: {key_path:?}");
-        return Err(eyre!("Register key already exists at: {}", key_path.display()))
-            .with_suggestion(|| "if you want to overwrite the existing key, run the command with the --overwrite flag")
-            .with_warning(|| "overwriting the existing key might result in loss of access to any existing registers created using that key");
-    }
-
-    // generate and write a new key to file
-    let key = RegisterSecretKey::random();
-    let path = crate::keys::create_register_signing_key_file(key)
-        .wrap_err("Failed to create new register key")?;
-    info!("Created new register key at: {path:?}");
+    let path = crate::keys::generate_signing_key(SigningKeyKind::Register, overwrite)?;
     println!("✅ Created new register key at: {}", path.display());
     Ok(())
 }
@@ -0,0 +1,233 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::keys::SigningKeyKind;
+use crate::network::NetworkPeers;
+use crate::utils::print_json;
+use crate::wallet::load_wallet;
+use autonomi::client::scratchpad::{Bytes, ScratchpadAddress};
+use autonomi::SecretKey;
+use color_eyre::eyre::Context;
+use color_eyre::eyre::Result;
+use color_eyre::Section;
+use serde_json::json;
+
+pub fn generate_key(overwrite: bool) -> Result<()> {
+    let path = crate::keys::generate_signing_key(SigningKeyKind::Scratchpad, overwrite)?;
+    println!("✅ Created new scratchpad key at: {}", path.display());
+    Ok(())
+}
+
+pub async fn cost(name: &str, json: bool, peers: NetworkPeers) -> Result<()> {
+    let scratchpad_key = get_scratchpad_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    let cost = client
+        .scratchpad_cost(&scratchpad_key.public_key())
+        .await
+        .wrap_err("Failed to get cost for scratchpad")?;
+    info!("Estimated cost to create a scratchpad with name {name}: {cost}");
+
+    if json {
+        print_json(&json!({ "name": name, "cost": cost.to_string() }))?;
+    } else {
+        println!("✅ The estimated cost to create a scratchpad with name {name} is: {cost}");
+    }
+    Ok(())
+}
+
+pub async fn create(
+    name: &str,
+    data: &str,
+    content_type: u64,
+    hex: bool,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let scratchpad_key = get_scratchpad_key(name)?;
+    let data = parse_data(data, hex)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let wallet = load_wallet(client.evm_network())?;
+
+    if !json {
+        println!("Creating scratchpad with name: {name}");
+    }
+    info!("Creating scratchpad with name: {name}");
+
+    let (cost, address) = client
+        .scratchpad_create(&scratchpad_key, content_type, &data, wallet.into())
+        .await
+        .wrap_err("Failed to create scratchpad")?;
+    info!("Scratchpad created at address: {address:?} with name: {name}");
+
+    if json {
+        print_json(&json!({
+            "name": name,
+            "address": address.to_hex(),
+            "content_type": content_type,
+            "size": data.len(),
+            "cost": cost.to_string(),
+        }))?;
+    } else {
+        println!("✅ Scratchpad created at address: {}", address.to_hex());
+        println!("With name: {name}");
+        println!("Total cost: {cost} AttoTokens");
+    }
+    Ok(())
+}
+
+pub async fn update(
+    name: &str,
+    data: &str,
+    content_type: u64,
+    hex: bool,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let scratchpad_key = get_scratchpad_key(name)?;
+    let data = parse_data(data, hex)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let address = ScratchpadAddress::new(scratchpad_key.public_key());
+
+    if !json {
+        println!("Updating scratchpad with name: {name}");
+    }
+    info!("Updating scratchpad with name: {name}");
+
+    client
+        .scratchpad_update(&scratchpad_key, content_type, &data)
+        .await
+        .wrap_err(format!("Failed to update scratchpad with name: {name}"))?;
+    info!("Successfully updated scratchpad at address: {address:?}");
+
+    if json {
+        print_json(&json!({
+            "name": name,
+            "address": address.to_hex(),
+            "content_type": content_type,
+            "size": data.len(),
+        }))?;
+    } else {
+        println!(
+            "✅ Successfully updated scratchpad at: {}",
+            address.to_hex()
+        );
+    }
+    Ok(())
+}
+
+/// Get a scratchpad, its content is only decrypted when it is fetched by name as only the owner can decrypt it.
+pub async fn get(
+    address: &str,
+    name: bool,
+    hex: bool,
+    json: bool,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let (addr, key) = if name {
+        let key = get_scratchpad_key(address)?;
+        (ScratchpadAddress::new(key.public_key()), Some(key))
+    } else {
+        let addr = ScratchpadAddress::from_hex(address)
+            .wrap_err(format!("Failed to parse scratchpad address: {address}"))
+            .with_suggestion(|| {
+                "if you want to use the name as the address, run the command with the --name flag"
+            })?;
+        (addr, None)
+    };
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    if !json {
+        println!("Getting scratchpad at: {address}");
+    }
+    info!("Getting scratchpad at: {address}");
+
+    let scratchpad = client
+        .scratchpad_get(&addr)
+        .await
+        .wrap_err(format!("Error getting scratchpad at: {address}"))?;
+    info!("Scratchpad found at: {address}");
+
+    let data = match key {
+        Some(key) => Some(
+            scratchpad
+                .decrypt_data(&key)
+                .wrap_err("Failed to decrypt scratchpad content")?,
+        ),
+        None => None,
+    };
+    let data_str = data.as_ref().map(|data| {
+        if hex {
+            hex::encode(data)
+        } else {
+            String::from_utf8_lossy(data).to_string()
+        }
+    });
+
+    if json {
+        print_json(&json!({
+            "address": addr.to_hex(),
+            "counter": scratchpad.counter(),
+            "content_type": scratchpad.data_encoding(),
+            "encrypted_size": scratchpad.encrypted_data().len(),
+            "data": data_str,
+        }))?;
+        return Ok(());
+    }
+
+    println!("✅ Scratchpad found at: {}", addr.to_hex());
+    println!("Counter: {}", scratchpad.counter());
+    println!("Content type: {}", scratchpad.data_encoding());
+    match data_str {
+        Some(value) if hex => println!("With hex value: [{value}]"),
+        Some(value) => println!("With value: [{value}]"),
+        None => {
+            println!(
+                "Encrypted content size: {} bytes",
+                scratchpad.encrypted_data().len()
+            );
+            println!(
+                "> Scratchpad content is encrypted, use the --name flag to decrypt it as its owner"
+            );
+        }
+    }
+    Ok(())
+}
+
+fn get_scratchpad_key(name: &str) -> Result<SecretKey> {
+    let main_scratchpads_key = crate::keys::get_signing_key(SigningKeyKind::Scratchpad)
+        .wrap_err("The scratchpad key is required to perform this action")?;
+    Ok(crate::keys::key_from_name(&main_scratchpads_key, name))
+}
+
+fn parse_data(data: &str, hex: bool) -> Result<Bytes> {
+    if hex {
+        let bytes = hex::decode(data.trim_start_matches("0x"))
+            .wrap_err("Failed to decode hex value")
+            .with_suggestion(|| "Make sure the value is a valid hex string")?;
+        Ok(Bytes::from(bytes))
+    } else {
+        Ok(Bytes::from(data.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data() -> Result<()> {
+        assert_eq!(parse_data("hello", false)?, Bytes::from("hello"));
+        // text is taken as is, even if it looks like hex
+        assert_eq!(parse_data("0x01", false)?, Bytes::from("0x01"));
+        assert_eq!(parse_data("0x0102", true)?, Bytes::from(vec![1, 2]));
+        assert_eq!(parse_data("0102", true)?, Bytes::from(vec![1, 2]));
+        assert!(parse_data("not hex", true).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::network::NetworkPeers;
+use crate::wallet::load_wallet;
+use autonomi::client::address::addr_to_str;
+use autonomi::client::pointer::{PointerAddress, PointerTarget};
+use autonomi::files::SiteRoot;
+use autonomi::ChunkAddress;
+use color_eyre::eyre::{Context, Result};
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+
+pub async fn publish(dir: &str, name: &str, peers: NetworkPeers) -> Result<()> {
+    let pointer_key = super::pointer::get_pointer_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let wallet = load_wallet(client.evm_network())?;
+
+    println!("Uploading site from: {dir}");
+    info!("Publishing site from {dir} with name: {name}");
+    let (cost, archive_addr) = client
+        .dir_and_archive_upload_public(PathBuf::from(dir), &wallet)
+        .await
+        .wrap_err("Failed to upload site")?;
+    info!("Uploaded site {dir} to archive at {archive_addr:?} for {cost}");
+
+    let address = PointerAddress::from_owner(pointer_key.public_key());
+    let target = PointerTarget::ChunkAddress(ChunkAddress::new(archive_addr));
+    let exists = client
+        .pointer_check_existance(&address)
+        .await
+        .wrap_err("Failed to check if the site pointer exists")?;
+    if exists {
+        client
+            .pointer_update(&pointer_key, target)
+            .await
+            .wrap_err(format!("Failed to update site pointer with name: {name}"))?;
+        info!("Updated site pointer {address:?} to archive at {archive_addr:?}");
+    } else {
+        let (pointer_cost, _) = client
+            .pointer_create(&pointer_key, target, wallet.into())
+            .await
+            .wrap_err(format!("Failed to create site pointer with name: {name}"))?;
+        info!("Created site pointer {address:?} to archive at {archive_addr:?} for {pointer_cost}");
+    }
+
+    let site = SiteRoot::Pointer(address);
+    println!("✅ Site published at: {site}");
+    println!("Current version: {}", addr_to_str(archive_addr));
+    println!("Upload cost: {cost} AttoTokens");
+    println!("Serve it locally with: ant site serve, then open http://127.0.0.1:8080/{site}/");
+
+    crate::user_data::write_local_public_file_archive(addr_to_str(archive_addr), name)
+        .wrap_err("Failed to save site archive to local user data")?;
+    Ok(())
+}
+
+pub async fn serve(host: IpAddr, port: u16, peers: NetworkPeers) -> Result<()> {
+    let client = crate::actions::connect_to_network(peers).await?;
+    crate::actions::run_gateway(client, SocketAddr::new(host, port)).await
+}
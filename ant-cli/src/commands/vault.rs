@@ -8,6 +8,8 @@
 
 use crate::network::NetworkPeers;
 use crate::wallet::load_wallet;
+use autonomi::vault::user_data::USER_DATA_VAULT_CONTENT_IDENTIFIER;
+use autonomi::vault::UserDataChange;
 use color_eyre::eyre::Context;
 use color_eyre::eyre::Result;
 use color_eyre::Section;
@@ -39,8 +41,9 @@ pub async fn create(peers: NetworkPeers) -> Result<()> {
     let registers_len = local_user_data.register_addresses.len();
     println!("Pushing to network vault...");
     let total_cost = client
-        .put_user_data_to_vault(&vault_sk, wallet.into(), local_user_data)
+        .put_user_data_to_vault(&vault_sk, wallet.into(), local_user_data.clone())
         .await?;
+    crate::user_data::write_sync_state(&local_user_data)?;
 
     if total_cost.is_zero() {
         println!("✅ Successfully pushed user data to existing vault");
@@ -56,13 +59,14 @@ pub async fn create(peers: NetworkPeers) -> Result<()> {
     Ok(())
 }
 
-pub async fn sync(force: bool, peers: NetworkPeers) -> Result<()> {
+pub async fn sync(force: bool, dry_run: bool, peers: NetworkPeers) -> Result<()> {
     let client = crate::actions::connect_to_network(peers).await?;
     let vault_sk = crate::keys::get_vault_secret_key()?;
-    let wallet = load_wallet(client.evm_network())?;
+    let local_user_data = crate::user_data::get_local_user_data()?;
 
-    if force {
+    let user_data = if force {
         println!("The force flag was provided, overwriting user data in the vault with local user data...");
+        local_user_data
     } else {
         println!("Fetching vault from network...");
         let net_user_data = client
@@ -70,19 +74,42 @@ pub async fn sync(force: bool, peers: NetworkPeers) -> Result<()> {
             .await
             .wrap_err("Failed to fetch vault from network")
             .with_suggestion(|| "Make sure you have already created a vault on the network")?;
-        println!("Syncing vault with local user data...");
-        crate::user_data::write_local_user_data(&net_user_data)?;
-    }
+
+        println!("Merging vault with local user data...");
+        let merge = local_user_data.merge(&net_user_data);
+        print_changes("local user data", &merge.local_changes);
+        print_changes("vault", &merge.remote_changes);
+        for conflict in merge.conflicts.iter() {
+            println!(
+                "Name conflict on {}: {:?} locally, {:?} in the vault, keeping {:?}",
+                conflict.entry, conflict.local_name, conflict.remote_name, conflict.kept_name
+            );
+        }
+
+        if dry_run {
+            println!("Dry run, no changes were made");
+            return Ok(());
+        }
+
+        crate::user_data::apply_local_changes(&merge.local_changes)?;
+        if merge.remote_changes.is_empty() && net_user_data == merge.merged {
+            crate::user_data::write_sync_state(&merge.merged)?;
+            println!("✅ Vault is already up to date");
+            return Ok(());
+        }
+        merge.merged
+    };
 
     println!("Pushing local user data to network vault...");
-    let local_user_data = crate::user_data::get_local_user_data()?;
-    let file_archives_len = local_user_data.file_archives.len();
-    let private_file_archives_len = local_user_data.private_file_archives.len();
-    let registers_len = local_user_data.register_addresses.len();
+    let wallet = load_wallet(client.evm_network())?;
+    let file_archives_len = user_data.file_archives.len();
+    let private_file_archives_len = user_data.private_file_archives.len();
+    let registers_len = user_data.register_addresses.len();
     client
-        .put_user_data_to_vault(&vault_sk, wallet.into(), local_user_data)
+        .put_user_data_to_vault(&vault_sk, wallet.into(), user_data.clone())
         .await
         .with_suggestion(|| "Make sure you have already created a vault on the network")?;
+    crate::user_data::write_sync_state(&user_data)?;
 
     println!("✅ Successfully synced vault");
     println!("Vault contains:");
@@ -92,6 +119,59 @@ pub async fn sync(force: bool, peers: NetworkPeers) -> Result<()> {
     Ok(())
 }
 
+fn print_changes(side: &str, changes: &[UserDataChange]) {
+    if changes.is_empty() {
+        println!("No changes to the {side}");
+        return;
+    }
+    println!("Changes to the {side}:");
+    for change in changes {
+        println!("  {change}");
+    }
+}
+
+pub async fn info(peers: NetworkPeers) -> Result<()> {
+    let client = crate::actions::connect_to_network(peers).await?;
+    let vault_sk = crate::keys::get_vault_secret_key()?;
+
+    println!("Retrieving vault info from network...");
+    let info = client
+        .vault_info(&vault_sk)
+        .await
+        .wrap_err("Failed to get vault info from network")?;
+
+    if info.scratchpads_created == 0 {
+        println!("No vault found on the network for this key");
+        return Ok(());
+    }
+
+    let content = if info.content_type == *USER_DATA_VAULT_CONTENT_IDENTIFIER {
+        "user data".to_string()
+    } else {
+        format!("content type {}", info.content_type)
+    };
+    println!("Vault contains {content}, updated {} time(s)", info.version);
+    println!(
+        "Used: {} bytes in {} scratchpad(s)",
+        info.used_bytes, info.scratchpads_used
+    );
+    println!(
+        "Allocated: {} bytes in {} scratchpad(s)",
+        info.allocated_bytes, info.scratchpads_created
+    );
+    println!(
+        "Claimed: {} scratchpad(s) in {} graph entry(ies)",
+        info.scratchpads_claimed, info.graph_entries
+    );
+    if info.garbage_bytes > 0 {
+        println!(
+            "Stale data left in unused scratchpads: {} bytes",
+            info.garbage_bytes
+        );
+    }
+    Ok(())
+}
+
 pub async fn load(peers: NetworkPeers) -> Result<()> {
     let client = crate::actions::connect_to_network(peers).await?;
     let vault_sk = crate::keys::get_vault_secret_key()?;
@@ -100,6 +180,7 @@ pub async fn load(peers: NetworkPeers) -> Result<()> {
     let user_data = client.get_user_data_from_vault(&vault_sk).await?;
     println!("Writing user data to disk...");
     crate::user_data::write_local_user_data(&user_data)?;
+    crate::user_data::write_sync_state(&user_data)?;
 
     println!("✅ Successfully loaded vault with:");
     println!("{} public file archive(s)", user_data.file_archives.len());
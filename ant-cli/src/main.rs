@@ -26,7 +26,10 @@ use color_eyre::Result;
 
 use ant_logging::metrics::init_metrics;
 use ant_logging::{LogBuilder, LogFormat, ReloadHandle, WorkerGuard};
-use ant_protocol::version;
+use ant_protocol::{
+    network_params::{self, NetworkParams},
+    version,
+};
 use opt::Opt;
 use tracing::Level;
 
@@ -35,8 +38,13 @@ async fn main() -> Result<()> {
     color_eyre::install().expect("Failed to initialise error handler");
     let opt = Opt::parse();
     if let Some(network_id) = opt.network_id {
-        ant_protocol::version::set_network_id(network_id);
+        version::set_network_id(network_id);
     }
+    network_params::set_network_params(NetworkParams::with_overrides(
+        opt.close_group_size,
+        opt.replication_factor,
+        opt.min_quotes_to_pay,
+    ))?;
 
     // The clone is necessary to resolve a clippy warning related to a mutex.
     let identify_protocol_str = version::IDENTIFY_PROTOCOL_STR
@@ -11,7 +11,7 @@ use ant_bootstrap::PeersArgs;
 use ant_logging::{LogFormat, LogOutputDest};
 use clap::Parser;
 use color_eyre::Result;
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 // Please do not remove the blank lines in these doc comments.
 // They are used for inserting line breaks when the help menu is rendered in the UI.
@@ -59,6 +59,25 @@ pub(crate) struct Opt {
     #[clap(long, verbatim_doc_comment)]
     pub network_id: Option<u8>,
 
+    /// Specify the close group size of the network, i.e. the number of nodes responsible for a record.
+    ///
+    /// It must match the one used by the nodes of the network. By default, it is set to 5, the size
+    /// used by the mainnet.
+    #[clap(long, verbatim_doc_comment)]
+    pub close_group_size: Option<usize>,
+
+    /// Specify the replication factor of the network, i.e. the number of nodes a record is replicated to.
+    ///
+    /// By default, it is set to the close group size + 2.
+    #[clap(long, verbatim_doc_comment)]
+    pub replication_factor: Option<usize>,
+
+    /// Specify the number of quotes to pay for to store a record on the network.
+    ///
+    /// By default, it is set to the close group size.
+    #[clap(long, verbatim_doc_comment)]
+    pub min_quotes_to_pay: Option<usize>,
+
     /// Prevent verification of data storage on the network.
     ///
     /// This may increase operation speed, but offers no guarantees that operations were successful.
@@ -68,6 +87,35 @@ pub(crate) struct Opt {
     #[command(flatten)]
     pub(crate) peers: PeersArgs,
 
+    /// Enable the TCP transport alongside QUIC, to be able to connect to peers only reachable over TCP.
+    #[clap(global = true, long)]
+    pub tcp: bool,
+
+    /// Enable the WebSocket transport alongside QUIC, to be able to connect to peers only reachable over WebSocket.
+    #[clap(global = true, long)]
+    pub websocket: bool,
+
+    /// Specify the file holding the pre-shared key of a private network, in the libp2p `swarm.key` format.
+    ///
+    /// The connections are made over TCP only, so the peers must be given by their TCP addresses.
+    #[clap(global = true, long, verbatim_doc_comment)]
+    pub network_key_file: Option<PathBuf>,
+
+    /// Specify the file listing the peer IDs of the nodes and clients allowed in a private network,
+    /// one per line.
+    ///
+    /// The client only connects to these peers. The nodes must list the peer ID of the client too,
+    /// hence the client has to connect with a fixed identity, see `--peer-key-file`.
+    #[clap(global = true, long, verbatim_doc_comment)]
+    pub allowed_peers_file: Option<PathBuf>,
+
+    /// Specify the file holding the ed25519 secret key the client connects with, instead of a random one.
+    ///
+    /// The file is created if it doesn't exist. The peer ID it gives is printed when connecting, to be
+    /// added to the allowed peers of a private network.
+    #[clap(global = true, long, verbatim_doc_comment)]
+    pub peer_key_file: Option<PathBuf>,
+
     /// Print the package version.
     #[cfg(not(feature = "nightly"))]
     #[clap(long)]
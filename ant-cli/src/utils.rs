@@ -7,6 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use autonomi::client::{Amount, ClientEvent, UploadSummary};
+use autonomi::QueryTrace;
 
 /// Collects upload summary from the event receiver.
 /// Send a signal to the returned sender to stop collecting and to return the result via the join handle.
@@ -31,6 +32,7 @@ pub fn collect_upload_summary(
                             record_count += upload_summary.records_paid;
                             records_already_paid += upload_summary.records_already_paid;
                         }
+                        Some(ClientEvent::QueryTrace(_)) => {}
                         None => break,
                     }
                 }
@@ -46,6 +48,7 @@ pub fn collect_upload_summary(
                     record_count += upload_summary.records_paid;
                     records_already_paid += upload_summary.records_already_paid;
                 }
+                ClientEvent::QueryTrace(_) => {}
             }
         }
 
@@ -58,3 +61,47 @@ pub fn collect_upload_summary(
 
     (stats_thread, upload_completed_tx)
 }
+
+/// Collects the query traces from the event receiver.
+/// Send a signal to the returned sender to stop collecting and to return the traces via the join handle.
+pub fn collect_query_traces(
+    mut event_receiver: tokio::sync::mpsc::Receiver<ClientEvent>,
+) -> (
+    tokio::task::JoinHandle<Vec<QueryTrace>>,
+    tokio::sync::oneshot::Sender<()>,
+) {
+    let (completed_tx, mut completed_rx) = tokio::sync::oneshot::channel::<()>();
+    let traces_thread = tokio::spawn(async move {
+        let mut traces = vec![];
+
+        loop {
+            tokio::select! {
+                event = event_receiver.recv() => {
+                    match event {
+                        Some(ClientEvent::QueryTrace(trace)) => traces.push(trace),
+                        Some(_) => {}
+                        None => break,
+                    }
+                }
+                _ = &mut completed_rx => break,
+            }
+        }
+
+        // try to drain the event receiver in case there are any more events
+        while let Ok(event) = event_receiver.try_recv() {
+            if let ClientEvent::QueryTrace(trace) = event {
+                traces.push(trace);
+            }
+        }
+
+        traces
+    });
+
+    (traces_thread, completed_tx)
+}
+
+/// Prints a value as pretty JSON to stdout, for commands run with the `--json` flag.
+pub fn print_json(value: &serde_json::Value) -> color_eyre::Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
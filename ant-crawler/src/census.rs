@@ -0,0 +1,286 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_evm::QuotingMetrics;
+use color_eyre::eyre::Result;
+use libp2p::{Multiaddr, PeerId};
+use serde::Serialize;
+use std::{collections::BTreeMap, fmt, io::Write, time::Duration};
+
+/// The agent version reported for the peers that we couldn't identify.
+const UNKNOWN_VERSION: &str = "unknown";
+
+/// What we learnt about a peer of the network.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct PeerRecord {
+    pub(crate) peer_id: PeerId,
+    /// Whether we managed to connect to the peer
+    pub(crate) reachable: bool,
+    pub(crate) agent_version: Option<String>,
+    pub(crate) protocol_version: Option<String>,
+    pub(crate) addresses: Vec<Multiaddr>,
+    /// The number of peers in the routing table of the peer
+    pub(crate) routing_table_size: Option<usize>,
+    pub(crate) quoting_metrics: Option<QuotingMetrics>,
+}
+
+impl PeerRecord {
+    pub(crate) fn new(peer_id: PeerId, addresses: Vec<Multiaddr>) -> Self {
+        Self {
+            peer_id,
+            reachable: false,
+            agent_version: None,
+            protocol_version: None,
+            addresses,
+            routing_table_size: None,
+            quoting_metrics: None,
+        }
+    }
+}
+
+/// An overview of the network, drawn from the peers found by the crawl.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Summary {
+    pub(crate) discovered_peers: usize,
+    pub(crate) reachable_peers: usize,
+    /// The median of the network size estimated by the nodes, or the number of discovered peers
+    /// when it is larger, as a crawl can't find more peers than there are
+    pub(crate) estimated_network_size: u64,
+    /// The number of peers running each agent version
+    pub(crate) versions: BTreeMap<String, usize>,
+    /// The records stored by all the nodes that reported their quoting metrics
+    pub(crate) total_records_stored: u64,
+    pub(crate) average_records_per_node: f64,
+    /// The average ratio of the records stored to the max records, between 0 and 1
+    pub(crate) average_fullness: f64,
+    pub(crate) crawl_duration_secs: u64,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Discovered peers: {}", self.discovered_peers)?;
+        writeln!(f, "Reachable peers: {}", self.reachable_peers)?;
+        writeln!(f, "Estimated network size: {}", self.estimated_network_size)?;
+        writeln!(f, "Versions:")?;
+        for (version, count) in &self.versions {
+            writeln!(f, "  {version}: {count}")?;
+        }
+        writeln!(f, "Total records stored: {}", self.total_records_stored)?;
+        writeln!(
+            f,
+            "Average records per node: {:.1}",
+            self.average_records_per_node
+        )?;
+        writeln!(f, "Average fullness: {:.2}%", self.average_fullness * 100.0)?;
+        write!(f, "Crawl duration: {}s", self.crawl_duration_secs)
+    }
+}
+
+/// The result of a crawl: every peer found, along with the summary.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct Census {
+    pub(crate) summary: Summary,
+    pub(crate) peers: Vec<PeerRecord>,
+}
+
+/// A row of the CSV output. The quoting metrics are flattened into the columns that matter for a
+/// census, as CSV can't hold nested values.
+#[derive(Serialize)]
+struct CsvRow<'a> {
+    peer_id: String,
+    reachable: bool,
+    agent_version: &'a str,
+    protocol_version: &'a str,
+    addresses: String,
+    routing_table_size: Option<usize>,
+    records_stored: Option<usize>,
+    max_records: Option<usize>,
+    received_payment_count: Option<usize>,
+    live_time_secs: Option<u64>,
+    reported_network_size: Option<u64>,
+}
+
+impl Census {
+    pub(crate) fn new(peers: Vec<PeerRecord>, crawl_duration: Duration) -> Self {
+        let reachable_peers = peers.iter().filter(|peer| peer.reachable).count();
+
+        let mut versions = BTreeMap::new();
+        for peer in peers.iter().filter(|peer| peer.reachable) {
+            let version = peer.agent_version.as_deref().unwrap_or(UNKNOWN_VERSION);
+            *versions.entry(version.to_string()).or_insert(0) += 1;
+        }
+
+        let metrics = peers
+            .iter()
+            .filter_map(|peer| peer.quoting_metrics.as_ref())
+            .collect::<Vec<_>>();
+
+        let mut reported_sizes = metrics
+            .iter()
+            .filter_map(|metrics| metrics.network_size)
+            .collect::<Vec<_>>();
+        reported_sizes.sort_unstable();
+        let median_reported_size = reported_sizes
+            .get(reported_sizes.len() / 2)
+            .copied()
+            .unwrap_or_default();
+        let estimated_network_size = median_reported_size.max(peers.len() as u64);
+
+        let total_records_stored = metrics
+            .iter()
+            .map(|metrics| metrics.close_records_stored as u64)
+            .sum::<u64>();
+        let (average_records_per_node, average_fullness) = if metrics.is_empty() {
+            (0.0, 0.0)
+        } else {
+            let fullness = metrics
+                .iter()
+                .filter(|metrics| metrics.max_records > 0)
+                .map(|metrics| metrics.close_records_stored as f64 / metrics.max_records as f64)
+                .sum::<f64>();
+            (
+                total_records_stored as f64 / metrics.len() as f64,
+                fullness / metrics.len() as f64,
+            )
+        };
+
+        let summary = Summary {
+            discovered_peers: peers.len(),
+            reachable_peers,
+            estimated_network_size,
+            versions,
+            total_records_stored,
+            average_records_per_node,
+            average_fullness,
+            crawl_duration_secs: crawl_duration.as_secs(),
+        };
+        Self { summary, peers }
+    }
+
+    pub(crate) fn write_json(&self, writer: impl Write) -> Result<()> {
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    /// Write a row per peer. The summary is not part of the CSV output.
+    pub(crate) fn write_csv(&self, writer: impl Write) -> Result<()> {
+        let mut writer = csv::Writer::from_writer(writer);
+        for peer in &self.peers {
+            let metrics = peer.quoting_metrics.as_ref();
+            writer.serialize(CsvRow {
+                peer_id: peer.peer_id.to_string(),
+                reachable: peer.reachable,
+                agent_version: peer.agent_version.as_deref().unwrap_or_default(),
+                protocol_version: peer.protocol_version.as_deref().unwrap_or_default(),
+                addresses: peer
+                    .addresses
+                    .iter()
+                    .map(|addr| addr.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                routing_table_size: peer.routing_table_size,
+                records_stored: metrics.map(|metrics| metrics.close_records_stored),
+                max_records: metrics.map(|metrics| metrics.max_records),
+                received_payment_count: metrics.map(|metrics| metrics.received_payment_count),
+                live_time_secs: metrics.map(|metrics| metrics.live_time),
+                reported_network_size: metrics.and_then(|metrics| metrics.network_size),
+            })?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(records: usize, max_records: usize, network_size: u64) -> QuotingMetrics {
+        QuotingMetrics {
+            data_type: 0,
+            data_size: 1,
+            close_records_stored: records,
+            records_per_type: vec![],
+            max_records,
+            received_payment_count: 0,
+            live_time: 1,
+            network_density: None,
+            network_size: Some(network_size),
+        }
+    }
+
+    fn reachable_peer(agent_version: &str, metrics: Option<QuotingMetrics>) -> PeerRecord {
+        let mut peer = PeerRecord::new(PeerId::random(), vec![]);
+        peer.reachable = true;
+        peer.agent_version = Some(agent_version.to_string());
+        peer.quoting_metrics = metrics;
+        peer
+    }
+
+    #[test]
+    fn test_census_summary() {
+        let peers = vec![
+            reachable_peer("ant/node/0.3/1", Some(metrics(10, 100, 40))),
+            reachable_peer("ant/node/0.3/1", Some(metrics(30, 100, 50))),
+            reachable_peer("ant/node/0.4/1", Some(metrics(50, 100, 60))),
+            reachable_peer("ant/node/0.4/1", None),
+            PeerRecord::new(PeerId::random(), vec![]),
+        ];
+        let census = Census::new(peers, Duration::from_secs(42));
+
+        assert_eq!(
+            census.summary,
+            Summary {
+                discovered_peers: 5,
+                reachable_peers: 4,
+                estimated_network_size: 50,
+                versions: BTreeMap::from([
+                    ("ant/node/0.3/1".to_string(), 2),
+                    ("ant/node/0.4/1".to_string(), 2)
+                ]),
+                total_records_stored: 90,
+                average_records_per_node: 30.0,
+                average_fullness: 0.3,
+                crawl_duration_secs: 42,
+            }
+        );
+    }
+
+    #[test]
+    fn test_census_network_size_is_at_least_the_discovered_peers() {
+        let peers = vec![
+            reachable_peer("ant/node/0.3/1", Some(metrics(10, 100, 1))),
+            reachable_peer("ant/node/0.3/1", None),
+            PeerRecord::new(PeerId::random(), vec![]),
+        ];
+        let census = Census::new(peers, Duration::ZERO);
+        assert_eq!(census.summary.estimated_network_size, 3);
+        assert_eq!(census.summary.versions.get(UNKNOWN_VERSION), None);
+    }
+
+    #[test]
+    fn test_census_csv_has_a_row_per_peer() -> Result<()> {
+        let peers = vec![
+            reachable_peer("ant/node/0.3/1", Some(metrics(10, 100, 40))),
+            PeerRecord::new(
+                PeerId::random(),
+                vec!["/ip4/127.0.0.1/udp/12000/quic-v1".parse()?],
+            ),
+        ];
+        let census = Census::new(peers, Duration::ZERO);
+
+        let mut csv = vec![];
+        census.write_csv(&mut csv)?;
+        let csv = String::from_utf8(csv)?;
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("peer_id,reachable,agent_version"));
+        assert!(lines[2].contains("/ip4/127.0.0.1/udp/12000/quic-v1"));
+        Ok(())
+    }
+}
@@ -0,0 +1,423 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::census::{Census, PeerRecord};
+use ant_bootstrap::multiaddr_get_peer_id;
+use ant_networking::multiaddr_is_global;
+use ant_protocol::{
+    messages::{Query, QueryResponse, Request, Response},
+    storage::DataTypes,
+    version::{IDENTIFY_CLIENT_VERSION_STR, IDENTIFY_PROTOCOL_STR, REQ_RESPONSE_VERSION_STR},
+    NetworkAddress,
+};
+use color_eyre::eyre::{eyre, Result};
+use futures::StreamExt;
+use libp2p::{
+    identify,
+    multiaddr::Protocol,
+    noise,
+    request_response::{self, OutboundRequestId, ProtocolSupport},
+    swarm::{dial_opts::DialOpts, NetworkBehaviour, SwarmEvent},
+    tcp, yamux, Multiaddr, PeerId, StreamProtocol, Swarm,
+};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+use tracing::{debug, info, warn};
+
+/// The range covering the whole address space, to have a node return its whole routing table.
+const FULL_RANGE: [u8; 32] = [0xff; 32];
+
+/// Timeout of the requests sent to a peer.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a connection is kept open once we are done with the peer.
+const IDLE_CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the progress of the crawl is logged.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(NetworkBehaviour)]
+#[behaviour(to_swarm = "CrawlerEvent")]
+struct CrawlerBehaviour {
+    identify: identify::Behaviour,
+    request_response: request_response::cbor::Behaviour<Request, Response>,
+}
+
+#[allow(clippy::large_enum_variant)]
+enum CrawlerEvent {
+    Identify(identify::Event),
+    RequestResponse(request_response::Event<Request, Response>),
+}
+
+impl From<identify::Event> for CrawlerEvent {
+    fn from(event: identify::Event) -> Self {
+        CrawlerEvent::Identify(event)
+    }
+}
+
+impl From<request_response::Event<Request, Response>> for CrawlerEvent {
+    fn from(event: request_response::Event<Request, Response>) -> Self {
+        CrawlerEvent::RequestResponse(event)
+    }
+}
+
+/// The state of a peer being crawled.
+#[derive(Debug, Default)]
+struct InFlight {
+    pending_requests: usize,
+    identified: bool,
+}
+
+impl InFlight {
+    fn is_done(&self) -> bool {
+        self.pending_requests == 0 && self.identified
+    }
+}
+
+/// Crawls the network, by asking every peer found for its routing table, until no new peer is
+/// found.
+///
+/// The crawler connects as a client, so it is not added to the routing table of the nodes.
+pub(crate) struct Crawler {
+    swarm: Swarm<CrawlerBehaviour>,
+    local: bool,
+    concurrency: usize,
+    fetch_quotes: bool,
+    peers: BTreeMap<PeerId, PeerRecord>,
+    to_crawl: VecDeque<PeerId>,
+    in_flight: HashMap<PeerId, InFlight>,
+    requests: HashMap<OutboundRequestId, PeerId>,
+}
+
+impl Crawler {
+    pub(crate) fn new(local: bool, concurrency: usize, fetch_quotes: bool) -> Result<Self> {
+        let identify_protocol_str = IDENTIFY_PROTOCOL_STR
+            .read()
+            .expect("Failed to obtain read lock for IDENTIFY_PROTOCOL_STR")
+            .clone();
+        let agent_version = IDENTIFY_CLIENT_VERSION_STR
+            .read()
+            .expect("Failed to obtain read lock for IDENTIFY_CLIENT_VERSION_STR")
+            .clone();
+        let req_res_version_str = REQ_RESPONSE_VERSION_STR
+            .read()
+            .expect("Failed to obtain read lock for REQ_RESPONSE_VERSION_STR")
+            .clone();
+        let req_res_protocol = StreamProtocol::try_from_owned(req_res_version_str)
+            .map_err(|err| eyre!("Invalid request response protocol: {err}"))?;
+
+        let swarm = libp2p::SwarmBuilder::with_new_identity()
+            .with_tokio()
+            .with_tcp(
+                tcp::Config::default().nodelay(true),
+                noise::Config::new,
+                yamux::Config::default,
+            )?
+            .with_quic()
+            .with_behaviour(|keypair| CrawlerBehaviour {
+                identify: identify::Behaviour::new(
+                    identify::Config::new(identify_protocol_str, keypair.public())
+                        .with_agent_version(agent_version)
+                        .with_hide_listen_addrs(true),
+                ),
+                request_response: request_response::cbor::Behaviour::new(
+                    [(req_res_protocol, ProtocolSupport::Outbound)],
+                    request_response::Config::default().with_request_timeout(REQUEST_TIMEOUT),
+                ),
+            })?
+            .with_swarm_config(|cfg| cfg.with_idle_connection_timeout(IDLE_CONNECTION_TIMEOUT))
+            .build();
+        info!("Crawling as {}", swarm.local_peer_id());
+
+        Ok(Self {
+            swarm,
+            local,
+            concurrency: concurrency.max(1),
+            fetch_quotes,
+            peers: BTreeMap::new(),
+            to_crawl: VecDeque::new(),
+            in_flight: HashMap::new(),
+            requests: HashMap::new(),
+        })
+    }
+
+    /// Crawl the network starting from the bootstrap peers, until every peer found has been
+    /// crawled or the timeout is reached.
+    pub(crate) async fn crawl(
+        mut self,
+        bootstrap_addrs: Vec<Multiaddr>,
+        timeout: Duration,
+    ) -> Result<Census> {
+        for addr in bootstrap_addrs {
+            match multiaddr_get_peer_id(&addr) {
+                Some(peer_id) => self.add_peer(peer_id, vec![addr]),
+                None => warn!("Ignoring the bootstrap addr {addr} without a peer id"),
+            }
+        }
+        if self.to_crawl.is_empty() {
+            return Err(eyre!("No bootstrap peer to start the crawl from"));
+        }
+
+        let start = Instant::now();
+        let deadline = tokio::time::sleep(timeout);
+        tokio::pin!(deadline);
+        let mut progress = tokio::time::interval(PROGRESS_INTERVAL);
+
+        loop {
+            self.dial_next_peers();
+            if self.to_crawl.is_empty() && self.in_flight.is_empty() {
+                info!("No more peers to crawl");
+                break;
+            }
+
+            tokio::select! {
+                event = self.swarm.select_next_some() => self.handle_swarm_event(event),
+                _ = progress.tick() => {
+                    info!(
+                        "Found {} peers, {} crawled, {} in flight, {} queued",
+                        self.peers.len(),
+                        self.peers.len() - self.to_crawl.len() - self.in_flight.len(),
+                        self.in_flight.len(),
+                        self.to_crawl.len(),
+                    );
+                }
+                _ = &mut deadline => {
+                    warn!(
+                        "The crawl timed out with {} peers in flight and {} queued",
+                        self.in_flight.len(),
+                        self.to_crawl.len()
+                    );
+                    break;
+                }
+            }
+        }
+
+        Ok(Census::new(
+            self.peers.into_values().collect(),
+            start.elapsed(),
+        ))
+    }
+
+    /// Queue a peer to be crawled, if not known yet.
+    fn add_peer(&mut self, peer_id: PeerId, addrs: Vec<Multiaddr>) {
+        if peer_id == *self.swarm.local_peer_id() || self.peers.contains_key(&peer_id) {
+            return;
+        }
+        let addrs = addrs
+            .into_iter()
+            .filter(|addr| self.local || multiaddr_is_global(addr))
+            .map(|addr| strip_p2p(&addr))
+            .collect::<Vec<_>>();
+        if addrs.is_empty() {
+            debug!("Ignoring {peer_id:?} without any dialable addr");
+            return;
+        }
+        let _ = self
+            .peers
+            .insert(peer_id, PeerRecord::new(peer_id, addrs.clone()));
+        self.to_crawl.push_back(peer_id);
+    }
+
+    fn dial_next_peers(&mut self) {
+        while self.in_flight.len() < self.concurrency {
+            let Some(peer_id) = self.to_crawl.pop_front() else {
+                return;
+            };
+            let addrs = self
+                .peers
+                .get(&peer_id)
+                .map(|peer| peer.addresses.clone())
+                .unwrap_or_default();
+            let opts = DialOpts::peer_id(peer_id).addresses(addrs).build();
+            if let Err(err) = self.swarm.dial(opts) {
+                debug!("Failed to dial {peer_id:?}: {err}");
+                continue;
+            }
+            let _ = self.in_flight.insert(peer_id, InFlight::default());
+        }
+    }
+
+    fn handle_swarm_event(&mut self, event: SwarmEvent<CrawlerEvent>) {
+        match event {
+            SwarmEvent::ConnectionEstablished {
+                peer_id,
+                num_established,
+                ..
+            } => {
+                if num_established.get() > 1 || !self.in_flight.contains_key(&peer_id) {
+                    return;
+                }
+                if let Some(peer) = self.peers.get_mut(&peer_id) {
+                    peer.reachable = true;
+                }
+                self.send_request(
+                    peer_id,
+                    Query::GetClosestPeers {
+                        key: NetworkAddress::from_peer(peer_id),
+                        num_of_peers: None,
+                        range: Some(FULL_RANGE),
+                        sign_result: false,
+                    },
+                );
+                if self.fetch_quotes {
+                    self.send_request(
+                        peer_id,
+                        Query::GetStoreQuote {
+                            key: NetworkAddress::from_peer(peer_id),
+                            data_type: DataTypes::Chunk.get_index(),
+                            data_size: 1,
+                            nonce: None,
+                            difficulty: 0,
+                        },
+                    );
+                }
+            }
+            SwarmEvent::OutgoingConnectionError {
+                peer_id: Some(peer_id),
+                error,
+                ..
+            } => {
+                if self.in_flight.remove(&peer_id).is_none() {
+                    return;
+                }
+                debug!("Failed to connect to {peer_id:?}: {error}");
+            }
+            SwarmEvent::Behaviour(CrawlerEvent::Identify(identify::Event::Received {
+                peer_id,
+                info,
+                ..
+            })) => {
+                if let Some(peer) = self.peers.get_mut(&peer_id) {
+                    peer.agent_version = Some(info.agent_version);
+                    peer.protocol_version = Some(info.protocol_version);
+                }
+                if let Some(in_flight) = self.in_flight.get_mut(&peer_id) {
+                    in_flight.identified = true;
+                }
+                self.complete_if_done(peer_id);
+            }
+            SwarmEvent::Behaviour(CrawlerEvent::Identify(identify::Event::Error {
+                peer_id,
+                error,
+                ..
+            })) => {
+                debug!("Failed to identify {peer_id:?}: {error}");
+                if let Some(in_flight) = self.in_flight.get_mut(&peer_id) {
+                    in_flight.identified = true;
+                }
+                self.complete_if_done(peer_id);
+            }
+            SwarmEvent::Behaviour(CrawlerEvent::RequestResponse(
+                request_response::Event::Message {
+                    peer,
+                    message:
+                        request_response::Message::Response {
+                            request_id,
+                            response,
+                        },
+                    ..
+                },
+            )) => {
+                let _ = self.requests.remove(&request_id);
+                self.handle_response(peer, response);
+                self.request_completed(peer);
+            }
+            SwarmEvent::Behaviour(CrawlerEvent::RequestResponse(
+                request_response::Event::OutboundFailure {
+                    peer,
+                    request_id,
+                    error,
+                    ..
+                },
+            )) => {
+                if self.requests.remove(&request_id).is_none() {
+                    return;
+                }
+                debug!("Request {request_id:?} to {peer:?} failed: {error}");
+                self.request_completed(peer);
+            }
+            SwarmEvent::ConnectionClosed {
+                peer_id,
+                num_established: 0,
+                ..
+            } => {
+                if self.in_flight.remove(&peer_id).is_none() {
+                    return;
+                }
+                // the peer went away before answering all our requests
+                debug!("Connection to {peer_id:?} closed before the crawl of it completed");
+                self.requests.retain(|_, peer| *peer != peer_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn send_request(&mut self, peer_id: PeerId, query: Query) {
+        let request_id = self
+            .swarm
+            .behaviour_mut()
+            .request_response
+            .send_request(&peer_id, Request::Query(query));
+        let _ = self.requests.insert(request_id, peer_id);
+        if let Some(in_flight) = self.in_flight.get_mut(&peer_id) {
+            in_flight.pending_requests += 1;
+        }
+    }
+
+    fn handle_response(&mut self, peer_id: PeerId, response: Response) {
+        match response {
+            Response::Query(QueryResponse::GetClosestPeers { peers, .. }) => {
+                if let Some(peer) = self.peers.get_mut(&peer_id) {
+                    peer.routing_table_size = Some(peers.len());
+                }
+                for (address, addrs) in peers {
+                    if let Some(found) = address.as_peer_id() {
+                        self.add_peer(found, addrs);
+                    }
+                }
+            }
+            Response::Query(QueryResponse::GetStoreQuote { quote, .. }) => match quote {
+                Ok(quote) => {
+                    if let Some(peer) = self.peers.get_mut(&peer_id) {
+                        peer.quoting_metrics = Some(quote.quoting_metrics);
+                    }
+                }
+                Err(err) => debug!("{peer_id:?} didn't quote: {err}"),
+            },
+            other => warn!("Unexpected response from {peer_id:?}: {other:?}"),
+        }
+    }
+
+    fn request_completed(&mut self, peer_id: PeerId) {
+        if let Some(in_flight) = self.in_flight.get_mut(&peer_id) {
+            in_flight.pending_requests = in_flight.pending_requests.saturating_sub(1);
+        }
+        self.complete_if_done(peer_id);
+    }
+
+    /// Disconnect from the peer once it answered all our requests, to make room for the next one.
+    fn complete_if_done(&mut self, peer_id: PeerId) {
+        if !self
+            .in_flight
+            .get(&peer_id)
+            .is_some_and(|in_flight| in_flight.is_done())
+        {
+            return;
+        }
+        let _ = self.in_flight.remove(&peer_id);
+        let _ = self.swarm.disconnect_peer_id(peer_id);
+    }
+}
+
+fn strip_p2p(addr: &Multiaddr) -> Multiaddr {
+    addr.iter()
+        .filter(|protocol| !matches!(protocol, Protocol::P2p(_)))
+        .collect()
+}
@@ -0,0 +1,167 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+mod census;
+mod crawler;
+
+use ant_bootstrap::PeersArgs;
+use ant_protocol::network_params::{set_network_params, NetworkParams};
+use clap::{Parser, ValueEnum};
+use color_eyre::eyre::Result;
+use crawler::Crawler;
+use std::{fs::File, io::BufWriter, path::PathBuf, time::Duration};
+use tracing::info;
+use tracing_log::AsTrace;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Json,
+    Csv,
+}
+
+/// Crawl the network to take a census of its nodes.
+///
+/// Every peer found is asked for its routing table, to find more peers, and for a quote, to learn
+/// how many records it stores. The summary of the census is printed once the crawl completes.
+#[derive(Debug, Parser)]
+#[clap(disable_version_flag = true)]
+struct Opt {
+    #[command(flatten)]
+    peers: PeersArgs,
+
+    /// Write the record of every peer found to this file.
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+
+    /// The format of the output file.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+
+    /// The number of peers crawled at the same time.
+    #[clap(long, default_value_t = 50)]
+    concurrency: usize,
+
+    /// Stop the crawl after this many seconds, even if some peers haven't been crawled yet.
+    #[clap(long, default_value_t = 600)]
+    timeout: u64,
+
+    /// Do not ask the nodes for a quote.
+    ///
+    /// The quoting metrics, like the number of records stored, won't be part of the census.
+    #[clap(long)]
+    no_quotes: bool,
+
+    /// Specify the network ID to use. This will allow you to crawl a different network.
+    ///
+    /// By default, the network ID is set to 1, which represents the mainnet.
+    #[clap(long, verbatim_doc_comment)]
+    network_id: Option<u8>,
+
+    /// Specify the close group size of the network to crawl, if other than the mainnet's.
+    #[clap(long)]
+    close_group_size: Option<usize>,
+
+    /// Specify the replication factor of the network to crawl, if other than the close group size + 2.
+    #[clap(long)]
+    replication_factor: Option<usize>,
+
+    /// Specify the number of quotes to pay of the network to crawl, if other than the close group size.
+    #[clap(long)]
+    min_quotes_to_pay: Option<usize>,
+
+    #[command(flatten)]
+    verbose: clap_verbosity_flag::Verbosity,
+
+    /// Print the crate version
+    #[clap(long)]
+    crate_version: bool,
+
+    /// Print the package version
+    #[clap(long)]
+    #[cfg(not(feature = "nightly"))]
+    package_version: bool,
+
+    /// Print version information.
+    #[clap(long)]
+    version: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let opt = Opt::parse();
+
+    if opt.version {
+        println!(
+            "{}",
+            ant_build_info::version_string(
+                "Autonomi Network Crawler",
+                env!("CARGO_PKG_VERSION"),
+                None
+            )
+        );
+        return Ok(());
+    }
+
+    if opt.crate_version {
+        println!("Crate version: {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    if opt.package_version {
+        println!("Package version: {}", ant_build_info::package_version());
+        return Ok(());
+    }
+
+    let registry = tracing_subscriber::registry().with(tracing_subscriber::fmt::layer());
+    // Use `RUST_LOG` if set, else use the verbosity flag (where `-vvvv` is trace level).
+    let _ = if std::env::var_os("RUST_LOG").is_some() {
+        registry.with(EnvFilter::from_env("RUST_LOG")).try_init()
+    } else {
+        let filter = tracing_subscriber::filter::Targets::new().with_target(
+            env!("CARGO_BIN_NAME").replace('-', "_"),
+            opt.verbose.log_level_filter().as_trace(),
+        );
+        registry.with(filter).try_init()
+    };
+
+    if let Some(network_id) = opt.network_id {
+        ant_protocol::version::set_network_id(network_id);
+    }
+    // the network params are part of the protocol strings, they must match the nodes' ones
+    set_network_params(NetworkParams::with_overrides(
+        opt.close_group_size,
+        opt.replication_factor,
+        opt.min_quotes_to_pay,
+    ))?;
+
+    let bootstrap_addrs = opt.peers.get_addrs(None, None).await?;
+    info!("Starting the crawl from {} peers", bootstrap_addrs.len());
+
+    let crawler = Crawler::new(opt.peers.local, opt.concurrency, !opt.no_quotes)?;
+    let census = crawler
+        .crawl(bootstrap_addrs, Duration::from_secs(opt.timeout))
+        .await?;
+
+    if let Some(path) = &opt.output {
+        let writer = BufWriter::new(File::create(path)?);
+        match opt.format {
+            OutputFormat::Json => census.write_json(writer)?,
+            OutputFormat::Csv => census.write_csv(writer)?,
+        }
+        println!("The census has been written to {path:?}");
+    }
+    println!("{}", census.summary);
+
+    Ok(())
+}
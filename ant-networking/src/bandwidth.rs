@@ -0,0 +1,491 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+#[cfg(feature = "open-metrics")]
+use crate::metrics::bandwidth::BandwidthMetrics;
+use crate::time::{sleep, Duration, Instant};
+use futures::{ready, AsyncRead, AsyncWrite, Future};
+use libp2p::{
+    core::muxing::{StreamMuxer, StreamMuxerBox, StreamMuxerEvent, SubstreamBox},
+    PeerId,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll},
+};
+use tokio::time::Sleep;
+
+/// Once a global limit has been hit, the bandwidth is considered saturated for this long.
+const SATURATION_WINDOW: Duration = Duration::from_secs(10);
+
+/// The shortest time a throttled stream waits for, to not wake it up for a handful of bytes.
+const MIN_THROTTLE_WAIT: Duration = Duration::from_millis(10);
+
+/// The limits on the bandwidth used by a node, in bytes per second. `None` is unlimited.
+///
+/// The global limits apply to the connections to all the peers combined, while the per peer
+/// limits apply to the connections to each peer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BandwidthLimits {
+    /// The upload limit across all the peers
+    pub upload: Option<u64>,
+    /// The download limit across all the peers
+    pub download: Option<u64>,
+    /// The upload limit to each peer
+    pub peer_upload: Option<u64>,
+    /// The download limit from each peer
+    pub peer_download: Option<u64>,
+}
+
+/// The bytes exchanged with one or more peers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Traffic {
+    pub inbound_bytes: u64,
+    pub outbound_bytes: u64,
+}
+
+impl Traffic {
+    fn add(&mut self, direction: Direction, bytes: u64) {
+        match direction {
+            Direction::Inbound => self.inbound_bytes = self.inbound_bytes.saturating_add(bytes),
+            Direction::Outbound => self.outbound_bytes = self.outbound_bytes.saturating_add(bytes),
+        }
+    }
+}
+
+/// The traffic of the node since it started, along with the traffic of each connected peer since
+/// it got connected.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BandwidthStats {
+    pub total: Traffic,
+    pub peers: BTreeMap<PeerId, Traffic>,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "open-metrics",
+    derive(prometheus_client::encoding::EncodeLabelValue)
+)]
+pub(crate) enum Direction {
+    Inbound,
+    Outbound,
+}
+
+/// A token bucket refilled at `rate` bytes per second, holding up to a second worth of bytes.
+///
+/// The bytes are taken out after they went through, so the tokens can go negative. A stream is
+/// then held back until the debt has been paid off.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u64) -> Self {
+        let rate = rate.max(1) as f64;
+        Self {
+            rate,
+            tokens: rate,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// The time to wait for tokens to be available, `None` if there are some already.
+    fn wait_time(&mut self) -> Option<Duration> {
+        let now = Instant::now();
+        let refill = now.duration_since(self.last_refill).as_secs_f64() * self.rate;
+        self.tokens = (self.tokens + refill).min(self.rate);
+        self.last_refill = now;
+
+        if self.tokens > 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(-self.tokens / self.rate).max(MIN_THROTTLE_WAIT))
+        }
+    }
+
+    fn consume(&mut self, bytes: u64) {
+        self.tokens -= bytes as f64;
+    }
+}
+
+#[derive(Debug)]
+struct PeerState {
+    traffic: Traffic,
+    upload: Option<TokenBucket>,
+    download: Option<TokenBucket>,
+    connections: usize,
+}
+
+#[derive(Debug)]
+struct TrackerState {
+    limits: BandwidthLimits,
+    total: Traffic,
+    upload: Option<TokenBucket>,
+    download: Option<TokenBucket>,
+    peers: HashMap<PeerId, PeerState>,
+    /// The last time a global limit has been hit
+    saturated_at: Option<Instant>,
+    #[cfg(feature = "open-metrics")]
+    metrics: Option<BandwidthMetrics>,
+}
+
+/// Keeps track of the bytes going through the connections of the node, and holds the streams
+/// back once the limits are hit.
+#[derive(Debug, Clone)]
+pub(crate) struct BandwidthTracker {
+    state: Arc<Mutex<TrackerState>>,
+}
+
+impl BandwidthTracker {
+    pub(crate) fn new(limits: BandwidthLimits) -> Self {
+        let state = TrackerState {
+            limits,
+            total: Traffic::default(),
+            upload: limits.upload.map(TokenBucket::new),
+            download: limits.download.map(TokenBucket::new),
+            peers: HashMap::new(),
+            saturated_at: None,
+            #[cfg(feature = "open-metrics")]
+            metrics: None,
+        };
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    #[cfg(feature = "open-metrics")]
+    pub(crate) fn set_metrics(&self, metrics: BandwidthMetrics) {
+        self.state().metrics = Some(metrics);
+    }
+
+    pub(crate) fn stats(&self) -> BandwidthStats {
+        let state = self.state();
+        BandwidthStats {
+            total: state.total,
+            peers: state
+                .peers
+                .iter()
+                .map(|(peer_id, peer)| (*peer_id, peer.traffic))
+                .collect(),
+        }
+    }
+
+    /// Whether a global limit has been hit recently. Background work, like replication, shall
+    /// then be held back to leave the bandwidth to the client requests.
+    pub(crate) fn is_saturated(&self) -> bool {
+        self.state()
+            .saturated_at
+            .is_some_and(|saturated_at| saturated_at.elapsed() < SATURATION_WINDOW)
+    }
+
+    /// Account and throttle the streams of a new connection to the peer.
+    pub(crate) fn wrap_muxer(&self, peer_id: PeerId, muxer: StreamMuxerBox) -> StreamMuxerBox {
+        {
+            let mut state = self.state();
+            let limits = state.limits;
+            let peer = state.peers.entry(peer_id).or_insert_with(|| PeerState {
+                traffic: Traffic::default(),
+                upload: limits.peer_upload.map(TokenBucket::new),
+                download: limits.peer_download.map(TokenBucket::new),
+                connections: 0,
+            });
+            peer.connections += 1;
+        }
+
+        StreamMuxerBox::new(BandwidthMuxer {
+            inner: muxer,
+            connection: Arc::new(PeerConnection {
+                peer_id,
+                tracker: self.clone(),
+            }),
+        })
+    }
+
+    fn state(&self) -> MutexGuard<'_, TrackerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn connection_closed(&self, peer_id: &PeerId) {
+        let mut state = self.state();
+        let Some(peer) = state.peers.get_mut(peer_id) else {
+            return;
+        };
+        peer.connections = peer.connections.saturating_sub(1);
+        if peer.connections == 0 {
+            let _ = state.peers.remove(peer_id);
+            #[cfg(feature = "open-metrics")]
+            if let Some(metrics) = &state.metrics {
+                metrics.remove_peer(peer_id);
+            }
+        }
+    }
+
+    fn wait_time(&self, peer_id: &PeerId, direction: Direction) -> Option<Duration> {
+        let mut state = self.state();
+        let state = &mut *state;
+
+        let global_bucket = match direction {
+            Direction::Inbound => state.download.as_mut(),
+            Direction::Outbound => state.upload.as_mut(),
+        };
+        let global_wait = global_bucket.and_then(TokenBucket::wait_time);
+        if global_wait.is_some() {
+            state.saturated_at = Some(Instant::now());
+        }
+
+        let peer_wait = state
+            .peers
+            .get_mut(peer_id)
+            .and_then(|peer| match direction {
+                Direction::Inbound => peer.download.as_mut(),
+                Direction::Outbound => peer.upload.as_mut(),
+            })
+            .and_then(TokenBucket::wait_time);
+
+        let wait = global_wait.max(peer_wait);
+        #[cfg(feature = "open-metrics")]
+        if let (Some(_), Some(metrics)) = (wait, &state.metrics) {
+            metrics.record_throttled(direction);
+        }
+        wait
+    }
+
+    fn record(&self, peer_id: &PeerId, direction: Direction, bytes: usize) {
+        if bytes == 0 {
+            return;
+        }
+        let bytes = bytes as u64;
+        let mut state = self.state();
+        let state = &mut *state;
+
+        state.total.add(direction, bytes);
+        let global_bucket = match direction {
+            Direction::Inbound => state.download.as_mut(),
+            Direction::Outbound => state.upload.as_mut(),
+        };
+        if let Some(bucket) = global_bucket {
+            bucket.consume(bytes);
+        }
+
+        if let Some(peer) = state.peers.get_mut(peer_id) {
+            peer.traffic.add(direction, bytes);
+            let peer_bucket = match direction {
+                Direction::Inbound => peer.download.as_mut(),
+                Direction::Outbound => peer.upload.as_mut(),
+            };
+            if let Some(bucket) = peer_bucket {
+                bucket.consume(bytes);
+            }
+        }
+
+        #[cfg(feature = "open-metrics")]
+        if let Some(metrics) = &state.metrics {
+            metrics.record(peer_id, direction, bytes);
+        }
+    }
+}
+
+/// A connection to a peer, shared by the muxer and its streams. The peer is forgotten once all
+/// its connections have been dropped.
+#[derive(Debug)]
+struct PeerConnection {
+    peer_id: PeerId,
+    tracker: BandwidthTracker,
+}
+
+impl PeerConnection {
+    /// Wait until the limits of the direction allow more bytes to go through.
+    fn poll_throttle(
+        &self,
+        cx: &mut Context<'_>,
+        direction: Direction,
+        delay: &mut Option<Pin<Box<Sleep>>>,
+    ) -> Poll<()> {
+        loop {
+            if let Some(sleep) = delay.as_mut() {
+                ready!(sleep.as_mut().poll(cx));
+                *delay = None;
+            }
+            match self.tracker.wait_time(&self.peer_id, direction) {
+                Some(wait) => *delay = Some(Box::pin(sleep(wait))),
+                None => return Poll::Ready(()),
+            }
+        }
+    }
+}
+
+impl Drop for PeerConnection {
+    fn drop(&mut self) {
+        self.tracker.connection_closed(&self.peer_id);
+    }
+}
+
+struct BandwidthMuxer {
+    inner: StreamMuxerBox,
+    connection: Arc<PeerConnection>,
+}
+
+impl BandwidthMuxer {
+    fn wrap_stream(&self, inner: SubstreamBox) -> BandwidthStream {
+        BandwidthStream {
+            inner,
+            connection: Arc::clone(&self.connection),
+            read_delay: None,
+            write_delay: None,
+        }
+    }
+}
+
+impl StreamMuxer for BandwidthMuxer {
+    type Substream = BandwidthStream;
+    type Error = io::Error;
+
+    fn poll_inbound(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Self::Substream, Self::Error>> {
+        let this = self.get_mut();
+        let inner = ready!(Pin::new(&mut this.inner).poll_inbound(cx))?;
+        Poll::Ready(Ok(this.wrap_stream(inner)))
+    }
+
+    fn poll_outbound(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Self::Substream, Self::Error>> {
+        let this = self.get_mut();
+        let inner = ready!(Pin::new(&mut this.inner).poll_outbound(cx))?;
+        Poll::Ready(Ok(this.wrap_stream(inner)))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<StreamMuxerEvent, Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll(cx)
+    }
+}
+
+struct BandwidthStream {
+    inner: SubstreamBox,
+    connection: Arc<PeerConnection>,
+    read_delay: Option<Pin<Box<Sleep>>>,
+    write_delay: Option<Pin<Box<Sleep>>>,
+}
+
+impl AsyncRead for BandwidthStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this
+            .connection
+            .poll_throttle(cx, Direction::Inbound, &mut this.read_delay));
+        let bytes = ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        this.connection
+            .tracker
+            .record(&this.connection.peer_id, Direction::Inbound, bytes);
+        Poll::Ready(Ok(bytes))
+    }
+}
+
+impl AsyncWrite for BandwidthStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this
+            .connection
+            .poll_throttle(cx, Direction::Outbound, &mut this.write_delay));
+        let bytes = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        this.connection
+            .tracker
+            .record(&this.connection.peer_id, Direction::Outbound, bytes);
+        Poll::Ready(Ok(bytes))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_throttles_after_the_burst() {
+        let mut bucket = TokenBucket::new(1000);
+        assert_eq!(bucket.wait_time(), None);
+
+        // a second worth of bytes plus half a second of debt
+        bucket.consume(1500);
+        let wait = bucket.wait_time().expect("the bucket to be empty");
+        assert!(wait > Duration::from_millis(400) && wait <= Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_tracker_accounts_per_peer() {
+        let tracker = BandwidthTracker::new(BandwidthLimits {
+            upload: Some(1000),
+            peer_download: Some(100),
+            ..Default::default()
+        });
+        let peer = PeerId::random();
+        let other_peer = PeerId::random();
+        for peer_id in [peer, other_peer] {
+            tracker.state().peers.insert(
+                peer_id,
+                PeerState {
+                    traffic: Traffic::default(),
+                    upload: None,
+                    download: Some(TokenBucket::new(100)),
+                    connections: 1,
+                },
+            );
+        }
+
+        tracker.record(&peer, Direction::Inbound, 200);
+        tracker.record(&other_peer, Direction::Outbound, 500);
+        assert!(tracker.wait_time(&peer, Direction::Inbound).is_some());
+        assert!(tracker.wait_time(&other_peer, Direction::Inbound).is_none());
+        assert!(!tracker.is_saturated());
+
+        tracker.record(&peer, Direction::Outbound, 600);
+        assert!(tracker
+            .wait_time(&other_peer, Direction::Outbound)
+            .is_some());
+        assert!(tracker.is_saturated());
+
+        let stats = tracker.stats();
+        assert_eq!(stats.total.inbound_bytes, 200);
+        assert_eq!(stats.total.outbound_bytes, 1100);
+        assert_eq!(stats.peers[&peer].outbound_bytes, 600);
+
+        tracker.connection_closed(&peer);
+        assert!(!tracker.stats().peers.contains_key(&peer));
+    }
+}
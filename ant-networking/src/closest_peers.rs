@@ -0,0 +1,232 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::Result;
+use ant_protocol::{
+    messages::{verify_closest_peers, QueryResponse, Response},
+    NetworkAddress,
+};
+use libp2p::{Multiaddr, PeerId};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The closest peers to a target, cross checked between the signed answers of the peers closest
+/// to it.
+///
+/// Honest peers close to the target have a similar view of its neighbourhood. A peer whose answer
+/// diverges from the majority is either lying about its closest peers, e.g. to eclipse the target
+/// with peers it controls, or has a poor view of the network.
+#[derive(Debug, Clone)]
+pub struct VerifiedClosestPeers {
+    pub target: NetworkAddress,
+    /// The peers closest to the target, sorted by their distance to it.
+    /// Only the peers known to a majority of the valid answers are part of it.
+    pub closest_peers: Vec<(PeerId, Vec<Multiaddr>)>,
+    /// The responders whose answer agrees with the majority
+    pub consistent: Vec<PeerId>,
+    /// The responders whose answer is validly signed, but diverges from the majority
+    pub divergent: Vec<PeerId>,
+    /// The responders that failed to answer, or whose answer isn't validly signed
+    pub invalid: Vec<PeerId>,
+}
+
+impl VerifiedClosestPeers {
+    /// Whether every responder sent a validly signed answer that agrees with the majority.
+    pub fn is_consistent(&self) -> bool {
+        self.divergent.is_empty() && self.invalid.is_empty()
+    }
+}
+
+/// Verify the signatures of the answers to a `Query::GetClosestPeers`, then cross check them to
+/// find the `num_of_peers` closest peers to the target that the majority agrees on.
+pub(crate) fn cross_check_closest_peers(
+    target: &NetworkAddress,
+    responses: BTreeMap<PeerId, Result<Response>>,
+    num_of_peers: usize,
+) -> VerifiedClosestPeers {
+    let mut invalid = vec![];
+    // the view of each responder includes itself, as a node doesn't return itself
+    let mut views = BTreeMap::new();
+    let mut known_addrs: BTreeMap<PeerId, BTreeSet<Multiaddr>> = BTreeMap::new();
+
+    for (responder, response) in responses {
+        let (peers, signature) = match response {
+            Ok(Response::Query(QueryResponse::GetClosestPeers {
+                target: answered_target,
+                peers,
+                signature: Some(signature),
+            })) if answered_target == *target => (peers, signature),
+            other => {
+                warn!("Invalid answer from {responder:?} for the closest peers to {target:?}: {other:?}");
+                invalid.push(responder);
+                continue;
+            }
+        };
+        if !verify_closest_peers(&responder, target, &peers, &signature) {
+            warn!("Invalid signature from {responder:?} for the closest peers to {target:?}");
+            invalid.push(responder);
+            continue;
+        }
+
+        let mut view = vec![responder];
+        for (address, addrs) in peers {
+            let Some(peer_id) = address.as_peer_id() else {
+                continue;
+            };
+            known_addrs.entry(peer_id).or_default().extend(addrs);
+            view.push(peer_id);
+        }
+        view.sort_by_key(|peer_id| target.distance(&NetworkAddress::from_peer(*peer_id)));
+        view.dedup();
+        view.truncate(num_of_peers);
+        let _ = views.insert(responder, view);
+    }
+
+    let majority = views.len() / 2 + 1;
+    let mut votes: BTreeMap<PeerId, usize> = BTreeMap::new();
+    for peer_id in views.values().flatten() {
+        *votes.entry(*peer_id).or_default() += 1;
+    }
+    let mut closest = votes
+        .into_iter()
+        .filter(|(_, count)| *count >= majority)
+        .map(|(peer_id, _)| peer_id)
+        .collect::<Vec<_>>();
+    closest.sort_by_key(|peer_id| target.distance(&NetworkAddress::from_peer(*peer_id)));
+    closest.truncate(num_of_peers);
+
+    // a view must hold a majority of the closest peers to be consistent
+    let required_overlap = closest.len() / 2 + 1;
+    let (consistent, divergent): (Vec<_>, Vec<_>) = views.into_iter().partition(|(_, view)| {
+        closest.is_empty()
+            || view
+                .iter()
+                .filter(|peer_id| closest.contains(peer_id))
+                .count()
+                >= required_overlap
+    });
+    let divergent = divergent
+        .into_iter()
+        .map(|(responder, _)| responder)
+        .collect::<Vec<_>>();
+    if !divergent.is_empty() {
+        warn!(
+            "The closest peers to {target:?} reported by {divergent:?} diverge from the majority"
+        );
+    }
+
+    VerifiedClosestPeers {
+        target: target.clone(),
+        closest_peers: closest
+            .into_iter()
+            .map(|peer_id| {
+                let addrs = known_addrs
+                    .remove(&peer_id)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect();
+                (peer_id, addrs)
+            })
+            .collect(),
+        consistent: consistent
+            .into_iter()
+            .map(|(responder, _)| responder)
+            .collect(),
+        divergent,
+        invalid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NetworkError;
+    use ant_protocol::messages::closest_peers_bytes;
+    use libp2p::identity::Keypair;
+
+    fn signed_answer(keypair: &Keypair, target: &NetworkAddress, peers: &[PeerId]) -> Response {
+        let peers = peers
+            .iter()
+            .map(|peer_id| {
+                (
+                    NetworkAddress::from_peer(*peer_id),
+                    vec!["/ip4/10.0.0.1/udp/12000/quic-v1"
+                        .parse()
+                        .expect("Failed to parse multiaddr")],
+                )
+            })
+            .collect::<Vec<_>>();
+        let signature = keypair
+            .sign(&closest_peers_bytes(target, &peers))
+            .expect("Failed to sign");
+        Response::Query(QueryResponse::GetClosestPeers {
+            target: target.clone(),
+            peers,
+            signature: Some(signature),
+        })
+    }
+
+    #[test]
+    fn test_cross_check_closest_peers() {
+        let target = NetworkAddress::from_peer(PeerId::random());
+        let keypairs = (0..5)
+            .map(|_| Keypair::generate_ed25519())
+            .collect::<Vec<_>>();
+        let honest = keypairs
+            .iter()
+            .map(|keypair| PeerId::from(keypair.public()))
+            .collect::<Vec<_>>();
+        let sybils = (0..5).map(|_| PeerId::random()).collect::<Vec<_>>();
+
+        let mut responses = BTreeMap::new();
+        for (keypair, peer_id) in keypairs.iter().zip(&honest).take(3) {
+            let others = honest
+                .iter()
+                .filter(|other| *other != peer_id)
+                .copied()
+                .collect::<Vec<_>>();
+            let _ = responses.insert(*peer_id, Ok(signed_answer(keypair, &target, &others)));
+        }
+        // a node lying about its closest peers, to eclipse the target with its sybils
+        let _ = responses.insert(honest[3], Ok(signed_answer(&keypairs[3], &target, &sybils)));
+        // a node answering with the signature of another node
+        let mut forged = signed_answer(&keypairs[0], &target, &sybils);
+        if let Response::Query(QueryResponse::GetClosestPeers { signature, .. }) = &mut forged {
+            let _ = signature.replace(keypairs[0].sign(b"something else").expect("Failed to sign"));
+        }
+        let _ = responses.insert(honest[4], Ok(forged));
+        let unreachable = PeerId::random();
+        let _ = responses.insert(unreachable, Err(NetworkError::InternalMsgChannelDropped));
+
+        let verified = cross_check_closest_peers(&target, responses, 5);
+
+        let mut expected_closest = honest.clone();
+        expected_closest
+            .sort_by_key(|peer_id| target.distance(&NetworkAddress::from_peer(*peer_id)));
+        assert_eq!(
+            verified
+                .closest_peers
+                .iter()
+                .map(|(peer_id, _)| *peer_id)
+                .collect::<Vec<_>>(),
+            expected_closest
+        );
+        assert!(verified
+            .closest_peers
+            .iter()
+            .all(|(_, addrs)| !addrs.is_empty()));
+
+        let mut consistent = honest[..3].to_vec();
+        consistent.sort();
+        assert_eq!(verified.consistent, consistent);
+        assert_eq!(verified.divergent, vec![honest[3]]);
+        let mut invalid = vec![honest[4], unreachable];
+        invalid.sort();
+        assert_eq!(verified.invalid, invalid);
+        assert!(!verified.is_consistent());
+    }
+}
@@ -7,16 +7,19 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
+    bandwidth::BandwidthStats,
     config::GetRecordCfg,
     driver::{PendingGetClosestType, SwarmDriver},
     error::{NetworkError, Result},
     event::TerminateNodeReason,
     log_markers::Marker,
-    multiaddr_pop_p2p, GetRecordError, MsgResponder, NetworkEvent, ResponseQuorum,
-    CLOSE_GROUP_SIZE,
+    multiaddr_pop_p2p,
+    query_trace::{AttemptTrace, GetRecordTrace},
+    GetRecordError, MsgResponder, NetworkEvent,
 };
 use ant_evm::{PaymentQuote, QuotingMetrics};
 use ant_protocol::{
+    close_group_size,
     messages::{Cmd, Request, Response},
     storage::{DataTypes, RecordHeader, RecordKind, ValidationType},
     NetworkAddress, PrettyPrintRecordKey,
@@ -29,7 +32,7 @@ use libp2p::{
     Multiaddr, PeerId,
 };
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{hash_map::Entry, BTreeMap, HashMap},
     fmt::Debug,
     time::Duration,
 };
@@ -83,6 +86,10 @@ pub enum LocalSwarmCmd {
     GetKBuckets {
         sender: oneshot::Sender<BTreeMap<u32, Vec<PeerId>>>,
     },
+    /// Get the traffic of the node and of each of its connected peers.
+    GetBandwidthStats {
+        sender: oneshot::Sender<BandwidthStats>,
+    },
     /// Returns the replicate candidates in range.
     /// In case the range is too narrow, returns at lease CLOSE_GROUP_SIZE peers.
     GetReplicateCandidates {
@@ -216,20 +223,8 @@ pub enum NetworkSwarmCmd {
         key: RecordKey,
         sender: oneshot::Sender<std::result::Result<Record, GetRecordError>>,
         cfg: GetRecordCfg,
-    },
-
-    /// Put record to network
-    PutRecord {
-        record: Record,
-        sender: oneshot::Sender<Result<()>>,
-        quorum: ResponseQuorum,
-    },
-    /// Put record to specific node
-    PutRecordTo {
-        peers: Vec<PeerId>,
-        record: Record,
-        sender: oneshot::Sender<Result<()>>,
-        quorum: ResponseQuorum,
+        /// Set when the query is traced, to receive the peers that answered it
+        trace_sender: Option<oneshot::Sender<AttemptTrace>>,
     },
 }
 
@@ -300,6 +295,9 @@ impl Debug for LocalSwarmCmd {
             LocalSwarmCmd::GetKBuckets { .. } => {
                 write!(f, "LocalSwarmCmd::GetKBuckets")
             }
+            LocalSwarmCmd::GetBandwidthStats { .. } => {
+                write!(f, "LocalSwarmCmd::GetBandwidthStats")
+            }
             LocalSwarmCmd::GetSwarmLocalState { .. } => {
                 write!(f, "LocalSwarmCmd::GetSwarmLocalState")
             }
@@ -373,20 +371,6 @@ impl Debug for NetworkSwarmCmd {
                     PrettyPrintRecordKey::from(key)
                 )
             }
-            NetworkSwarmCmd::PutRecord { record, .. } => {
-                write!(
-                    f,
-                    "NetworkSwarmCmd::PutRecord {{ key: {:?} }}",
-                    PrettyPrintRecordKey::from(&record.key)
-                )
-            }
-            NetworkSwarmCmd::PutRecordTo { peers, record, .. } => {
-                write!(
-                    f,
-                    "NetworkSwarmCmd::PutRecordTo {{ peers: {peers:?}, key: {:?} }}",
-                    PrettyPrintRecordKey::from(&record.key)
-                )
-            }
             NetworkSwarmCmd::GetClosestPeersToAddressFromNetwork { key, .. } => {
                 write!(f, "NetworkSwarmCmd::GetClosestPeers {{ key: {key:?} }}")
             }
@@ -416,7 +400,12 @@ impl SwarmDriver {
         let start = Instant::now();
         let cmd_string;
         match cmd {
-            NetworkSwarmCmd::GetNetworkRecord { key, sender, cfg } => {
+            NetworkSwarmCmd::GetNetworkRecord {
+                key,
+                sender,
+                cfg,
+                trace_sender,
+            } => {
                 cmd_string = "GetNetworkRecord";
 
                 for (pending_query, (inflight_record_query_key, senders, _, _)) in
@@ -428,6 +417,16 @@ impl SwarmDriver {
                             PrettyPrintRecordKey::from(&key)
                         );
                         senders.push(sender);
+                        if let Some(trace_sender) = trace_sender {
+                            match self.pending_get_record_traces.entry(*pending_query) {
+                                Entry::Occupied(mut entry) => {
+                                    entry.get_mut().senders.push(trace_sender)
+                                }
+                                Entry::Vacant(entry) => {
+                                    let _ = entry.insert(GetRecordTrace::new(trace_sender));
+                                }
+                            }
+                        }
 
                         // early exit as we're already processing this query
                         return Ok(());
@@ -449,6 +448,11 @@ impl SwarmDriver {
                 {
                     warn!("An existing get_record task {query_id:?} got replaced");
                 }
+                if let Some(trace_sender) = trace_sender {
+                    let _ = self
+                        .pending_get_record_traces
+                        .insert(query_id, GetRecordTrace::new(trace_sender));
+                }
                 // Logging the status of the `pending_get_record`.
                 // We also interested in the status of `result_map` (which contains record) inside.
                 let total_records: usize = self
@@ -459,63 +463,6 @@ impl SwarmDriver {
                 info!("We now have {} pending get record attempts and cached {total_records} fetched copies",
                       self.pending_get_record.len());
             }
-            NetworkSwarmCmd::PutRecord {
-                record,
-                sender,
-                quorum,
-            } => {
-                cmd_string = "PutRecord";
-                let record_key = PrettyPrintRecordKey::from(&record.key).into_owned();
-                debug!(
-                    "Putting record sized: {:?} to network {:?}",
-                    record.value.len(),
-                    record_key
-                );
-                let res = match self
-                    .swarm
-                    .behaviour_mut()
-                    .kademlia
-                    .put_record(record, quorum.get_kad_quorum())
-                {
-                    Ok(request_id) => {
-                        debug!("Sent record {record_key:?} to network. Request id: {request_id:?} to network");
-                        Ok(())
-                    }
-                    Err(error) => {
-                        error!("Error sending record {record_key:?} to network");
-                        Err(NetworkError::from(error))
-                    }
-                };
-
-                if let Err(err) = sender.send(res) {
-                    error!("Could not send response to PutRecord cmd: {:?}", err);
-                }
-            }
-            NetworkSwarmCmd::PutRecordTo {
-                peers,
-                record,
-                sender,
-                quorum,
-            } => {
-                cmd_string = "PutRecordTo";
-                let record_key = PrettyPrintRecordKey::from(&record.key).into_owned();
-                debug!(
-                    "Putting record {record_key:?} sized: {:?} to {peers:?}",
-                    record.value.len(),
-                );
-                let peers_count = peers.len();
-                let request_id = self.swarm.behaviour_mut().kademlia.put_record_to(
-                    record,
-                    peers.into_iter(),
-                    quorum.get_kad_quorum(),
-                );
-                debug!("Sent record {record_key:?} to {peers_count:?} peers. Request id: {request_id:?}");
-
-                if let Err(err) = sender.send(Ok(())) {
-                    error!("Could not send response to PutRecordTo cmd: {:?}", err);
-                }
-            }
-
             NetworkSwarmCmd::Dial { addr, sender } => {
                 cmd_string = "Dial";
 
@@ -678,11 +625,11 @@ impl SwarmDriver {
                     .kademlia
                     .get_closest_local_peers(&kbucket_key)
                     .map(|peer| peer.into_preimage())
-                    .take(CLOSE_GROUP_SIZE)
+                    .take(close_group_size())
                     .collect();
                 // In case of not enough clsest_peers, send the entire list
-                if closest_peers.len() >= CLOSE_GROUP_SIZE {
-                    let boundary_peer = closest_peers[CLOSE_GROUP_SIZE - 1];
+                if closest_peers.len() >= close_group_size() {
+                    let boundary_peer = closest_peers[close_group_size() - 1];
                     let key_address = NetworkAddress::from_record_key(&key);
                     let boundary_distance =
                         key_address.distance(&NetworkAddress::from_peer(boundary_peer));
@@ -871,6 +818,15 @@ impl SwarmDriver {
                 }
                 let _ = sender.send(ilog2_kbuckets);
             }
+            LocalSwarmCmd::GetBandwidthStats { sender } => {
+                cmd_string = "GetBandwidthStats";
+                let stats = self
+                    .bandwidth
+                    .as_ref()
+                    .map(|bandwidth| bandwidth.stats())
+                    .unwrap_or_default();
+                let _ = sender.send(stats);
+            }
             LocalSwarmCmd::GetPeersWithMultiaddr { sender } => {
                 cmd_string = "GetPeersWithMultiAddr";
                 let mut result: Vec<(PeerId, Vec<Multiaddr>)> = vec![];
@@ -900,7 +856,7 @@ impl SwarmDriver {
                     .kademlia
                     .get_closest_local_peers(&key)
                     .map(|peer| peer.into_preimage())
-                    .take(CLOSE_GROUP_SIZE)
+                    .take(close_group_size())
                     .collect();
 
                 let _ = sender.send(closest_peers);
@@ -1194,9 +1150,9 @@ impl SwarmDriver {
     ) -> Result<Vec<PeerId>> {
         let is_periodic_replicate = target.as_peer_id().is_some();
         let expected_candidates = if is_periodic_replicate {
-            CLOSE_GROUP_SIZE * 2
+            close_group_size() * 2
         } else {
-            CLOSE_GROUP_SIZE
+            close_group_size()
         };
 
         // get closest peers from buckets, sorted by increasing distance to the target
@@ -7,16 +7,18 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use ant_protocol::{
-    messages::{ChunkProof, Nonce},
-    PrettyPrintRecordKey, CLOSE_GROUP_SIZE,
+    close_group_size,
+    messages::{ChunkProof, Nonce, PutRecordRejection},
+    PrettyPrintRecordKey,
 };
 use core::fmt::{self, Debug};
 use exponential_backoff::Backoff;
-use libp2p::{
-    kad::{Quorum, Record},
-    PeerId,
+use libp2p::{kad::Record, PeerId};
+use std::{
+    collections::{BTreeMap, HashSet},
+    num::NonZeroUsize,
+    time::Duration,
 };
-use std::{collections::HashSet, num::NonZeroUsize, time::Duration};
 
 use crate::close_group_majority;
 
@@ -106,20 +108,11 @@ impl std::str::FromStr for ResponseQuorum {
 }
 
 impl ResponseQuorum {
-    pub(crate) fn get_kad_quorum(&self) -> Quorum {
-        match self {
-            ResponseQuorum::One => Quorum::One,
-            ResponseQuorum::Majority => Quorum::Majority,
-            ResponseQuorum::All => Quorum::All,
-            ResponseQuorum::N(n) => Quorum::N(*n),
-        }
-    }
-
     /// Get the value of the provided Quorum
     pub fn get_value(&self) -> usize {
         match self {
             ResponseQuorum::Majority => close_group_majority(),
-            ResponseQuorum::All => CLOSE_GROUP_SIZE,
+            ResponseQuorum::All => close_group_size(),
             ResponseQuorum::N(v) => v.get(),
             ResponseQuorum::One => 1,
         }
@@ -173,19 +166,28 @@ impl Debug for GetRecordCfg {
 /// The various settings related to writing a record to the network.
 #[derive(Debug, Clone)]
 pub struct PutRecordCfg {
-    /// The quorum used by KAD PUT. KAD still sends out the request to all the peers set by the `replication_factor`, it
-    /// just makes sure that we get atleast `n` successful responses defined by the Quorum.
-    /// Our nodes currently send `Ok()` response for every KAD PUT. Thus this field does not do anything atm.
+    /// The number of peers that must acknowledge the PUT. The record is still sent to all the target peers, but the
+    /// PUT only succeeds once `n` of them have validated, stored and signed an acknowledgement for the record.
     pub put_quorum: ResponseQuorum,
     /// If enabled, the provided `RetryStrategy` is used to retry if a PUT attempt fails.
+    /// Retries are only sent to the peers that did not acknowledge or permanently reject the record.
     pub retry_strategy: RetryStrategy,
-    /// Use the `kad::put_record_to` to PUT the record only to the specified peers. If this option is set to None, we
-    /// will be using `kad::put_record` which would PUT the record to all the closest members of the record.
+    /// PUT the record only to the specified peers. If this option is set to None, the record is PUT to the closest
+    /// peers of the record.
     pub use_put_record_to: Option<Vec<PeerId>>,
     /// Enables verification after writing. The VerificationKind is used to determine the method to use.
     pub verification: Option<(VerificationKind, GetRecordCfg)>,
 }
 
+/// How the target peers answered a successful PUT.
+#[derive(Debug, Clone, Default)]
+pub struct PutRecordOutcome {
+    /// The peers that validated, stored and signed an acknowledgement for the record
+    pub acknowledged: HashSet<PeerId>,
+    /// The peers that refused to store the record, with their reason
+    pub rejections: BTreeMap<PeerId, PutRecordRejection>,
+}
+
 /// The methods in which verification on a PUT can be carried out.
 #[derive(Debug, Clone)]
 pub enum VerificationKind {
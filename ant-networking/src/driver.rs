@@ -7,6 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
+    bandwidth::{BandwidthLimits, BandwidthTracker},
     bootstrap::{ContinuousNetworkDiscover, NETWORK_DISCOVER_INTERVAL},
     circular_vec::CircularVec,
     cmd::{LocalSwarmCmd, NetworkSwarmCmd},
@@ -19,12 +20,16 @@ use crate::{
     log_markers::Marker,
     multiaddr_pop_p2p,
     network_discovery::NetworkDiscovery,
+    private_network::PrivateNetwork,
+    query_trace::GetRecordTrace,
     record_store::{ClientRecordStore, NodeRecordStore, NodeRecordStoreConfig},
     record_store_api::UnifiedRecordStore,
     relay_manager::RelayManager,
     replication_fetcher::ReplicationFetcher,
+    routing_table_snapshot::NODE_ROUTING_TABLE_SNAPSHOT_FILE,
     time::{interval, spawn, Instant, Interval},
-    transport, GetRecordError, Network, NodeIssue, CLOSE_GROUP_SIZE,
+    transport::{self, TransportConfig},
+    GetRecordError, Network, NodeIssue,
 };
 #[cfg(feature = "open-metrics")]
 use crate::{
@@ -33,7 +38,9 @@ use crate::{
 use ant_bootstrap::BootstrapCacheStore;
 use ant_evm::PaymentQuote;
 use ant_protocol::{
+    close_group_size,
     messages::{Request, Response},
+    replication_factor,
     version::{
         get_network_id, IDENTIFY_CLIENT_VERSION_STR, IDENTIFY_NODE_VERSION_STR,
         IDENTIFY_PROTOCOL_STR, REQ_RESPONSE_VERSION_STR,
@@ -80,6 +87,9 @@ pub(crate) const CLOSET_RECORD_CHECK_INTERVAL: Duration = Duration::from_secs(15
 /// Interval over which we query relay manager to check if we can make any more reservations.
 pub(crate) const RELAY_MANAGER_RESERVATION_INTERVAL: Duration = Duration::from_secs(30);
 
+/// Interval over which we persist the routing table snapshot, if enabled.
+const ROUTING_TABLE_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(30);
+
 const KAD_STREAM_PROTOCOL_ID: StreamProtocol = StreamProtocol::new("/autonomi/kad/1.0.0");
 
 /// The ways in which the Get Closest queries are used.
@@ -129,12 +139,11 @@ const KAD_QUERY_TIMEOUT_S: Duration = Duration::from_secs(10);
 /// This is the max time it should take. Minimum interval at any node will be half this
 const PERIODIC_KAD_BOOTSTRAP_INTERVAL_MAX_S: u64 = 21600;
 
-// Init during compilation, instead of runtime error that should never happen
-// Option<T>::expect will be stabilised as const in the future (https://github.com/rust-lang/rust/issues/67441)
-const REPLICATION_FACTOR: NonZeroUsize = match NonZeroUsize::new(CLOSE_GROUP_SIZE + 2) {
-    Some(v) => v,
-    None => panic!("CLOSE_GROUP_SIZE should not be zero"),
-};
+/// How many nodes _should_ store a record, as configured for the network.
+fn kad_replication_factor() -> NonZeroUsize {
+    // the network params are validated to be above zero
+    NonZeroUsize::new(replication_factor()).unwrap_or(NonZeroUsize::MIN)
+}
 
 impl From<std::convert::Infallible> for NodeEvent {
     fn from(_: std::convert::Infallible) -> Self {
@@ -150,6 +159,9 @@ impl From<std::convert::Infallible> for NodeEvent {
 pub(super) struct NodeBehaviour {
     pub(super) blocklist:
         libp2p::allow_block_list::Behaviour<libp2p::allow_block_list::BlockedPeers>,
+    /// Denies the connections with the peers outside of the allow-list of a private network.
+    pub(super) allow_list:
+        Toggle<libp2p::allow_block_list::Behaviour<libp2p::allow_block_list::AllowedPeers>>,
     pub(super) identify: libp2p::identify::Behaviour,
     pub(super) upnp: Toggle<libp2p::upnp::tokio::Behaviour>,
     pub(super) relay_client: libp2p::relay::client::Behaviour,
@@ -160,6 +172,7 @@ pub(super) struct NodeBehaviour {
 
 #[derive(Debug)]
 pub struct NetworkBuilder {
+    bandwidth_limits: BandwidthLimits,
     bootstrap_cache: Option<BootstrapCacheStore>,
     concurrency_limit: Option<usize>,
     is_behind_home_network: bool,
@@ -170,13 +183,17 @@ pub struct NetworkBuilder {
     metrics_registries: Option<MetricsRegistries>,
     #[cfg(feature = "open-metrics")]
     metrics_server_port: Option<u16>,
+    private_network: PrivateNetwork,
     request_timeout: Option<Duration>,
+    routing_table_snapshot: Option<PathBuf>,
+    transports: TransportConfig,
     upnp: bool,
 }
 
 impl NetworkBuilder {
     pub fn new(keypair: Keypair, local: bool) -> Self {
         Self {
+            bandwidth_limits: BandwidthLimits::default(),
             bootstrap_cache: None,
             concurrency_limit: None,
             is_behind_home_network: false,
@@ -187,11 +204,19 @@ impl NetworkBuilder {
             metrics_registries: None,
             #[cfg(feature = "open-metrics")]
             metrics_server_port: None,
+            private_network: PrivateNetwork::default(),
             request_timeout: None,
+            routing_table_snapshot: None,
+            transports: TransportConfig::default(),
             upnp: false,
         }
     }
 
+    /// Limit the bandwidth used by the node. Only applies to a node, a client is never limited.
+    pub fn bandwidth_limits(&mut self, limits: BandwidthLimits) {
+        self.bandwidth_limits = limits;
+    }
+
     pub fn bootstrap_cache(&mut self, bootstrap_cache: BootstrapCacheStore) {
         self.bootstrap_cache = Some(bootstrap_cache);
     }
@@ -229,6 +254,25 @@ impl NetworkBuilder {
         self.upnp = upnp;
     }
 
+    /// Persist the peers of the routing table to the file, and seed the routing table with the
+    /// peers saved in it by a previous run, to be connected without bootstrapping from scratch.
+    pub fn routing_table_snapshot(&mut self, path: PathBuf) {
+        self.routing_table_snapshot = Some(path);
+    }
+
+    /// Enable the TCP and/or WebSocket transports alongside QUIC.
+    /// A node listens on the configured ports, while a client is only able to dial them.
+    pub fn transports(&mut self, transports: TransportConfig) {
+        self.transports = transports;
+    }
+
+    /// Restrict the peers able to connect with a network key and/or the nodes allowed in the
+    /// network. With a network key, QUIC is disabled and a node listens on TCP instead, on the
+    /// configured TCP port or else on the port of the listen address.
+    pub fn private_network(&mut self, private_network: PrivateNetwork) {
+        self.private_network = private_network;
+    }
+
     /// Creates a new `SwarmDriver` instance, along with a `Network` handle
     /// for sending commands and an `mpsc::Receiver<NetworkEvent>` for receiving
     /// network events. It initializes the swarm, sets up the transport, and
@@ -243,7 +287,7 @@ impl NetworkBuilder {
     ///
     /// Returns an error if there is a problem initializing the mDNS behaviour.
     pub fn build_node(
-        self,
+        mut self,
         root_dir: PathBuf,
     ) -> Result<(Network, mpsc::Receiver<NetworkEvent>, SwarmDriver)> {
         let bootstrap_interval = rand::thread_rng().gen_range(
@@ -263,13 +307,13 @@ impl NetworkBuilder {
             // 1mb packet size
             .set_max_packet_size(MAX_PACKET_SIZE)
             // How many nodes _should_ store data.
-            .set_replication_factor(REPLICATION_FACTOR)
+            .set_replication_factor(kad_replication_factor())
             .set_query_timeout(KAD_QUERY_TIMEOUT_S)
             // Require iterative queries to use disjoint paths for increased resiliency in the presence of potentially adversarial nodes.
             .disjoint_query_paths(true)
             // Records never expire
             .set_record_ttl(None)
-            .set_replication_factor(REPLICATION_FACTOR)
+            .set_replication_factor(kad_replication_factor())
             .set_periodic_bootstrap_interval(Some(Duration::from_secs(bootstrap_interval)))
             // Emit PUT events for validation prior to insertion into the RecordStore.
             // This is no longer needed as the record_storage::put now can carry out validation.
@@ -313,7 +357,14 @@ impl NetworkBuilder {
             }
         };
 
+        // The routing table is persisted in the root dir, to not bootstrap from scratch on restart
+        if self.routing_table_snapshot.is_none() {
+            self.routing_table_snapshot = Some(root_dir.join(NODE_ROUTING_TABLE_SNAPSHOT_FILE));
+        }
+
         let listen_addr = self.listen_addr;
+        let transports = self.transports;
+        let is_private = self.private_network.key.is_some();
         let upnp = self.upnp;
 
         let (network, events_receiver, mut swarm_driver) =
@@ -322,13 +373,36 @@ impl NetworkBuilder {
         // Listen on the provided address
         let listen_socket_addr = listen_addr.ok_or(NetworkError::ListenAddressNotProvided)?;
 
-        // Listen on QUIC
-        let addr_quic = Multiaddr::from(listen_socket_addr.ip())
-            .with(Protocol::Udp(listen_socket_addr.port()))
-            .with(Protocol::QuicV1);
-        swarm_driver
-            .listen_on(addr_quic)
-            .expect("Multiaddr should be supported by our configured transports");
+        // Listen on QUIC, unless the network key forces TCP
+        if !is_private {
+            let addr_quic = Multiaddr::from(listen_socket_addr.ip())
+                .with(Protocol::Udp(listen_socket_addr.port()))
+                .with(Protocol::QuicV1);
+            swarm_driver
+                .listen_on(addr_quic)
+                .expect("Multiaddr should be supported by our configured transports");
+        }
+
+        // Listen on TCP
+        let tcp_port = transports
+            .tcp
+            .or(is_private.then_some(listen_socket_addr.port()));
+        if let Some(port) = tcp_port {
+            let addr_tcp = Multiaddr::from(listen_socket_addr.ip()).with(Protocol::Tcp(port));
+            swarm_driver
+                .listen_on(addr_tcp)
+                .expect("Multiaddr should be supported by our configured transports");
+        }
+
+        // Listen on WebSocket
+        if let Some(port) = transports.websocket {
+            let addr_ws = Multiaddr::from(listen_socket_addr.ip())
+                .with(Protocol::Tcp(port))
+                .with(Protocol::Ws("/".into()));
+            swarm_driver
+                .listen_on(addr_ws)
+                .expect("Multiaddr should be supported by our configured transports");
+        }
 
         Ok((network, events_receiver, swarm_driver))
     }
@@ -343,11 +417,11 @@ impl NetworkBuilder {
         let _ = kad_cfg
             .set_kbucket_inserts(libp2p::kad::BucketInserts::Manual)
             .set_max_packet_size(MAX_PACKET_SIZE)
-            .set_replication_factor(REPLICATION_FACTOR)
+            .set_replication_factor(kad_replication_factor())
             // Require iterative queries to use disjoint paths for increased resiliency in the presence of potentially adversarial nodes.
             .disjoint_query_paths(true)
             // How many nodes _should_ store data.
-            .set_replication_factor(REPLICATION_FACTOR);
+            .set_replication_factor(kad_replication_factor());
 
         let (network, net_event_recv, driver) =
             self.build(kad_cfg, None, true, ProtocolSupport::Outbound, false);
@@ -385,9 +459,15 @@ impl NetworkBuilder {
 
         // ==== Transport ====
         #[cfg(feature = "open-metrics")]
-        let main_transport = transport::build_transport(&self.keypair, &mut metrics_registries);
+        let main_transport = transport::build_transport(
+            &self.keypair,
+            self.transports,
+            self.private_network.key,
+            &mut metrics_registries,
+        );
         #[cfg(not(feature = "open-metrics"))]
-        let main_transport = transport::build_transport(&self.keypair);
+        let main_transport =
+            transport::build_transport(&self.keypair, self.transports, self.private_network.key);
         let transport = if !self.local {
             debug!("Preventing non-global dials");
             // Wrap upper in a transport that prevents dialing local addresses.
@@ -414,6 +494,17 @@ impl NetworkBuilder {
             })
             .boxed();
 
+        // A node keeps track of the traffic of each peer, and throttles it to the limits
+        let bandwidth = (!is_client).then(|| BandwidthTracker::new(self.bandwidth_limits));
+        let transport = if let Some(bandwidth) = bandwidth.clone() {
+            libp2p::Transport::map(transport, move |(peer_id, muxer), _| {
+                (peer_id, bandwidth.wrap_muxer(peer_id, muxer))
+            })
+            .boxed()
+        } else {
+            transport
+        };
+
         #[cfg(feature = "open-metrics")]
         let metrics_recorder = if let Some(port) = self.metrics_server_port {
             let metrics_recorder = NetworkMetricsRecorder::new(&mut metrics_registries);
@@ -435,6 +526,10 @@ impl NetworkBuilder {
                 )]),
             );
 
+            if let Some(bandwidth) = &bandwidth {
+                bandwidth.set_metrics(metrics_recorder.bandwidth.clone());
+            }
+
             run_metrics_server(metrics_registries, port);
             Some(metrics_recorder)
         } else {
@@ -451,7 +546,11 @@ impl NetworkBuilder {
                 .clone();
 
             info!("Building request response with {req_res_version_str:?}",);
-            request_response::cbor::Behaviour::new(
+            // records are PUT through request response, so requests must be able to carry a whole record
+            let codec = default_codec::<request_response::cbor::Behaviour<Request, Response>>()
+                .set_request_size_maximum(MAX_PACKET_SIZE as u64);
+            request_response::Behaviour::with_codec(
+                codec,
                 [(
                     StreamProtocol::try_from_owned(req_res_version_str)
                         .expect("StreamProtocol should start with a /"),
@@ -539,8 +638,26 @@ impl NetworkBuilder {
             libp2p::relay::Behaviour::new(peer_id, relay_server_cfg)
         };
 
+        let allow_list = self
+            .private_network
+            .allowed_peers
+            .as_ref()
+            .map(|allowed_peers| {
+                info!(
+                    "Only allowing the connections with the {} peers of the private network",
+                    allowed_peers.len()
+                );
+                let mut allow_list = libp2p::allow_block_list::Behaviour::default();
+                for peer_id in allowed_peers {
+                    allow_list.allow_peer(*peer_id);
+                }
+                allow_list
+            })
+            .into(); // Into `Toggle<T>`
+
         let behaviour = NodeBehaviour {
             blocklist: libp2p::allow_block_list::Behaviour::default(),
+            allow_list,
             relay_client: relay_behaviour,
             relay_server,
             upnp,
@@ -555,7 +672,8 @@ impl NetworkBuilder {
         let swarm = Swarm::new(transport, behaviour, peer_id, swarm_config);
 
         let bootstrap = ContinuousNetworkDiscover::new();
-        let replication_fetcher = ReplicationFetcher::new(peer_id, network_event_sender.clone());
+        let replication_fetcher =
+            ReplicationFetcher::new(peer_id, network_event_sender.clone(), bandwidth.clone());
 
         // Enable relay manager for nodes behind home network
         let relay_manager = if !is_client && self.is_behind_home_network {
@@ -589,14 +707,17 @@ impl NetworkBuilder {
             is_client,
             is_behind_home_network: self.is_behind_home_network,
             #[cfg(feature = "open-metrics")]
-            close_group: Vec::with_capacity(CLOSE_GROUP_SIZE),
+            close_group: Vec::with_capacity(close_group_size()),
             peers_in_rt: 0,
             bootstrap,
             bootstrap_cache: self.bootstrap_cache,
+            routing_table_snapshot_path: self.routing_table_snapshot,
+            private_network: self.private_network,
             relay_manager,
             connected_relay_clients: Default::default(),
             external_address_manager,
             replication_fetcher,
+            bandwidth,
             #[cfg(feature = "open-metrics")]
             metrics_recorder,
             // kept here to ensure we can push messages to the channel
@@ -609,6 +730,7 @@ impl NetworkBuilder {
             pending_get_closest_peers: Default::default(),
             pending_requests: Default::default(),
             pending_get_record: Default::default(),
+            pending_get_record_traces: Default::default(),
             // We use 255 here which allows covering a network larger than 64k without any rotating.
             // This is based on the libp2p kad::kBuckets peers distribution.
             dialed_peers: CircularVec::new(255),
@@ -638,6 +760,26 @@ impl NetworkBuilder {
     }
 }
 
+/// The default codec of a request response behaviour.
+/// The cbor codec type is not exported by libp2p, so it can only be obtained through inference.
+fn default_codec<B>() -> B::Codec
+where
+    B: IsRequestResponse,
+    B::Codec: Default,
+{
+    B::Codec::default()
+}
+
+trait IsRequestResponse {
+    type Codec;
+}
+
+impl<C: request_response::Codec + Clone + Send + 'static> IsRequestResponse
+    for request_response::Behaviour<C>
+{
+    type Codec = C;
+}
+
 fn check_and_wipe_storage_dir_if_necessary(
     root_dir: PathBuf,
     storage_dir_path: PathBuf,
@@ -689,12 +831,19 @@ pub struct SwarmDriver {
     pub(crate) peers_in_rt: usize,
     pub(crate) bootstrap: ContinuousNetworkDiscover,
     pub(crate) bootstrap_cache: Option<BootstrapCacheStore>,
+    /// The file where the routing table is persisted, if enabled.
+    pub(crate) routing_table_snapshot_path: Option<PathBuf>,
+    /// The restrictions of the network. The allowed peers are enforced by the `allow_list` behaviour,
+    /// which denies the connections of any other peer as they are established.
+    pub(crate) private_network: PrivateNetwork,
     pub(crate) external_address_manager: Option<ExternalAddressManager>,
     pub(crate) relay_manager: Option<RelayManager>,
     /// The peers that are using our relay service.
     pub(crate) connected_relay_clients: HashSet<PeerId>,
     /// The peers that are closer to our PeerId. Includes self.
     pub(crate) replication_fetcher: ReplicationFetcher,
+    /// The traffic of the peers, only tracked by a node.
+    pub(crate) bandwidth: Option<BandwidthTracker>,
     #[cfg(feature = "open-metrics")]
     pub(crate) metrics_recorder: Option<NetworkMetricsRecorder>,
 
@@ -709,6 +858,8 @@ pub struct SwarmDriver {
     pub(crate) pending_requests:
         HashMap<OutboundRequestId, Option<oneshot::Sender<Result<Response>>>>,
     pub(crate) pending_get_record: PendingGetRecord,
+    /// The GET queries being traced, see `Network::with_query_tracer`
+    pub(crate) pending_get_record_traces: HashMap<QueryId, GetRecordTrace>,
     /// A list of the most recent peers we have dialed ourselves. Old dialed peers are evicted once the vec fills up.
     pub(crate) dialed_peers: CircularVec<PeerId>,
     // A list of random `PeerId` candidates that falls into kbuckets,
@@ -768,6 +919,15 @@ impl SwarmDriver {
             );
         }
 
+        let mut routing_table_snapshot_interval = self
+            .routing_table_snapshot_path
+            .as_ref()
+            .map(|_| interval(ROUTING_TABLE_SNAPSHOT_INTERVAL));
+        if let Some(interval) = routing_table_snapshot_interval.as_mut() {
+            interval.tick().await; // first tick completes immediately
+        }
+        self.seed_routing_table_from_snapshot();
+
         // temporarily skip processing IncomingConnectionError swarm event to avoid log spamming
         let mut previous_incoming_connection_error_event = None;
         loop {
@@ -850,7 +1010,7 @@ impl SwarmDriver {
                         ) = self.kbuckets_status();
                         let estimated_network_size =
                             Self::estimate_network_size(peers_in_non_full_buckets, num_of_full_buckets);
-                        if estimated_network_size <= CLOSE_GROUP_SIZE {
+                        if estimated_network_size <= close_group_size() {
                             info!("Not enough estimated network size {estimated_network_size}, with {peers_in_non_full_buckets} peers_in_non_full_buckets and {num_of_full_buckets}num_of_full_buckets.");
                             continue;
                         }
@@ -859,18 +1019,18 @@ impl SwarmDriver {
                         // The network density (average distance among nodes) can be estimated as:
                         //     network_density = entire_U256_space / estimated_network_size
                         let density = U256::MAX / U256::from(estimated_network_size);
-                        let density_distance = density * U256::from(CLOSE_GROUP_SIZE);
+                        let density_distance = density * U256::from(close_group_size());
 
                         // Use distance to close peer to avoid the situation that
                         // the estimated density_distance is too narrow.
                         let closest_k_peers = self.get_closest_k_value_local_peers();
-                        if closest_k_peers.len() <= CLOSE_GROUP_SIZE + 2 {
+                        if closest_k_peers.len() <= replication_factor() {
                             continue;
                         }
                         // Results are sorted, hence can calculate distance directly
                         // Note: self is included
                         let self_addr = NetworkAddress::from_peer(self.self_peer_id);
-                        let close_peers_distance = self_addr.distance(&NetworkAddress::from_peer(closest_k_peers[CLOSE_GROUP_SIZE + 1]));
+                        let close_peers_distance = self_addr.distance(&NetworkAddress::from_peer(closest_k_peers[replication_factor() - 1]));
 
                         let distance = std::cmp::max(Distance(density_distance), close_peers_distance);
 
@@ -951,8 +1111,13 @@ impl SwarmDriver {
                     trace!("Bootstrap cache synced in {:?}", start.elapsed());
 
                 },
+                Some(()) = Self::conditional_interval(&mut routing_table_snapshot_interval) => {
+                    self.save_routing_table_snapshot(false);
+                },
             }
         }
+
+        self.save_routing_table_snapshot(true);
     }
 
     // --------------------------------------------
@@ -7,7 +7,11 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use ant_protocol::storage::GraphEntryAddress;
-use ant_protocol::{messages::Response, storage::RecordKind, NetworkAddress, PrettyPrintRecordKey};
+use ant_protocol::{
+    messages::{PutRecordRejection, Response},
+    storage::RecordKind,
+    NetworkAddress, PrettyPrintRecordKey,
+};
 use libp2p::{
     kad::{self, QueryId, Record},
     request_response::{OutboundFailure, OutboundRequestId},
@@ -15,7 +19,7 @@ use libp2p::{
     PeerId, TransportError,
 };
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::Debug,
     io,
     path::PathBuf,
@@ -114,6 +118,16 @@ pub enum NetworkError {
     GetRecordError(#[from] GetRecordError),
     #[error("Record not stored by nodes, it could be invalid, else you should retry: {0:?}")]
     RecordNotStoredByNodes(NetworkAddress),
+    #[error("Record {address:?} was stored by {stored} peers, but the quorum is {quorum}. Rejected by: {rejections:?}, no acknowledgement from: {unacknowledged:?}")]
+    PutRecordQuorumFailed {
+        address: NetworkAddress,
+        stored: usize,
+        quorum: usize,
+        /// The reasons given by the peers that refused to store the record
+        rejections: BTreeMap<PeerId, PutRecordRejection>,
+        /// The peers that could not be reached or sent back an invalid acknowledgement
+        unacknowledged: Vec<PeerId>,
+    },
 
     // The RecordKind that was obtained did not match with the expected one
     #[error("The RecordKind obtained from the Record did not match with the expected kind: {0}")]
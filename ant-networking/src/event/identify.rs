@@ -8,6 +8,7 @@
 
 use crate::relay_manager::is_a_relayed_peer;
 use crate::{multiaddr_is_global, multiaddr_strip_p2p, NetworkEvent, SwarmDriver};
+use ant_protocol::network_params::is_network_params_mismatch;
 use ant_protocol::version::{IDENTIFY_NODE_VERSION_STR, IDENTIFY_PROTOCOL_STR};
 use libp2p::identify::Info;
 use libp2p::kad::K_VALUE;
@@ -47,7 +48,11 @@ impl SwarmDriver {
         let our_identify_protocol = IDENTIFY_PROTOCOL_STR.read().expect("IDENTIFY_PROTOCOL_STR has been locked to write. A call to set_network_id performed. This should not happen.").to_string();
 
         if info.protocol_version != our_identify_protocol {
-            warn!(?info.protocol_version, "identify: {peer_id:?} does not have the same protocol. Our IDENTIFY_PROTOCOL_STR: {our_identify_protocol:?}");
+            if is_network_params_mismatch(&our_identify_protocol, &info.protocol_version) {
+                error!(?info.protocol_version, "identify: {peer_id:?} is part of the same network but runs with other network params (close group size, replication factor, quotes to pay). Our IDENTIFY_PROTOCOL_STR: {our_identify_protocol:?}");
+            } else {
+                warn!(?info.protocol_version, "identify: {peer_id:?} does not have the same protocol. Our IDENTIFY_PROTOCOL_STR: {our_identify_protocol:?}");
+            }
 
             self.send_event(NetworkEvent::PeerWithUnsupportedProtocol {
                 our_protocol: our_identify_protocol,
@@ -7,17 +7,24 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
-    driver::PendingGetClosestType, get_graph_entry_from_record, time::Instant, GetRecordCfg,
-    GetRecordError, NetworkError, Result, SwarmDriver, CLOSE_GROUP_SIZE,
+    driver::PendingGetClosestType,
+    get_graph_entry_from_record,
+    query_trace::{KadQueryStats, PeerOutcome},
+    time::Instant,
+    GetRecordCfg, GetRecordError, NetworkError, Result, SwarmDriver,
 };
 use ant_protocol::{
+    close_group_size,
     storage::{try_serialize_record, DataTypes, GraphEntry, RecordKind},
     NetworkAddress, PrettyPrintRecordKey,
 };
 use itertools::Itertools;
-use libp2p::kad::{
-    self, GetClosestPeersError, InboundRequest, PeerRecord, ProgressStep, QueryId, QueryResult,
-    QueryStats, Record, K_VALUE,
+use libp2p::{
+    kad::{
+        self, GetClosestPeersError, InboundRequest, PeerRecord, ProgressStep, QueryId, QueryResult,
+        QueryStats, Record, K_VALUE,
+    },
+    PeerId,
 };
 use std::collections::{hash_map::Entry, BTreeSet, HashSet};
 use tokio::sync::oneshot;
@@ -125,7 +132,12 @@ impl SwarmDriver {
                     PrettyPrintRecordKey::from(&peer_record.record.key),
                     peer_record.peer
                 );
-                self.accumulate_get_record_found(id, peer_record, stats, step)?;
+                let peer = peer_record.peer.unwrap_or(self.self_peer_id);
+                self.trace_get_record_peer(id, peer, PeerOutcome::RecordFound);
+                let kad_stats = KadQueryStats::from(&stats);
+                let result = self.accumulate_get_record_found(id, peer_record, stats, step);
+                self.complete_get_record_trace(id, kad_stats);
+                result?;
             }
             kad::Event::OutboundQueryProgressed {
                 id,
@@ -138,7 +150,13 @@ impl SwarmDriver {
             } => {
                 event_string = "kad_event::get_record::finished_no_additional";
                 debug!("Query task {id:?} of get_record completed with {stats:?} - {step:?} - {cache_candidates:?}");
-                self.handle_get_record_finished(id, step)?;
+                // the closest peers that didn't return the record
+                for peer in cache_candidates.values() {
+                    self.trace_get_record_peer(id, *peer, PeerOutcome::RecordMissing);
+                }
+                let result = self.handle_get_record_finished(id, step);
+                self.complete_get_record_trace(id, KadQueryStats::from(&stats));
+                result?;
             }
             kad::Event::OutboundQueryProgressed {
                 id,
@@ -175,7 +193,15 @@ impl SwarmDriver {
                         );
                     }
                 }
-                self.handle_get_record_error(id, get_record_err, stats, step)?;
+                if let kad::GetRecordError::NotFound { closest_peers, .. } = &get_record_err {
+                    for peer in closest_peers {
+                        self.trace_get_record_peer(id, *peer, PeerOutcome::RecordMissing);
+                    }
+                }
+                let kad_stats = KadQueryStats::from(&stats);
+                let result = self.handle_get_record_error(id, get_record_err, stats, step);
+                self.complete_get_record_trace(id, kad_stats);
+                result?;
             }
             kad::Event::OutboundQueryProgressed {
                 id,
@@ -282,7 +308,7 @@ impl SwarmDriver {
                     },
             } => {
                 event_string = "kad_event::InboundRequest::GetRecord";
-                if !present_locally && num_closer_peers < CLOSE_GROUP_SIZE {
+                if !present_locally && num_closer_peers < close_group_size() {
                     debug!("InboundRequest::GetRecord doesn't have local record, with {num_closer_peers:?} closer_peers");
                 }
             }
@@ -445,7 +471,7 @@ impl SwarmDriver {
                 if let Some(mut query) = self.swarm.behaviour_mut().kademlia.query_mut(&query_id) {
                     query.finish();
                 }
-            } else if usize::from(step.count) >= CLOSE_GROUP_SIZE {
+            } else if usize::from(step.count) >= close_group_size() {
                 debug!("For record {pretty_key:?} task {query_id:?}, got {:?} with {} versions so far.",
                    step.count, result_map.len());
             }
@@ -621,6 +647,24 @@ impl SwarmDriver {
         Ok(())
     }
 
+    /// Records the outcome of a peer contacted by a traced GetRecord query.
+    fn trace_get_record_peer(&mut self, query_id: QueryId, peer: PeerId, outcome: PeerOutcome) {
+        if let Some(trace) = self.pending_get_record_traces.get_mut(&query_id) {
+            trace.add_peer(peer, outcome);
+        }
+    }
+
+    /// Sends the trace of a GetRecord query to the callers, once the result has been returned
+    /// to them.
+    fn complete_get_record_trace(&mut self, query_id: QueryId, kad_stats: KadQueryStats) {
+        if self.pending_get_record.contains_key(&query_id) {
+            return;
+        }
+        if let Some(trace) = self.pending_get_record_traces.remove(&query_id) {
+            trace.complete(kad_stats);
+        }
+    }
+
     fn send_record_after_checking_target(
         senders: Vec<oneshot::Sender<std::result::Result<Record, GetRecordError>>>,
         record: Record,
@@ -21,9 +21,9 @@ use libp2p::{
 };
 
 use ant_evm::{PaymentQuote, ProofOfPayment};
-use ant_protocol::storage::DataTypes;
 #[cfg(feature = "open-metrics")]
-use ant_protocol::CLOSE_GROUP_SIZE;
+use ant_protocol::close_group_size;
+use ant_protocol::storage::DataTypes;
 use ant_protocol::{
     messages::{Query, Request, Response},
     storage::ValidationType,
@@ -110,6 +110,13 @@ pub enum NetworkEvent {
         /// The channel to send the `Response` through
         channel: MsgResponder,
     },
+    /// Incoming record to store from a peer, which awaits the `CmdResponse::PutRecord`
+    PutRecordRequestReceived {
+        /// The record to validate and store
+        record: Record,
+        /// The channel to send the `Response` through
+        channel: MsgResponder,
+    },
     /// Handles the responses that are not awaited at the call site
     ResponseReceived {
         /// Response
@@ -162,6 +169,10 @@ impl Debug for NetworkEvent {
             NetworkEvent::QueryRequestReceived { query, .. } => {
                 write!(f, "NetworkEvent::QueryRequestReceived({query:?})")
             }
+            NetworkEvent::PutRecordRequestReceived { record, .. } => {
+                let pretty_key = PrettyPrintRecordKey::from(&record.key);
+                write!(f, "NetworkEvent::PutRecordRequestReceived({pretty_key:?})")
+            }
             NetworkEvent::ResponseReceived { res, .. } => {
                 write!(f, "NetworkEvent::ResponseReceived({res:?})")
             }
@@ -221,8 +232,10 @@ impl SwarmDriver {
         // this includes self
         let closest_k_peers = self.get_closest_k_value_local_peers();
 
-        let new_closest_peers: Vec<_> =
-            closest_k_peers.into_iter().take(CLOSE_GROUP_SIZE).collect();
+        let new_closest_peers: Vec<_> = closest_k_peers
+            .into_iter()
+            .take(close_group_size())
+            .collect();
 
         let old = self.close_group.iter().cloned().collect::<HashSet<_>>();
         let new_members: Vec<_> = new_closest_peers
@@ -15,7 +15,10 @@ use ant_protocol::{
     storage::ValidationType,
     NetworkAddress,
 };
-use libp2p::request_response::{self, Message};
+use libp2p::{
+    kad::Record,
+    request_response::{self, Message},
+};
 
 impl SwarmDriver {
     /// Forwards `Request` to the upper layers using `Sender<NetworkEvent>`. Sends `Response` to the peers
@@ -93,6 +96,23 @@ impl SwarmDriver {
                                 error!("Received a bad_peer notification from {detected_by:?}, targeting {bad_peer:?}, which is not us.");
                             }
                         }
+                        Request::Cmd(ant_protocol::messages::Cmd::PutRecord {
+                            address,
+                            record,
+                        }) => {
+                            // The response is only sent once the record has been validated,
+                            // so it is up to the upper layer to respond.
+                            let record = Record {
+                                key: address.to_record_key(),
+                                value: record.to_vec(),
+                                publisher: None,
+                                expires: None,
+                            };
+                            self.send_event(NetworkEvent::PutRecordRequestReceived {
+                                record,
+                                channel: MsgResponder::FromPeer(channel),
+                            })
+                        }
                         Request::Query(query) => {
                             self.send_event(NetworkEvent::QueryRequestReceived {
                                 query,
@@ -9,8 +9,10 @@
 #[macro_use]
 extern crate tracing;
 
+mod bandwidth;
 mod bootstrap;
 mod circular_vec;
+mod closest_peers;
 mod cmd;
 mod config;
 mod driver;
@@ -23,10 +25,13 @@ mod log_markers;
 #[cfg(feature = "open-metrics")]
 mod metrics;
 mod network_discovery;
+mod private_network;
+mod query_trace;
 mod record_store;
 mod record_store_api;
 mod relay_manager;
 mod replication_fetcher;
+mod routing_table_snapshot;
 pub mod time;
 mod transport;
 
@@ -35,25 +40,39 @@ use xor_name::XorName;
 
 // re-export arch dependent deps for use in the crate, or above
 pub use self::{
+    bandwidth::{BandwidthLimits, BandwidthStats, Traffic},
+    closest_peers::VerifiedClosestPeers,
     cmd::{NodeIssue, SwarmLocalState},
-    config::{GetRecordCfg, PutRecordCfg, ResponseQuorum, RetryStrategy, VerificationKind},
+    config::{
+        GetRecordCfg, PutRecordCfg, PutRecordOutcome, ResponseQuorum, RetryStrategy,
+        VerificationKind,
+    },
     driver::{NetworkBuilder, SwarmDriver, MAX_PACKET_SIZE},
     error::{GetRecordError, NetworkError},
     event::{MsgResponder, NetworkEvent},
     graph::get_graph_entry_from_record,
+    private_network::{PrivateNetwork, PrivateNetworkError},
+    query_trace::{
+        AttemptTrace, KadQueryStats, PeerOutcome, PeerTrace, QueryKind, QueryTrace, QueryTracer,
+    },
     record_store::NodeRecordStore,
+    transport::TransportConfig,
 };
 #[cfg(feature = "open-metrics")]
 pub use metrics::service::MetricsRegistries;
 pub use time::{interval, sleep, spawn, Instant, Interval};
 
-use self::{cmd::NetworkSwarmCmd, error::Result};
+use self::{cmd::NetworkSwarmCmd, error::Result, query_trace::QueryTraceBuilder};
 use ant_evm::{PaymentQuote, QuotingMetrics};
 use ant_protocol::{
+    close_group_size,
     error::Error as ProtocolError,
-    messages::{ChunkProof, Nonce, Query, QueryResponse, Request, Response},
+    messages::{
+        verify_put_record_ack, ChunkProof, Cmd, CmdResponse, Nonce, PutRecordRejection, Query,
+        QueryResponse, Request, Response,
+    },
     storage::{DataTypes, Pointer, Scratchpad, ValidationType},
-    NetworkAddress, PrettyPrintKBucketKey, PrettyPrintRecordKey, CLOSE_GROUP_SIZE,
+    NetworkAddress, PrettyPrintKBucketKey, PrettyPrintRecordKey,
 };
 use futures::future::select_all;
 use libp2p::{
@@ -84,10 +103,10 @@ use {
 
 /// Majority of a given group (i.e. > 1/2).
 #[inline]
-pub const fn close_group_majority() -> usize {
+pub fn close_group_majority() -> usize {
     // Calculate the majority of the close group size by dividing it by 2 and adding 1.
     // This ensures that the majority is always greater than half.
-    CLOSE_GROUP_SIZE / 2 + 1
+    close_group_size() / 2 + 1
 }
 
 /// Max duration to wait for verification.
@@ -114,11 +133,11 @@ pub fn sort_peers_by_key<'a, T>(
 ) -> Result<Vec<&'a PeerId>> {
     // Check if there are enough peers to satisfy the request.
     // bail early if that's not the case
-    if CLOSE_GROUP_SIZE > peers.len() {
+    if close_group_size() > peers.len() {
         warn!("Not enough peers in the k-bucket to satisfy the request");
         return Err(NetworkError::NotEnoughPeers {
             found: peers.len(),
-            required: CLOSE_GROUP_SIZE,
+            required: close_group_size(),
         });
     }
 
@@ -149,6 +168,7 @@ pub fn sort_peers_by_key<'a, T>(
 /// API to interact with the underlying Swarm
 pub struct Network {
     inner: Arc<NetworkInner>,
+    query_tracer: Option<QueryTracer>,
 }
 
 /// The actual implementation of the Network. The other is just a wrapper around this, so that we don't expose
@@ -175,6 +195,18 @@ impl Network {
                 peer_id,
                 keypair,
             }),
+            query_tracer: None,
+        }
+    }
+
+    /// Returns a handle to the same network, that traces the queries made through it: the GETs,
+    /// the PUTs and the requests sent to a set of peers.
+    ///
+    /// The other handles sharing the network are left untraced.
+    pub fn with_query_tracer(&self, tracer: QueryTracer) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            query_tracer: Some(tracer),
         }
     }
 
@@ -238,6 +270,34 @@ impl Network {
             .await
     }
 
+    /// Ask the closest peers to the target for their own closest peers to it, with each answer
+    /// signed by the peer that sent it.
+    ///
+    /// The answers are cross checked, to detect the peers lying about the neighbourhood of the
+    /// target, e.g. in an attempt to eclipse it.
+    pub async fn get_verified_closest_peers(
+        &self,
+        target: &NetworkAddress,
+    ) -> Result<VerifiedClosestPeers> {
+        let responders = self
+            .client_get_all_close_peers_in_range_or_close_group(target)
+            .await?;
+        let request = Request::Query(Query::GetClosestPeers {
+            key: target.clone(),
+            num_of_peers: Some(close_group_size()),
+            range: None,
+            sign_result: true,
+        });
+        let responses = self
+            .send_and_get_responses(&responders, &request, true)
+            .await;
+        Ok(closest_peers::cross_check_closest_peers(
+            target,
+            responses,
+            close_group_size(),
+        ))
+    }
+
     /// Returns a list of peers in local RT and their correspondent Multiaddr.
     /// Does not include self
     pub async fn get_local_peers_with_multiaddr(&self) -> Result<Vec<(PeerId, Vec<Multiaddr>)>> {
@@ -259,6 +319,16 @@ impl Network {
             .map_err(|_e| NetworkError::InternalMsgChannelDropped)
     }
 
+    /// Returns the traffic of the node and of each of its connected peers.
+    /// A client doesn't keep track of its traffic, hence always gets empty stats.
+    pub async fn get_bandwidth_stats(&self) -> Result<BandwidthStats> {
+        let (sender, receiver) = oneshot::channel();
+        self.send_local_swarm_cmd(LocalSwarmCmd::GetBandwidthStats { sender });
+        receiver
+            .await
+            .map_err(|_e| NetworkError::InternalMsgChannelDropped)
+    }
+
     /// Returns all the PeerId from all the KBuckets from our local Routing Table
     /// Also contains our own PeerId.
     pub async fn get_closest_k_value_local_peers(&self) -> Result<Vec<PeerId>> {
@@ -494,16 +564,42 @@ impl Network {
         key: RecordKey,
         cfg: &GetRecordCfg,
     ) -> Result<Record> {
-        let pretty_key = PrettyPrintRecordKey::from(&key);
+        let Some(tracer) = &self.query_tracer else {
+            return self.get_record_with_retries(&key, cfg, None).await;
+        };
+        let mut trace =
+            QueryTraceBuilder::new(QueryKind::GetRecord, NetworkAddress::from_record_key(&key));
+        let result = self
+            .get_record_with_retries(&key, cfg, Some(&mut trace))
+            .await;
+        tracer.emit(trace.finish(result.as_ref().err().map(|err| err.to_string())));
+        result
+    }
+
+    async fn get_record_with_retries(
+        &self,
+        key: &RecordKey,
+        cfg: &GetRecordCfg,
+        mut trace: Option<&mut QueryTraceBuilder>,
+    ) -> Result<Record> {
+        let pretty_key = PrettyPrintRecordKey::from(key);
         let mut backoff = cfg.retry_strategy.backoff().into_iter();
 
         loop {
             info!("Getting record from network of {pretty_key:?}. with cfg {cfg:?}",);
             let (sender, receiver) = oneshot::channel();
+            let (trace_sender, trace_receiver) = match &trace {
+                Some(trace) => {
+                    let (trace_sender, trace_receiver) = oneshot::channel();
+                    (Some(trace_sender), Some((trace.elapsed(), trace_receiver)))
+                }
+                None => (None, None),
+            };
             self.send_network_swarm_cmd(NetworkSwarmCmd::GetNetworkRecord {
                 key: key.clone(),
                 sender,
                 cfg: cfg.clone(),
+                trace_sender,
             });
             let result = match receiver.await {
                 Ok(result) => result,
@@ -516,6 +612,14 @@ impl Network {
                 }
             };
 
+            if let (Some(trace), Some((started, trace_receiver))) = (&mut trace, trace_receiver) {
+                // the driver only drops the sender if the query got lost
+                let mut attempt = trace_receiver.await.unwrap_or_default();
+                attempt.started = started;
+                attempt.error = result.as_ref().err().map(|err| err.to_string());
+                trace.add_attempt(attempt);
+            }
+
             let err = match result {
                 Ok(record) => {
                     info!("Record returned: {pretty_key:?}.");
@@ -544,7 +648,7 @@ impl Network {
                 }
                 GetRecordError::SplitRecord { result_map } => {
                     error!("Encountered a split record for {pretty_key:?}.");
-                    if let Some(record) = Self::handle_split_record_error(result_map, &key)? {
+                    if let Some(record) = Self::handle_split_record_error(result_map, key)? {
                         info!("Merged the split record for {pretty_key:?}, into a single record");
                         return Ok(record);
                     }
@@ -769,23 +873,102 @@ impl Network {
     }
 
     /// Put `Record` to network
+    /// Each peer acknowledges the record once it has validated and stored it, the PUT succeeds once
+    /// `put_quorum` peers have acknowledged it.
     /// Optionally verify the record is stored after putting it to network
     /// If verify is on, we retry.
     pub async fn put_record(&self, record: Record, cfg: &PutRecordCfg) -> Result<()> {
+        self.put_record_and_get_outcome(record, cfg)
+            .await
+            .map(|_| ())
+    }
+
+    /// Put `Record` to network like [`Network::put_record`], returning which peers acknowledged it and which
+    /// ones rejected it.
+    pub async fn put_record_and_get_outcome(
+        &self,
+        record: Record,
+        cfg: &PutRecordCfg,
+    ) -> Result<PutRecordOutcome> {
+        let Some(tracer) = &self.query_tracer else {
+            return self.put_record_with_retries(record, cfg, None).await;
+        };
+        let mut trace = QueryTraceBuilder::new(
+            QueryKind::PutRecord,
+            NetworkAddress::from_record_key(&record.key),
+        );
+        let result = self
+            .put_record_with_retries(record, cfg, Some(&mut trace))
+            .await;
+        tracer.emit(trace.finish(result.as_ref().err().map(|err| err.to_string())));
+        result
+    }
+
+    async fn put_record_with_retries(
+        &self,
+        record: Record,
+        cfg: &PutRecordCfg,
+        mut trace: Option<&mut QueryTraceBuilder>,
+    ) -> Result<PutRecordOutcome> {
         let pretty_key = PrettyPrintRecordKey::from(&record.key);
         let mut backoff = cfg.retry_strategy.backoff().into_iter();
+        // The outcome of the previous attempts, so retries only go to the peers that have not stored the record yet
+        let mut acknowledged = HashSet::new();
+        let mut rejections = BTreeMap::new();
 
         loop {
             info!(
                 "Attempting to PUT record with key: {pretty_key:?} to network, with cfg {cfg:?}, retrying via backoff..."
             );
 
-            let err = match self.put_record_once(record.clone(), cfg).await {
-                Ok(_) => break Ok(()),
+            let mut attempt = trace.as_ref().map(|trace| AttemptTrace {
+                started: trace.elapsed(),
+                ..Default::default()
+            });
+            let result = self
+                .put_record_once(
+                    record.clone(),
+                    cfg,
+                    &mut acknowledged,
+                    &mut rejections,
+                    attempt.as_mut(),
+                )
+                .await;
+            if let (Some(trace), Some(mut attempt)) = (&mut trace, attempt) {
+                attempt.duration = trace.elapsed().saturating_sub(attempt.started);
+                attempt.error = result.as_ref().err().map(|err| err.to_string());
+                trace.add_attempt(attempt);
+            }
+
+            let err = match result {
+                Ok(_) => {
+                    break Ok(PutRecordOutcome {
+                        acknowledged,
+                        rejections,
+                    })
+                }
                 Err(err) => err,
             };
 
-            // FIXME: Skip if we get a permanent error during verification
+            if let NetworkError::PutRecordQuorumFailed {
+                stored,
+                quorum,
+                rejections,
+                unacknowledged,
+                ..
+            } = &err
+            {
+                let retriable = unacknowledged.len()
+                    + rejections
+                        .values()
+                        .filter(|rejection| !rejection.is_permanent())
+                        .count();
+                if stored + retriable < *quorum {
+                    warn!("Record with key: {pretty_key:?} has been rejected by too many peers to reach the quorum, not retrying: {err:?}");
+                    break Err(err);
+                }
+            }
+
             warn!("Failed to PUT record with key: {pretty_key:?} to network (retry via backoff) with error: {err:?}");
 
             match backoff.next() {
@@ -797,33 +980,119 @@ impl Network {
         }
     }
 
-    async fn put_record_once(&self, record: Record, cfg: &PutRecordCfg) -> Result<()> {
+    async fn put_record_once(
+        &self,
+        record: Record,
+        cfg: &PutRecordCfg,
+        acknowledged: &mut HashSet<PeerId>,
+        rejections: &mut BTreeMap<PeerId, PutRecordRejection>,
+        mut attempt: Option<&mut AttemptTrace>,
+    ) -> Result<()> {
         let record_key = record.key.clone();
         let pretty_key = PrettyPrintRecordKey::from(&record_key);
+        let address = NetworkAddress::from_record_key(&record_key);
         info!(
             "Putting record of {} - length {:?} to network",
             pretty_key,
             record.value.len()
         );
 
-        // Waiting for a response to avoid flushing to network too quick that causing choke
-        let (sender, receiver) = oneshot::channel();
-        if let Some(put_record_to_peers) = &cfg.use_put_record_to {
-            self.send_network_swarm_cmd(NetworkSwarmCmd::PutRecordTo {
-                peers: put_record_to_peers.clone(),
-                record: record.clone(),
-                sender,
-                quorum: cfg.put_quorum,
-            });
-        } else {
-            self.send_network_swarm_cmd(NetworkSwarmCmd::PutRecord {
-                record: record.clone(),
-                sender,
-                quorum: cfg.put_quorum,
-            });
+        let peers = match &cfg.use_put_record_to {
+            Some(peers) => peers.clone(),
+            None => {
+                self.client_get_all_close_peers_in_range_or_close_group(&address)
+                    .await?
+            }
+        };
+        let quorum = cfg.put_quorum.get_value();
+
+        // Permanent rejections are caused by the record itself, no need to send it again
+        let pending: Vec<_> = peers
+            .iter()
+            .filter(|peer| {
+                !acknowledged.contains(*peer)
+                    && !rejections
+                        .get(*peer)
+                        .is_some_and(|rejection| rejection.is_permanent())
+            })
+            .cloned()
+            .collect();
+        let request = Request::Cmd(Cmd::PutRecord {
+            address: address.clone(),
+            record: record.value.clone().into(),
+        });
+        let responses = self
+            .send_and_get_timed_responses(&pending, &request, true)
+            .await;
+
+        let mut unacknowledged = vec![];
+        for peer in pending {
+            let response = responses.get(&peer);
+            let outcome = match response.map(|(response, _)| response) {
+                Some(Ok(Response::Cmd(CmdResponse::PutRecord {
+                    result: Ok(signature),
+                    ..
+                }))) => {
+                    if verify_put_record_ack(&peer, &address, &record.value, signature) {
+                        let _ = acknowledged.insert(peer);
+                        let _ = rejections.remove(&peer);
+                        PeerOutcome::Answered
+                    } else {
+                        warn!("Peer {peer:?} acknowledged record {pretty_key:?} with an invalid signature");
+                        unacknowledged.push(peer);
+                        PeerOutcome::Failed("invalid acknowledgement signature".to_string())
+                    }
+                }
+                Some(Ok(Response::Cmd(CmdResponse::PutRecord {
+                    result: Err(rejection),
+                    ..
+                }))) => {
+                    warn!("Peer {peer:?} rejected record {pretty_key:?}: {rejection}");
+                    let _ = rejections.insert(peer, rejection.clone());
+                    PeerOutcome::Rejected(rejection.to_string())
+                }
+                other => {
+                    warn!("Peer {peer:?} did not acknowledge record {pretty_key:?}: {other:?}");
+                    unacknowledged.push(peer);
+                    match other {
+                        Some(Err(err)) => PeerOutcome::Failed(err.to_string()),
+                        _ => PeerOutcome::Failed("unexpected response".to_string()),
+                    }
+                }
+            };
+            if let Some(attempt) = attempt.as_mut() {
+                let latency = response.map(|(_, latency)| *latency);
+                attempt.peers.push(PeerTrace {
+                    peer,
+                    elapsed: latency.unwrap_or_default(),
+                    latency,
+                    outcome,
+                });
+            }
+        }
+        if let Some(attempt) = attempt {
+            attempt.peers.sort_by_key(|peer| peer.elapsed);
         }
 
-        let response = receiver.await?;
+        let stored = peers
+            .iter()
+            .filter(|peer| acknowledged.contains(*peer))
+            .count();
+        if stored < quorum {
+            let rejections = rejections
+                .iter()
+                .filter(|(peer, _)| peers.contains(peer))
+                .map(|(peer, rejection)| (*peer, rejection.clone()))
+                .collect();
+            return Err(NetworkError::PutRecordQuorumFailed {
+                address,
+                stored,
+                quorum,
+                rejections,
+                unacknowledged,
+            });
+        }
+        debug!("Record {pretty_key:?} acknowledged by {stored} peers, quorum is {quorum}");
 
         if let Some((verification_kind, get_cfg)) = &cfg.verification {
             // Generate a random duration between MAX_WAIT_BEFORE_READING_A_PUT and MIN_WAIT_BEFORE_READING_A_PUT
@@ -876,7 +1145,7 @@ impl Network {
                 }
             }
         }
-        response
+        Ok(())
     }
 
     /// Notify ReplicationFetch a fetch attempt is completed.
@@ -1085,7 +1354,7 @@ impl Network {
             );
         }
 
-        let expanded_close_group = CLOSE_GROUP_SIZE + CLOSE_GROUP_SIZE / 2;
+        let expanded_close_group = close_group_size() + close_group_size() / 2;
         let closest_peers = sort_peers_by_address(&closest_peers, key, expanded_close_group)?;
         Ok(closest_peers.into_iter().cloned().collect())
     }
@@ -1100,7 +1369,66 @@ impl Network {
         req: &Request,
         get_all_responses: bool,
     ) -> BTreeMap<PeerId, Result<Response>> {
+        let Some(tracer) = &self.query_tracer else {
+            return self
+                .send_and_get_timed_responses(peers, req, get_all_responses)
+                .await
+                .into_iter()
+                .map(|(peer, (resp, _))| (peer, resp))
+                .collect();
+        };
+
+        let name = match req {
+            Request::Cmd(cmd) => cmd.to_string(),
+            Request::Query(query) => query.to_string(),
+        };
+        let mut trace = QueryTraceBuilder::new(QueryKind::Request(name), req.dst());
+        let responses = self
+            .send_and_get_timed_responses(peers, req, get_all_responses)
+            .await;
+
+        let mut peer_traces = responses
+            .iter()
+            .map(|(peer, (resp, latency))| PeerTrace {
+                peer: *peer,
+                elapsed: *latency,
+                latency: Some(*latency),
+                outcome: match resp {
+                    Ok(_) => PeerOutcome::Answered,
+                    Err(err) => PeerOutcome::Failed(err.to_string()),
+                },
+            })
+            .collect::<Vec<_>>();
+        peer_traces.sort_by_key(|peer| peer.elapsed);
+        let error = if responses.values().any(|(resp, _)| resp.is_ok()) {
+            None
+        } else {
+            Some(format!("None of the {} peers answered", peers.len()))
+        };
+        trace.add_attempt(AttemptTrace {
+            started: Duration::ZERO,
+            duration: trace.elapsed(),
+            peers: peer_traces,
+            kad_stats: None,
+            error: error.clone(),
+        });
+        tracer.emit(trace.finish(error));
+
+        responses
+            .into_iter()
+            .map(|(peer, (resp, _))| (peer, resp))
+            .collect()
+    }
+
+    /// Same as `send_and_get_responses`, along with the time it took each peer to answer.
+    async fn send_and_get_timed_responses(
+        &self,
+        peers: &[PeerId],
+        req: &Request,
+        get_all_responses: bool,
+    ) -> BTreeMap<PeerId, (Result<Response>, Duration)> {
         debug!("send_and_get_responses for {req:?}");
+        let start = Instant::now();
         let mut list_of_futures = peers
             .iter()
             .map(|peer| {
@@ -1114,15 +1442,16 @@ impl Network {
         let mut responses = BTreeMap::new();
         while !list_of_futures.is_empty() {
             let ((peer, resp), _, remaining_futures) = select_all(list_of_futures).await;
+            let latency = start.elapsed();
             let resp_string = match &resp {
                 Ok(resp) => format!("{resp}"),
                 Err(err) => format!("{err:?}"),
             };
             debug!("Got response from {peer:?} for the req: {req:?}, resp: {resp_string}");
             if !get_all_responses && resp.is_ok() {
-                return BTreeMap::from([(peer, resp)]);
+                return BTreeMap::from([(peer, (resp, latency))]);
             }
-            responses.insert(peer, resp);
+            responses.insert(peer, (resp, latency));
             list_of_futures = remaining_futures;
         }
 
@@ -1199,7 +1528,7 @@ pub(crate) fn multiaddr_get_ip(addr: &Multiaddr) -> Option<IpAddr> {
 
 pub(crate) fn multiaddr_get_port(addr: &Multiaddr) -> Option<u16> {
     addr.iter().find_map(|p| match p {
-        Protocol::Udp(port) => Some(port),
+        Protocol::Udp(port) | Protocol::Tcp(port) => Some(port),
         _ => None,
     })
 }
@@ -7,7 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::time::interval;
-use ant_protocol::CLOSE_GROUP_SIZE;
+use ant_protocol::close_group_size;
 use libp2p::PeerId;
 use prometheus_client::{
     encoding::{EncodeLabelSet, EncodeLabelValue},
@@ -22,9 +22,13 @@ use strum::IntoEnumIterator;
 const UPDATE_INTERVAL: Duration = Duration::from_secs(20);
 
 #[cfg(not(test))]
-const MAX_EVICTED_CLOSE_GROUP_PEERS: usize = 5 * CLOSE_GROUP_SIZE;
+fn max_evicted_close_group_peers() -> usize {
+    5 * close_group_size()
+}
 #[cfg(test)]
-const MAX_EVICTED_CLOSE_GROUP_PEERS: usize = CLOSE_GROUP_SIZE + 2;
+fn max_evicted_close_group_peers() -> usize {
+    close_group_size() + 2
+}
 
 pub struct BadNodeMetrics {
     shunned_count_across_time_frames: ShunnedCountAcrossTimeFrames,
@@ -210,7 +214,7 @@ impl ShunnedByCloseGroup {
             debug!("The close group has been updated. The new members are {new_members:?}. The evicted members are {evicted_members:?}");
             self.close_group_peers = new_closest_peers;
 
-            while self.old_close_group_peers.len() > MAX_EVICTED_CLOSE_GROUP_PEERS {
+            while self.old_close_group_peers.len() > max_evicted_close_group_peers() {
                 if let Some(removed_peer) = self.old_close_group_peers.pop_front() {
                     if self.old_new_group_shunned_list.remove(&removed_peer) {
                         self.metric_old_group.dec();
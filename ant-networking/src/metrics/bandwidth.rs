@@ -0,0 +1,62 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::bandwidth::Direction;
+use libp2p::PeerId;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{counter::Counter, family::Family},
+};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct PeerBandwidthLabels {
+    peer_id: String,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ThrottledLabels {
+    direction: Direction,
+}
+
+/// The bandwidth metrics, updated by the connections as the bytes go through them.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BandwidthMetrics {
+    pub(crate) peer_bandwidth: Family<PeerBandwidthLabels, Counter>,
+    pub(crate) bandwidth_throttled: Family<ThrottledLabels, Counter>,
+}
+
+impl BandwidthMetrics {
+    pub(crate) fn record(&self, peer_id: &PeerId, direction: Direction, bytes: u64) {
+        let _ = self
+            .peer_bandwidth
+            .get_or_create(&PeerBandwidthLabels {
+                peer_id: peer_id.to_string(),
+                direction,
+            })
+            .inc_by(bytes);
+    }
+
+    pub(crate) fn record_throttled(&self, direction: Direction) {
+        let _ = self
+            .bandwidth_throttled
+            .get_or_create(&ThrottledLabels { direction })
+            .inc();
+    }
+
+    /// Remove the metrics of a disconnected peer, to not keep the metrics of every peer we have
+    /// ever been connected to.
+    pub(crate) fn remove_peer(&self, peer_id: &PeerId) {
+        for direction in [Direction::Inbound, Direction::Outbound] {
+            let _ = self.peer_bandwidth.remove(&PeerBandwidthLabels {
+                peer_id: peer_id.to_string(),
+                direction,
+            });
+        }
+    }
+}
@@ -8,6 +8,7 @@
 
 // Implementation to record `libp2p::upnp::Event` metrics
 mod bad_node;
+pub(crate) mod bandwidth;
 mod relay_client;
 pub mod service;
 mod upnp;
@@ -17,6 +18,7 @@ use std::sync::atomic::AtomicU64;
 use crate::MetricsRegistries;
 use crate::{log_markers::Marker, time::sleep};
 use bad_node::{BadNodeMetrics, BadNodeMetricsMsg, TimeFrame};
+use bandwidth::BandwidthMetrics;
 use libp2p::{
     metrics::{Metrics as Libp2pMetrics, Recorder},
     PeerId,
@@ -24,6 +26,7 @@ use libp2p::{
 use prometheus_client::{
     metrics::family::Family,
     metrics::{counter::Counter, gauge::Gauge},
+    registry::Unit,
 };
 use sysinfo::{Pid, ProcessRefreshKind, System};
 use tokio::time::Duration;
@@ -47,6 +50,7 @@ pub(crate) struct NetworkMetricsRecorder {
     pub(crate) peers_in_routing_table: Gauge,
     pub(crate) records_stored: Gauge,
     pub(crate) relay_reservation_health: Gauge<f64, AtomicU64>,
+    pub(crate) bandwidth: BandwidthMetrics,
 
     // quoting metrics
     relevant_records: Gauge,
@@ -162,6 +166,13 @@ impl NetworkMetricsRecorder {
             process_cpu_usage_percentage.clone(),
         );
 
+        let bandwidth = BandwidthMetrics::default();
+        sub_registry.register(
+            "bandwidth_throttled",
+            "The number of times a stream has been held back by the bandwidth limits",
+            bandwidth.bandwidth_throttled.clone(),
+        );
+
         // quoting metrics
         let relevant_records = Gauge::default();
         sub_registry.register(
@@ -213,6 +224,12 @@ impl NetworkMetricsRecorder {
             "The number of times our node has been shunned by other nodes across different time frames",
             shunned_count_across_time_frames.clone(),
         );
+        extended_metrics_sub_registry.register_with_unit(
+            "peer_bandwidth",
+            "The bytes exchanged with each connected peer, by direction",
+            Unit::Bytes,
+            bandwidth.peer_bandwidth.clone(),
+        );
 
         let bad_nodes_notifier = BadNodeMetrics::spawn_background_task(
             shunned_count_across_time_frames.clone(),
@@ -229,6 +246,7 @@ impl NetworkMetricsRecorder {
             connected_peers,
             open_connections,
             relay_reservation_health,
+            bandwidth,
             peers_in_routing_table,
             relevant_records,
             max_records,
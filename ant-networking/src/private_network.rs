@@ -0,0 +1,200 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use libp2p::{identity::Keypair, pnet::PreSharedKey, PeerId};
+use std::{
+    collections::HashSet,
+    fmt, fs,
+    io::{self, Write},
+    path::Path,
+};
+
+type Result<T, E = PrivateNetworkError> = std::result::Result<T, E>;
+
+/// Errors that can occur when reading the configuration of a private network.
+#[derive(Debug, thiserror::Error)]
+pub enum PrivateNetworkError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Invalid private network configuration: {0}")]
+    InvalidConfig(String),
+}
+
+/// The restrictions of a private network, on top of the network id.
+///
+/// The network key is a libp2p pre-shared key, in the `swarm.key` format used by the other libp2p
+/// implementations:
+///
+/// ```text
+/// /key/swarm/psk/1.0.0/
+/// /base16/
+/// <64 hex characters>
+/// ```
+///
+/// Every connection is encrypted with it, so only the nodes and clients holding the key are able to
+/// connect. QUIC not supporting pre-shared keys, the connections are made over TCP (and WebSocket
+/// if enabled) instead.
+///
+/// The allowed peers restrict the peers of the network, nodes and clients alike: the connections
+/// with any other peer are denied as they are established, before any request can be made. A client
+/// is usually given a new peer id on each start, hence it has to connect with a fixed `identity`
+/// for its peer id to be listed by the nodes.
+#[derive(Clone, Default)]
+pub struct PrivateNetwork {
+    /// The key shared by all the peers of the network
+    pub key: Option<PreSharedKey>,
+    /// The only peers allowed in the network, or any peer if `None`
+    pub allowed_peers: Option<HashSet<PeerId>>,
+    /// The keypair a client connects with, instead of a random one. Nodes have their own keypair.
+    pub identity: Option<Keypair>,
+}
+
+impl fmt::Debug for PrivateNetwork {
+    // Only the fingerprint of the key is shown, to not leak it in the logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrivateNetwork")
+            .field(
+                "key_fingerprint",
+                &self.key.map(|key| key.fingerprint().to_string()),
+            )
+            .field(
+                "allowed_peers",
+                &self.allowed_peers.as_ref().map(|peers| peers.len()),
+            )
+            .field(
+                "identity",
+                &self
+                    .identity
+                    .as_ref()
+                    .map(|keypair| keypair.public().to_peer_id()),
+            )
+            .finish()
+    }
+}
+
+impl PrivateNetwork {
+    /// Read the network key and/or the allowed peers from their files.
+    ///
+    /// The allowed peers file lists one `PeerId` per line, empty lines and lines starting with `#`
+    /// being ignored.
+    pub fn from_files(key_file: Option<&Path>, allowed_peers_file: Option<&Path>) -> Result<Self> {
+        let key = key_file.map(read_network_key).transpose()?;
+        let allowed_peers = allowed_peers_file.map(read_allowed_peers).transpose()?;
+        Ok(Self {
+            key,
+            allowed_peers,
+            identity: None,
+        })
+    }
+
+    /// Read the keypair to connect with from the file, in the format of the `secret-key` file of a
+    /// node: the 32 bytes of an ed25519 secret key. A new keypair is generated and written to the
+    /// file if it doesn't exist yet.
+    pub fn read_or_create_identity(path: &Path) -> Result<Keypair> {
+        match fs::read(path) {
+            Ok(bytes) => Keypair::ed25519_from_bytes(bytes)
+                .map_err(|err| PrivateNetworkError::InvalidConfig(format!("{path:?}: {err}"))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let secret_key = libp2p::identity::ed25519::SecretKey::generate();
+                let mut options = fs::OpenOptions::new();
+                let _ = options.write(true).create_new(true);
+                // On Unix systems, make sure only the current user can read/write.
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::OpenOptionsExt;
+                    let _ = options.mode(0o600);
+                }
+                options.open(path)?.write_all(secret_key.as_ref())?;
+                info!("Generated a new identity and stored it to {path:?}");
+                Ok(libp2p::identity::ed25519::Keypair::from(secret_key).into())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Whether the peer is part of the network. Always true if there is no allow-list.
+    pub fn is_allowed(&self, peer_id: &PeerId) -> bool {
+        self.allowed_peers
+            .as_ref()
+            .is_none_or(|peers| peers.contains(peer_id))
+    }
+}
+
+fn read_network_key(path: &Path) -> Result<PreSharedKey> {
+    let content = fs::read_to_string(path)?;
+    content
+        .parse()
+        .map_err(|err| PrivateNetworkError::InvalidConfig(format!("{path:?}: {err}")))
+}
+
+fn read_allowed_peers(path: &Path) -> Result<HashSet<PeerId>> {
+    let content = fs::read_to_string(path)?;
+    let peers = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            line.parse::<PeerId>().map_err(|err| {
+                PrivateNetworkError::InvalidConfig(format!("{path:?}: {line:?}: {err}"))
+            })
+        })
+        .collect::<Result<HashSet<_>>>()?;
+    if peers.is_empty() {
+        return Err(PrivateNetworkError::InvalidConfig(format!(
+            "{path:?} does not list any peer"
+        )));
+    }
+    Ok(peers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eyre::Result;
+
+    #[test]
+    fn test_private_network_from_files() -> Result<()> {
+        let dir = assert_fs::TempDir::new()?;
+        let key_file = dir.path().join("swarm.key");
+        let key = PreSharedKey::new([7; 32]);
+        fs::write(&key_file, key.to_string())?;
+
+        let allowed = PeerId::random();
+        let allowed_peers_file = dir.path().join("allowed_peers");
+        fs::write(&allowed_peers_file, format!("# our nodes\n\n{allowed}\n"))?;
+
+        let private_network =
+            PrivateNetwork::from_files(Some(&key_file), Some(&allowed_peers_file))?;
+        assert_eq!(private_network.key, Some(key));
+        assert!(private_network.is_allowed(&allowed));
+        assert!(!private_network.is_allowed(&PeerId::random()));
+        assert!(!format!("{private_network:?}").contains("0707"));
+
+        assert!(PrivateNetwork::default().is_allowed(&PeerId::random()));
+
+        fs::write(&allowed_peers_file, "not a peer id\n")?;
+        assert!(PrivateNetwork::from_files(None, Some(&allowed_peers_file)).is_err());
+        fs::write(&key_file, "/key/swarm/psk/1.0.0/\n/base16/\n0707\n")?;
+        assert!(PrivateNetwork::from_files(Some(&key_file), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_identity_is_created_then_reused() -> Result<()> {
+        let dir = assert_fs::TempDir::new()?;
+        let identity_file = dir.path().join("secret-key");
+
+        let created = PrivateNetwork::read_or_create_identity(&identity_file)?;
+        let reused = PrivateNetwork::read_or_create_identity(&identity_file)?;
+        assert_eq!(created.public(), reused.public());
+
+        fs::write(&identity_file, b"too short")?;
+        assert!(PrivateNetwork::read_or_create_identity(&identity_file).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::time::Instant;
+use ant_protocol::NetworkAddress;
+use libp2p::{kad::QueryStats, PeerId};
+use std::{fmt, sync::Arc, time::Duration};
+use tokio::sync::oneshot;
+
+/// The kind of query that has been traced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryKind {
+    /// A record fetched through a Kademlia GET
+    GetRecord,
+    /// A record sent to its close group through request-response
+    PutRecord,
+    /// A request sent to a set of peers through request-response
+    Request(String),
+}
+
+impl fmt::Display for QueryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryKind::GetRecord => write!(f, "GET"),
+            QueryKind::PutRecord => write!(f, "PUT"),
+            QueryKind::Request(request) => write!(f, "{request}"),
+        }
+    }
+}
+
+/// What came out of a peer contacted during a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerOutcome {
+    /// The peer returned a copy of the record
+    RecordFound,
+    /// The peer is among the closest to the record, but didn't return a copy of it
+    RecordMissing,
+    /// The peer answered the request
+    Answered,
+    /// The peer refused the request, e.g. by rejecting the record to store
+    Rejected(String),
+    /// The request to the peer failed
+    Failed(String),
+}
+
+impl fmt::Display for PeerOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeerOutcome::RecordFound => write!(f, "record found"),
+            PeerOutcome::RecordMissing => write!(f, "record missing"),
+            PeerOutcome::Answered => write!(f, "answered"),
+            PeerOutcome::Rejected(reason) => write!(f, "rejected: {reason}"),
+            PeerOutcome::Failed(err) => write!(f, "failed: {err}"),
+        }
+    }
+}
+
+/// A peer contacted during an attempt of a query.
+#[derive(Debug, Clone)]
+pub struct PeerTrace {
+    pub peer: PeerId,
+    /// When the outcome was known, since the start of the attempt
+    pub elapsed: Duration,
+    /// The round trip time of the request sent to the peer.
+    /// Not known for the peers contacted by a Kademlia query.
+    pub latency: Option<Duration>,
+    pub outcome: PeerOutcome,
+}
+
+/// The statistics of a Kademlia query, as reported by libp2p.
+#[derive(Debug, Clone, Copy)]
+pub struct KadQueryStats {
+    /// The number of requests sent by the query, to find the closest peers along with the record
+    pub requests: u32,
+    pub successes: u32,
+    pub failures: u32,
+}
+
+impl From<&QueryStats> for KadQueryStats {
+    fn from(stats: &QueryStats) -> Self {
+        Self {
+            requests: stats.num_requests(),
+            successes: stats.num_successes(),
+            failures: stats.num_failures(),
+        }
+    }
+}
+
+/// An attempt of a query. A query is attempted again, after a backoff, when it fails.
+#[derive(Debug, Clone, Default)]
+pub struct AttemptTrace {
+    /// When the attempt started, since the start of the query
+    pub started: Duration,
+    pub duration: Duration,
+    /// The peers contacted, in the order their outcome became known
+    pub peers: Vec<PeerTrace>,
+    /// Only set for the attempts carried out through a Kademlia query
+    pub kad_stats: Option<KadQueryStats>,
+    /// Why the attempt failed
+    pub error: Option<String>,
+}
+
+/// The trace of a query made to the network, to find out why it is slow or failing.
+///
+/// See [`crate::Network::with_query_tracer`].
+#[derive(Debug, Clone)]
+pub struct QueryTrace {
+    pub kind: QueryKind,
+    pub target: NetworkAddress,
+    pub attempts: Vec<AttemptTrace>,
+    pub duration: Duration,
+    /// Why the query failed, once all the attempts have been exhausted
+    pub error: Option<String>,
+}
+
+impl fmt::Display for QueryTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match &self.error {
+            Some(err) => format!("failed: {err}"),
+            None => "succeeded".to_string(),
+        };
+        writeln!(
+            f,
+            "{} {:?} {result} in {:?} after {} attempt(s)",
+            self.kind,
+            self.target,
+            self.duration,
+            self.attempts.len()
+        )?;
+        for (index, attempt) in self.attempts.iter().enumerate() {
+            write!(
+                f,
+                "  attempt {} at +{:?}, took {:?}",
+                index + 1,
+                attempt.started,
+                attempt.duration
+            )?;
+            if let Some(stats) = &attempt.kad_stats {
+                write!(
+                    f,
+                    ", {} kad requests ({} succeeded, {} failed)",
+                    stats.requests, stats.successes, stats.failures
+                )?;
+            }
+            if let Some(err) = &attempt.error {
+                write!(f, ", failed: {err}")?;
+            }
+            writeln!(f)?;
+            for peer in &attempt.peers {
+                write!(f, "    +{:?} {}: {}", peer.elapsed, peer.peer, peer.outcome)?;
+                if let Some(latency) = peer.latency {
+                    write!(f, " (latency {latency:?})")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Receives the trace of every query made through a [`crate::Network`] with tracing enabled.
+#[derive(Clone)]
+pub struct QueryTracer(Arc<dyn Fn(QueryTrace) + Send + Sync>);
+
+impl QueryTracer {
+    pub fn new(on_trace: impl Fn(QueryTrace) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(on_trace))
+    }
+
+    pub(crate) fn emit(&self, trace: QueryTrace) {
+        (self.0)(trace)
+    }
+}
+
+impl fmt::Debug for QueryTracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "QueryTracer")
+    }
+}
+
+/// Builds the trace of a query, as its attempts go.
+pub(crate) struct QueryTraceBuilder {
+    kind: QueryKind,
+    target: NetworkAddress,
+    start: Instant,
+    attempts: Vec<AttemptTrace>,
+}
+
+impl QueryTraceBuilder {
+    pub(crate) fn new(kind: QueryKind, target: NetworkAddress) -> Self {
+        Self {
+            kind,
+            target,
+            start: Instant::now(),
+            attempts: vec![],
+        }
+    }
+
+    /// The time elapsed since the start of the query, to tell when an attempt started.
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    pub(crate) fn add_attempt(&mut self, attempt: AttemptTrace) {
+        self.attempts.push(attempt);
+    }
+
+    pub(crate) fn finish(self, error: Option<String>) -> QueryTrace {
+        QueryTrace {
+            kind: self.kind,
+            target: self.target,
+            attempts: self.attempts,
+            duration: self.start.elapsed(),
+            error,
+        }
+    }
+}
+
+/// Accumulates the peers that returned a copy of the record during a Kademlia GET, for the
+/// `SwarmDriver` to send them along with the stats once the query completes.
+#[derive(Debug)]
+pub(crate) struct GetRecordTrace {
+    pub(crate) start: Instant,
+    pub(crate) peers: Vec<PeerTrace>,
+    pub(crate) senders: Vec<oneshot::Sender<AttemptTrace>>,
+}
+
+impl GetRecordTrace {
+    pub(crate) fn new(sender: oneshot::Sender<AttemptTrace>) -> Self {
+        Self {
+            start: Instant::now(),
+            peers: vec![],
+            senders: vec![sender],
+        }
+    }
+
+    pub(crate) fn add_peer(&mut self, peer: PeerId, outcome: PeerOutcome) {
+        // a peer that already returned a copy can't be missing it
+        if outcome == PeerOutcome::RecordMissing
+            && self.peers.iter().any(|trace| trace.peer == peer)
+        {
+            return;
+        }
+        self.peers.push(PeerTrace {
+            peer,
+            elapsed: self.start.elapsed(),
+            latency: None,
+            outcome,
+        });
+    }
+
+    /// Send the attempt to all the callers waiting on the query. The error, if any, is filled in
+    /// by the callers, as they are the ones to interpret the result.
+    pub(crate) fn complete(self, kad_stats: KadQueryStats) {
+        let attempt = AttemptTrace {
+            started: Duration::ZERO,
+            duration: self.start.elapsed(),
+            peers: self.peers,
+            kad_stats: Some(kad_stats),
+            error: None,
+        };
+        for sender in self.senders {
+            let _ = sender.send(attempt.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_record_trace() {
+        let (first_sender, mut first_receiver) = oneshot::channel();
+        let (second_sender, mut second_receiver) = oneshot::channel();
+        let mut trace = GetRecordTrace::new(first_sender);
+        trace.senders.push(second_sender);
+
+        let holder = PeerId::random();
+        let other = PeerId::random();
+        trace.add_peer(holder, PeerOutcome::RecordFound);
+        // the closest peers listed once the query completes include the ones that had the record
+        trace.add_peer(holder, PeerOutcome::RecordMissing);
+        trace.add_peer(other, PeerOutcome::RecordMissing);
+        trace.complete(KadQueryStats {
+            requests: 3,
+            successes: 2,
+            failures: 1,
+        });
+
+        for receiver in [&mut first_receiver, &mut second_receiver] {
+            let attempt = receiver
+                .try_recv()
+                .expect("The trace should have been sent");
+            let outcomes = attempt
+                .peers
+                .iter()
+                .map(|peer| (peer.peer, peer.outcome.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                outcomes,
+                vec![
+                    (holder, PeerOutcome::RecordFound),
+                    (other, PeerOutcome::RecordMissing)
+                ]
+            );
+            assert_eq!(attempt.kad_stats.map(|stats| stats.requests), Some(3));
+        }
+    }
+}
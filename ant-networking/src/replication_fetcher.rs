@@ -8,8 +8,9 @@
 #![allow(clippy::mutable_key_type)]
 
 use crate::time::spawn;
-use crate::{event::NetworkEvent, time::Instant, CLOSE_GROUP_SIZE};
+use crate::{bandwidth::BandwidthTracker, event::NetworkEvent, time::Instant};
 use ant_protocol::{
+    close_group_size,
     storage::{DataTypes, ValidationType},
     NetworkAddress, PrettyPrintRecordKey,
 };
@@ -23,6 +24,10 @@ use tokio::{sync::mpsc, time::Duration};
 // Max parallel fetches that can be undertaken at the same time.
 const MAX_PARALLEL_FETCH: usize = K_VALUE.get();
 
+// Max parallel fetches while the bandwidth limits are being hit,
+// to leave the bandwidth to the client GET/PUT requests.
+const SATURATED_PARALLEL_FETCH: usize = 1;
+
 // The duration after which a peer will be considered failed to fetch data from,
 // if no response got from that peer.
 // Note this will also cover the period that node self write the fetched copy to disk.
@@ -57,11 +62,17 @@ pub(crate) struct ReplicationFetcher {
     /// only records got `majority` of replicated in copies shall be trusted.
     /// This is the temp container to accumulate those intitial replicated in records.
     initial_replicates: HashMap<(NetworkAddress, ValidationType), HashSet<PeerId>>,
+    /// The traffic of the node, to hold replication back when the bandwidth is saturated.
+    bandwidth: Option<BandwidthTracker>,
 }
 
 impl ReplicationFetcher {
     /// Instantiate a new replication fetcher with passed PeerId.
-    pub(crate) fn new(self_peer_id: PeerId, event_sender: mpsc::Sender<NetworkEvent>) -> Self {
+    pub(crate) fn new(
+        self_peer_id: PeerId,
+        event_sender: mpsc::Sender<NetworkEvent>,
+        bandwidth: Option<BandwidthTracker>,
+    ) -> Self {
         Self {
             self_peer_id,
             to_be_fetched: HashMap::new(),
@@ -71,6 +82,7 @@ impl ReplicationFetcher {
             farthest_acceptable_distance: None,
             peers_scores: HashMap::new(),
             initial_replicates: HashMap::new(),
+            bandwidth,
         }
     }
 
@@ -205,12 +217,25 @@ impl ReplicationFetcher {
     // Returns the set of keys that has to be fetched from the peer/network.
     // Target must not be under-fetching
     // and no more than MAX_PARALLEL_FETCH fetches to be undertaken at the same time.
+    // Replication is prioritised below client requests, hence only SATURATED_PARALLEL_FETCH
+    // fetches are undertaken while the bandwidth limits are being hit.
     pub(crate) fn next_keys_to_fetch(&mut self) -> Vec<(PeerId, RecordKey)> {
         self.prune_expired_keys_and_slow_nodes();
 
         debug!("Next to fetch....");
 
-        if self.on_going_fetches.len() >= MAX_PARALLEL_FETCH {
+        let max_parallel_fetch = if self
+            .bandwidth
+            .as_ref()
+            .is_some_and(|bandwidth| bandwidth.is_saturated())
+        {
+            debug!("Bandwidth is saturated, holding back replication");
+            SATURATED_PARALLEL_FETCH
+        } else {
+            MAX_PARALLEL_FETCH
+        };
+
+        if self.on_going_fetches.len() >= max_parallel_fetch {
             warn!("Replication Fetcher doesn't have free fetch capacity. Currently has {} entries in queue.",
                 self.to_be_fetched.len());
             return vec![];
@@ -227,7 +252,7 @@ impl ReplicationFetcher {
         );
 
         // Pre-allocate vectors with known capacity
-        let remaining_capacity = MAX_PARALLEL_FETCH - self.on_going_fetches.len();
+        let remaining_capacity = max_parallel_fetch - self.on_going_fetches.len();
         let mut data_to_fetch = Vec::with_capacity(remaining_capacity);
 
         // Sort to_be_fetched by key closeness to our PeerId
@@ -245,7 +270,7 @@ impl ReplicationFetcher {
             // Already carried out expiration pruning above.
             // Hence here only need to check whether is ongoing fetching.
             // Also avoid fetching same record from different nodes.
-            if self.on_going_fetches.len() < MAX_PARALLEL_FETCH
+            if self.on_going_fetches.len() < max_parallel_fetch
                 && !self
                     .on_going_fetches
                     .contains_key(&(key.clone(), t.clone()))
@@ -258,7 +283,7 @@ impl ReplicationFetcher {
             }
 
             // break out the loop early if we can do no more now
-            if self.on_going_fetches.len() >= MAX_PARALLEL_FETCH {
+            if self.on_going_fetches.len() >= max_parallel_fetch {
                 break;
             }
         }
@@ -375,7 +400,7 @@ impl ReplicationFetcher {
             .values()
             .filter(|(scores, _last_seen)| scores.len() > 1)
             .count()
-            >= CLOSE_GROUP_SIZE
+            >= close_group_size()
     }
 
     // Accumulates initial replicates when doesn't have enough knowledge of peers scores.
@@ -396,7 +421,7 @@ impl ReplicationFetcher {
                 .entry(addr_val_type.clone())
                 .or_default();
             let _ = peers.insert(*holder);
-            if peers.len() >= CLOSE_GROUP_SIZE / 2 {
+            if peers.len() >= close_group_size() / 2 {
                 majorities.push(addr_val_type);
             }
         }
@@ -470,9 +495,9 @@ impl ReplicationFetcher {
                 // but still supposed to be held by the closest group to us.
                 if !is_in_range && distance.0 - distance_range.0 < distance_range.0 {
                     closest_k_peers.sort_by_key(|key| key.distance(addr));
-                    let closest_group: HashSet<_> = closest_k_peers.iter().take(CLOSE_GROUP_SIZE).collect();
+                    let closest_group: HashSet<_> = closest_k_peers.iter().take(close_group_size()).collect();
                     if closest_group.contains(&self_address) {
-                        debug!("Record {addr:?} has a far distance but still among {CLOSE_GROUP_SIZE} closest within {} neighbourd.", closest_k_peers.len());
+                        debug!("Record {addr:?} has a far distance but still among {} closest within {} neighbourd.", close_group_size(), closest_k_peers.len());
                         is_in_range = true;
                     }
                 }
@@ -596,7 +621,7 @@ impl ReplicationFetcher {
 #[cfg(test)]
 mod tests {
     use super::{ReplicationFetcher, FETCH_TIMEOUT, MAX_PARALLEL_FETCH};
-    use crate::CLOSE_GROUP_SIZE;
+    use ant_protocol::close_group_size;
     use ant_protocol::{storage::ValidationType, NetworkAddress};
     use eyre::Result;
     use libp2p::{kad::RecordKey, PeerId};
@@ -611,7 +636,7 @@ mod tests {
         //random peer_id
         let peer_id = PeerId::random();
         let (event_sender, _event_receiver) = mpsc::channel(4);
-        let mut replication_fetcher = ReplicationFetcher::new(peer_id, event_sender);
+        let mut replication_fetcher = ReplicationFetcher::new(peer_id, event_sender, None);
         let locally_stored_keys = HashMap::new();
 
         let mut incoming_keys = Vec::new();
@@ -685,7 +710,7 @@ mod tests {
         let peer_id = PeerId::random();
         let self_address = NetworkAddress::from_peer(peer_id);
         let (event_sender, _event_receiver) = mpsc::channel(4);
-        let mut replication_fetcher = ReplicationFetcher::new(peer_id, event_sender);
+        let mut replication_fetcher = ReplicationFetcher::new(peer_id, event_sender, None);
 
         // Set distance range
         let distance_target = NetworkAddress::from_peer(PeerId::random());
@@ -712,7 +737,7 @@ mod tests {
                 closest_k_peers_include_self.sort_by_key(|addr| key.distance(addr));
                 let closest_group: HashSet<_> = closest_k_peers_include_self
                     .iter()
-                    .take(CLOSE_GROUP_SIZE)
+                    .take(close_group_size())
                     .collect();
                 if closest_group.contains(&self_address) {
                     in_range_keys += 1;
@@ -0,0 +1,360 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    driver::NodeBehaviour, multiaddr_is_global, multiaddr_strip_p2p, time::spawn, PrivateNetwork,
+    SwarmDriver,
+};
+use libp2p::{
+    swarm::dial_opts::{DialOpts, PeerCondition},
+    Multiaddr, PeerId, Swarm,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+/// The name of the file holding the routing table snapshot of a node, inside its root dir.
+pub(crate) const NODE_ROUTING_TABLE_SNAPSHOT_FILE: &str = "routing_table_snapshot";
+
+/// A client adds the peers of the snapshot without checking them first, hence only a recent
+/// snapshot is used, as most of the peers of an older one are likely to have left.
+const MAX_CLIENT_SNAPSHOT_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// A node checks the peers of the snapshot before adding them, so an older snapshot can be used.
+const MAX_NODE_SNAPSHOT_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The peers of the routing table along with their addresses, persisted to disk so that the next
+/// start can be seeded with them instead of bootstrapping from scratch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct RoutingTableSnapshot {
+    pub(crate) peers: Vec<(PeerId, Vec<Multiaddr>)>,
+    pub(crate) saved_at: SystemTime,
+}
+
+impl Default for RoutingTableSnapshot {
+    fn default() -> Self {
+        Self {
+            peers: vec![],
+            saved_at: SystemTime::now(),
+        }
+    }
+}
+
+impl RoutingTableSnapshot {
+    /// Take a snapshot of the peers currently in the routing table.
+    pub(crate) fn from_swarm(swarm: &mut Swarm<NodeBehaviour>) -> Self {
+        let mut peers = vec![];
+        for kbucket in swarm.behaviour_mut().kademlia.kbuckets() {
+            for entry in kbucket.iter() {
+                let addrs = entry
+                    .node
+                    .value
+                    .iter()
+                    .map(multiaddr_strip_p2p)
+                    .collect::<Vec<_>>();
+                if !addrs.is_empty() {
+                    peers.push((*entry.node.key.preimage(), addrs));
+                }
+            }
+        }
+        Self {
+            peers,
+            saved_at: SystemTime::now(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// The peers to seed the routing table with: ourself, the peers outside of the allow-list of a
+    /// private network and the peers without any reachable address are skipped.
+    /// Only global addresses are kept, unless running a local network.
+    pub(crate) fn peers_to_seed(
+        self,
+        self_peer_id: &PeerId,
+        private_network: &PrivateNetwork,
+        local: bool,
+    ) -> Vec<(PeerId, Vec<Multiaddr>)> {
+        self.peers
+            .into_iter()
+            .filter(|(peer_id, _)| peer_id != self_peer_id && private_network.is_allowed(peer_id))
+            .filter_map(|(peer_id, addrs)| {
+                let addrs = addrs
+                    .into_iter()
+                    .filter(|addr| local || multiaddr_is_global(addr))
+                    .collect::<Vec<_>>();
+                (!addrs.is_empty()).then_some((peer_id, addrs))
+            })
+            .collect()
+    }
+
+    /// Load a snapshot from the file. A missing, unreadable or outdated file is treated as an empty
+    /// snapshot.
+    pub(crate) fn load(path: &Path, max_age: Duration) -> Self {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                debug!("No routing table snapshot found at {path:?}");
+                return Self::default();
+            }
+            Err(err) => {
+                warn!("Failed to read the routing table snapshot at {path:?}: {err}");
+                return Self::default();
+            }
+        };
+        match rmp_serde::from_slice::<Self>(&bytes) {
+            Ok(snapshot) => {
+                let age = snapshot.saved_at.elapsed().unwrap_or_default();
+                if age > max_age {
+                    info!("The routing table snapshot at {path:?} is outdated ({age:?} old), ignoring it");
+                    return Self::default();
+                }
+                snapshot
+            }
+            Err(err) => {
+                warn!("Failed to deserialize the routing table snapshot at {path:?}: {err}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Write the snapshot to the file. The file is replaced atomically, so a crash while writing
+    /// can't leave a corrupted snapshot behind.
+    pub(crate) fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = rmp_serde::to_vec(self).map_err(io::Error::other)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)
+    }
+}
+
+impl SwarmDriver {
+    /// Seed the routing table with the peers of the snapshot saved by a previous run.
+    ///
+    /// A client adds the peers right away, so that the network can be used without waiting for the
+    /// bootstrap. The peers that went away are evicted from the routing table once dialing them fails.
+    ///
+    /// A node dials the peers instead, as a liveness check. The peers that are still alive are then
+    /// added to the routing table through the usual identify exchange, which also ensures they are
+    /// on the same network as us.
+    pub(crate) fn seed_routing_table_from_snapshot(&mut self) {
+        let Some(path) = self.routing_table_snapshot_path.as_ref() else {
+            return;
+        };
+        let max_age = if self.is_client {
+            MAX_CLIENT_SNAPSHOT_AGE
+        } else {
+            MAX_NODE_SNAPSHOT_AGE
+        };
+        let peers = RoutingTableSnapshot::load(path, max_age).peers_to_seed(
+            &self.self_peer_id,
+            &self.private_network,
+            self.local,
+        );
+        info!(
+            "Seeding the routing table with {} peers from the snapshot at {path:?}",
+            peers.len()
+        );
+
+        for (peer_id, addrs) in peers {
+            if self.is_client {
+                for addr in addrs {
+                    let _routing_update = self
+                        .swarm
+                        .behaviour_mut()
+                        .kademlia
+                        .add_address(&peer_id, addr);
+                }
+            } else if let Err(err) = self.swarm.dial(
+                DialOpts::peer_id(peer_id)
+                    .condition(PeerCondition::NotDialing)
+                    .addresses(addrs)
+                    .build(),
+            ) {
+                debug!("Failed to dial {peer_id:?} from the routing table snapshot: {err:?}");
+            }
+        }
+    }
+
+    /// Persist the peers of the routing table, if enabled.
+    ///
+    /// An empty routing table is not saved, to not lose the peers of the previous snapshot
+    /// when we failed to connect to the network.
+    pub(crate) fn save_routing_table_snapshot(&mut self, blocking: bool) {
+        let Some(path) = self.routing_table_snapshot_path.clone() else {
+            return;
+        };
+        let snapshot = RoutingTableSnapshot::from_swarm(&mut self.swarm);
+        if snapshot.is_empty() {
+            debug!("The routing table is empty, not saving the snapshot");
+            return;
+        }
+
+        let save = move || {
+            if let Err(err) = snapshot.save(&path) {
+                error!("Failed to save the routing table snapshot to {path:?}: {err}");
+            } else {
+                debug!(
+                    "Saved the routing table snapshot with {} peers to {path:?}",
+                    snapshot.peers.len()
+                );
+            }
+        };
+        if blocking {
+            save();
+        } else {
+            let _handle = spawn(async move { save() });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::TempDir;
+
+    #[test]
+    fn test_snapshot_save_and_load() -> eyre::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("routing_table");
+
+        assert!(RoutingTableSnapshot::load(&path, MAX_CLIENT_SNAPSHOT_AGE).is_empty());
+
+        let mut snapshot = RoutingTableSnapshot {
+            saved_at: SystemTime::now(),
+            peers: vec![
+                (
+                    PeerId::random(),
+                    vec!["/ip4/127.0.0.1/udp/12000/quic-v1".parse()?],
+                ),
+                (
+                    PeerId::random(),
+                    vec![
+                        "/ip4/127.0.0.1/udp/12001/quic-v1".parse()?,
+                        "/ip4/127.0.0.1/tcp/12001".parse()?,
+                    ],
+                ),
+            ],
+        };
+        snapshot.save(&path)?;
+        assert_eq!(
+            RoutingTableSnapshot::load(&path, MAX_CLIENT_SNAPSHOT_AGE),
+            snapshot
+        );
+
+        // an outdated snapshot is not used
+        snapshot.saved_at = SystemTime::now() - MAX_CLIENT_SNAPSHOT_AGE * 2;
+        snapshot.save(&path)?;
+        assert!(RoutingTableSnapshot::load(&path, MAX_CLIENT_SNAPSHOT_AGE).is_empty());
+        assert_eq!(
+            RoutingTableSnapshot::load(&path, MAX_NODE_SNAPSHOT_AGE),
+            snapshot
+        );
+
+        // a corrupted file doesn't prevent the start
+        fs::write(&path, b"not a snapshot")?;
+        assert!(RoutingTableSnapshot::load(&path, MAX_NODE_SNAPSHOT_AGE).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot_save_leaves_no_temporary_file() -> eyre::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir
+            .path()
+            .join("node")
+            .join(NODE_ROUTING_TABLE_SNAPSHOT_FILE);
+
+        // the parent dir is created, and saving again replaces the previous snapshot
+        RoutingTableSnapshot::default().save(&path)?;
+        let snapshot = RoutingTableSnapshot {
+            saved_at: SystemTime::now(),
+            peers: vec![(PeerId::random(), vec!["/ip4/8.8.8.8/tcp/12000".parse()?])],
+        };
+        snapshot.save(&path)?;
+
+        assert_eq!(
+            RoutingTableSnapshot::load(&path, MAX_NODE_SNAPSHOT_AGE),
+            snapshot
+        );
+        assert!(!path.with_extension("tmp").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_peers_to_seed() -> eyre::Result<()> {
+        let self_peer_id = PeerId::random();
+        let (global, local_only, allowed_out) =
+            (PeerId::random(), PeerId::random(), PeerId::random());
+        let snapshot = RoutingTableSnapshot {
+            saved_at: SystemTime::now(),
+            peers: vec![
+                (self_peer_id, vec!["/ip4/8.8.8.8/tcp/12000".parse()?]),
+                (
+                    global,
+                    vec![
+                        "/ip4/8.8.8.8/udp/12001/quic-v1".parse()?,
+                        "/ip4/127.0.0.1/udp/12001/quic-v1".parse()?,
+                    ],
+                ),
+                (local_only, vec!["/ip4/127.0.0.1/tcp/12002".parse()?]),
+                (allowed_out, vec!["/ip4/8.8.4.4/tcp/12003".parse()?]),
+            ],
+        };
+
+        // on a public network, only the global addresses of the other peers are used
+        let public = PrivateNetwork::default();
+        let peers = snapshot
+            .clone()
+            .peers_to_seed(&self_peer_id, &public, false);
+        assert_eq!(
+            peers,
+            vec![
+                (global, vec!["/ip4/8.8.8.8/udp/12001/quic-v1".parse()?]),
+                (allowed_out, vec!["/ip4/8.8.4.4/tcp/12003".parse()?]),
+            ]
+        );
+
+        // on a local network, all the addresses are used
+        let peers = snapshot.clone().peers_to_seed(&self_peer_id, &public, true);
+        assert_eq!(
+            peers
+                .iter()
+                .map(|(peer_id, _)| *peer_id)
+                .collect::<Vec<_>>(),
+            vec![global, local_only, allowed_out]
+        );
+        assert_eq!(peers[0].1.len(), 2);
+
+        // the peers outside of the allow-list of a private network are skipped
+        let private = PrivateNetwork {
+            allowed_peers: Some([self_peer_id, global, local_only].into_iter().collect()),
+            ..Default::default()
+        };
+        let peers = snapshot.peers_to_seed(&self_peer_id, &private, true);
+        assert_eq!(
+            peers
+                .iter()
+                .map(|(peer_id, _)| *peer_id)
+                .collect::<Vec<_>>(),
+            vec![global, local_only]
+        );
+        Ok(())
+    }
+}
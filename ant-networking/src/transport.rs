@@ -8,19 +8,60 @@
 
 #[cfg(feature = "open-metrics")]
 use crate::MetricsRegistries;
+use futures::future::Either;
 use libp2p::{
-    core::{muxing::StreamMuxerBox, transport},
+    core::{muxing::StreamMuxerBox, transport, upgrade::Version},
     identity::Keypair,
+    pnet::{PnetConfig, PreSharedKey},
     PeerId, Transport as _,
 };
 
 const MAX_STREAM_DATA_ENV_STR: &str = "ANT_MAX_STREAM_DATA";
 
+/// The transports used alongside QUIC.
+///
+/// QUIC is enabled unless a private network key is set: the key cannot protect QUIC, so only TCP
+/// (and WebSocket if enabled) is used then, TCP being enabled even if not set here.
+///
+/// For a node, the port of an enabled transport is the port it listens on, with `0` picking a
+/// random port. A client only dials, so the port is ignored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TransportConfig {
+    /// Enable TCP (noise + yamux) on the given port
+    pub tcp: Option<u16>,
+    /// Enable WebSocket over TCP (noise + yamux) on the given port
+    pub websocket: Option<u16>,
+}
+
+/// Build the transport of the enabled protocols.
+///
+/// With a private network key, QUIC is left out as it cannot be protected by the key, and TCP is
+/// always enabled.
 pub(crate) fn build_transport(
     keypair: &Keypair,
+    config: TransportConfig,
+    psk: Option<PreSharedKey>,
     #[cfg(feature = "open-metrics")] registries: &mut MetricsRegistries,
 ) -> transport::Boxed<(PeerId, StreamMuxerBox)> {
-    let trans = generate_quic_transport(keypair);
+    let mut trans = if psk.is_none() {
+        generate_quic_transport(keypair)
+            .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+            .boxed()
+    } else {
+        tracing::info!("Private network key provided, QUIC is disabled");
+        transport::dummy::DummyTransport::new().boxed()
+    };
+
+    // WebSocket has to be tried before plain TCP, as the TCP transport would also accept
+    // the `/tcp/<port>/ws` addresses and then fail the websocket handshake.
+    if config.websocket.is_some() {
+        let ws = libp2p::websocket::WsConfig::new(generate_tcp_transport());
+        trans = upgrade_and_or(keypair, ws, psk, trans);
+    }
+    if config.tcp.is_some() || psk.is_some() {
+        trans = upgrade_and_or(keypair, generate_tcp_transport(), psk, trans);
+    }
+
     #[cfg(feature = "open-metrics")]
     let trans = libp2p::metrics::BandwidthTransport::new(trans, &mut registries.standard_metrics);
 
@@ -29,6 +70,62 @@ pub(crate) fn build_transport(
     trans.boxed()
 }
 
+/// Protect a stream based transport with the private network key if any, then secure it with
+/// noise, multiplex it with yamux and fall back to `other` for the addresses it doesn't support.
+fn upgrade_and_or<T>(
+    keypair: &Keypair,
+    stream_transport: T,
+    psk: Option<PreSharedKey>,
+    other: transport::Boxed<(PeerId, StreamMuxerBox)>,
+) -> transport::Boxed<(PeerId, StreamMuxerBox)>
+where
+    T: libp2p::Transport + Send + Unpin + 'static,
+    T::Output: futures::AsyncRead + futures::AsyncWrite + Send + Unpin + 'static,
+    T::Error: Send + Sync + 'static,
+    T::Dial: Send + 'static,
+    T::ListenerUpgrade: Send + 'static,
+{
+    match psk {
+        Some(psk) => secure_and_or(
+            keypair,
+            stream_transport.and_then(move |socket, _| PnetConfig::new(psk).handshake(socket)),
+            other,
+        ),
+        None => secure_and_or(keypair, stream_transport, other),
+    }
+}
+
+fn secure_and_or<T>(
+    keypair: &Keypair,
+    stream_transport: T,
+    other: transport::Boxed<(PeerId, StreamMuxerBox)>,
+) -> transport::Boxed<(PeerId, StreamMuxerBox)>
+where
+    T: libp2p::Transport + Send + Unpin + 'static,
+    T::Output: futures::AsyncRead + futures::AsyncWrite + Send + Unpin + 'static,
+    T::Error: Send + Sync + 'static,
+    T::Dial: Send + 'static,
+    T::ListenerUpgrade: Send + 'static,
+{
+    stream_transport
+        .upgrade(Version::V1Lazy)
+        .authenticate(
+            libp2p::noise::Config::new(keypair)
+                .expect("Signing libp2p-noise static DH keypair failed."),
+        )
+        .multiplex(libp2p::yamux::Config::default())
+        .or_transport(other)
+        .map(|either_output, _| match either_output {
+            Either::Left((peer_id, muxer)) => (peer_id, StreamMuxerBox::new(muxer)),
+            Either::Right((peer_id, muxer)) => (peer_id, muxer),
+        })
+        .boxed()
+}
+
+fn generate_tcp_transport() -> libp2p::tcp::tokio::Transport {
+    libp2p::tcp::tokio::Transport::new(libp2p::tcp::Config::default().nodelay(true))
+}
+
 fn generate_quic_transport(
     keypair: &Keypair,
 ) -> libp2p::quic::GenTransport<libp2p::quic::tokio::Provider> {
@@ -70,6 +70,7 @@ impl PortRange {
 
 #[derive(Debug, PartialEq)]
 pub struct InstallNodeServiceCtxBuilder {
+    pub allowed_peers_file: Option<PathBuf>,
     pub antnode_path: PathBuf,
     pub autostart: bool,
     pub data_dir_path: PathBuf,
@@ -80,6 +81,7 @@ pub struct InstallNodeServiceCtxBuilder {
     pub log_format: Option<LogFormat>,
     pub name: String,
     pub network_id: Option<u8>,
+    pub network_key_file: Option<PathBuf>,
     pub max_archived_log_files: Option<usize>,
     pub max_log_files: Option<usize>,
     pub metrics_port: Option<u16>,
@@ -109,6 +111,14 @@ impl InstallNodeServiceCtxBuilder {
             args.push(OsString::from("--network-id"));
             args.push(OsString::from(id.to_string()));
         }
+        if let Some(path) = self.network_key_file {
+            args.push(OsString::from("--network-key-file"));
+            args.push(OsString::from(path.to_string_lossy().to_string()));
+        }
+        if let Some(path) = self.allowed_peers_file {
+            args.push(OsString::from("--allowed-peers-file"));
+            args.push(OsString::from(path.to_string_lossy().to_string()));
+        }
         if self.home_network {
             args.push(OsString::from("--home-network"));
         }
@@ -171,6 +181,7 @@ impl InstallNodeServiceCtxBuilder {
 }
 
 pub struct AddNodeServiceOptions {
+    pub allowed_peers_file: Option<PathBuf>,
     pub antnode_dir_path: PathBuf,
     pub antnode_src_path: PathBuf,
     pub auto_restart: bool,
@@ -186,6 +197,7 @@ pub struct AddNodeServiceOptions {
     pub max_log_files: Option<usize>,
     pub metrics_port: Option<PortRange>,
     pub network_id: Option<u8>,
+    pub network_key_file: Option<PathBuf>,
     pub node_ip: Option<Ipv4Addr>,
     pub node_port: Option<PortRange>,
     pub peers_args: PeersArgs,
@@ -306,6 +318,7 @@ mod tests {
 
     fn create_default_builder() -> InstallNodeServiceCtxBuilder {
         InstallNodeServiceCtxBuilder {
+            allowed_peers_file: None,
             antnode_path: PathBuf::from("/bin/antnode"),
             autostart: true,
             data_dir_path: PathBuf::from("/data"),
@@ -319,6 +332,7 @@ mod tests {
             metrics_port: None,
             name: "test-node".to_string(),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -332,6 +346,7 @@ mod tests {
 
     fn create_custom_evm_network_builder() -> InstallNodeServiceCtxBuilder {
         InstallNodeServiceCtxBuilder {
+            allowed_peers_file: None,
             autostart: true,
             data_dir_path: PathBuf::from("/data"),
             env_variables: None,
@@ -354,6 +369,7 @@ mod tests {
             metrics_port: None,
             name: "test-node".to_string(),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -368,6 +384,7 @@ mod tests {
 
     fn create_builder_with_all_options_enabled() -> InstallNodeServiceCtxBuilder {
         InstallNodeServiceCtxBuilder {
+            allowed_peers_file: None,
             autostart: true,
             data_dir_path: PathBuf::from("/data"),
             env_variables: None,
@@ -390,6 +407,7 @@ mod tests {
             metrics_port: None,
             name: "test-node".to_string(),
             network_id: Some(5),
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -481,6 +499,8 @@ mod tests {
         builder.node_ip = Some(Ipv4Addr::new(192, 168, 1, 1));
         builder.node_port = Some(12345);
         builder.metrics_port = Some(9090);
+        builder.network_key_file = Some(PathBuf::from("/keys/swarm.key"));
+        builder.allowed_peers_file = Some(PathBuf::from("/keys/allowed_peers"));
         builder.peers_args.addrs = vec![
             "/ip4/127.0.0.1/tcp/8080".parse().unwrap(),
             "/ip4/192.168.1.1/tcp/8081".parse().unwrap(),
@@ -511,6 +531,10 @@ mod tests {
             "--ignore-cache",
             "--network-id",
             "5",
+            "--network-key-file",
+            "/keys/swarm.key",
+            "--allowed-peers-file",
+            "/keys/allowed_peers",
             "--home-network",
             "--log-format",
             "json",
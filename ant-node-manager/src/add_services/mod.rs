@@ -189,6 +189,7 @@ pub async fn add_node(
         }
 
         let install_ctx = InstallNodeServiceCtxBuilder {
+            allowed_peers_file: options.allowed_peers_file.clone(),
             autostart: options.auto_restart,
             data_dir_path: service_data_dir_path.clone(),
             env_variables: options.env_variables.clone(),
@@ -201,6 +202,7 @@ pub async fn add_node(
             metrics_port: metrics_free_port,
             name: service_name.clone(),
             network_id: options.network_id,
+            network_key_file: options.network_key_file.clone(),
             node_ip: options.node_ip,
             node_port,
             peers_args: options.peers_args.clone(),
@@ -224,6 +226,7 @@ pub async fn add_node(
                 ));
 
                 node_registry.nodes.push(NodeServiceData {
+                    allowed_peers_file: options.allowed_peers_file.clone(),
                     antnode_path: service_antnode_path,
                     auto_restart: options.auto_restart,
                     connected_peers: None,
@@ -237,6 +240,7 @@ pub async fn add_node(
                     max_log_files: options.max_log_files,
                     metrics_port: metrics_free_port,
                     network_id: options.network_id,
+                    network_key_file: options.network_key_file.clone(),
                     node_ip: options.node_ip,
                     node_port,
                     number: node_number,
@@ -120,6 +120,7 @@ async fn add_genesis_node_should_use_latest_version_and_add_one_service() -> Res
     };
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -140,6 +141,7 @@ async fn add_genesis_node_should_use_latest_version_and_add_one_service() -> Res
         metrics_port: None,
         name: "antnode1".to_string(),
         network_id: None,
+        network_key_file: None,
         node_ip: None,
         node_port: None,
         peers_args: peers_args.clone(),
@@ -162,6 +164,7 @@ async fn add_genesis_node_should_use_latest_version_and_add_one_service() -> Res
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -174,6 +177,7 @@ async fn add_genesis_node_should_use_latest_version_and_add_one_service() -> Res
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args,
@@ -275,6 +279,7 @@ async fn add_genesis_node_should_return_an_error_if_there_is_already_a_genesis_n
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -295,6 +300,7 @@ async fn add_genesis_node_should_return_an_error_if_there_is_already_a_genesis_n
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -330,6 +336,7 @@ async fn add_genesis_node_should_return_an_error_if_there_is_already_a_genesis_n
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -342,6 +349,7 @@ async fn add_genesis_node_should_return_an_error_if_there_is_already_a_genesis_n
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args,
@@ -419,6 +427,7 @@ async fn add_genesis_node_should_return_an_error_if_count_is_greater_than_1() ->
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(3),
@@ -431,6 +440,7 @@ async fn add_genesis_node_should_return_an_error_if_count_is_greater_than_1() ->
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args,
@@ -507,6 +517,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -526,6 +537,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -555,6 +567,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
         .returning(|| Ok(8083))
         .in_sequence(&mut seq);
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode2"),
         env_variables: None,
@@ -574,6 +587,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode2".to_string(),
         node_ip: None,
         node_port: None,
@@ -603,6 +617,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
         .returning(|| Ok(8085))
         .in_sequence(&mut seq);
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode3"),
         env_variables: None,
@@ -622,6 +637,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode3".to_string(),
         node_ip: None,
         node_port: None,
@@ -646,6 +662,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(3),
@@ -658,6 +675,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -784,6 +802,7 @@ async fn add_node_should_update_the_environment_variables_inside_node_registry()
         .returning(|| Ok(12001))
         .in_sequence(&mut seq);
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: env_variables.clone(),
@@ -803,6 +822,7 @@ async fn add_node_should_update_the_environment_variables_inside_node_registry()
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -826,6 +846,7 @@ async fn add_node_should_update_the_environment_variables_inside_node_registry()
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -838,6 +859,7 @@ async fn add_node_should_update_the_environment_variables_inside_node_registry()
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -912,6 +934,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -932,6 +955,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -969,6 +993,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
         .returning(|| Ok(8083))
         .in_sequence(&mut seq);
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode2"),
         env_variables: None,
@@ -988,6 +1013,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode2".to_string(),
         node_ip: None,
         node_port: None,
@@ -1012,6 +1038,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -1024,6 +1051,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -1175,6 +1203,7 @@ async fn add_node_should_create_service_file_with_first_arg() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -1187,6 +1216,7 @@ async fn add_node_should_create_service_file_with_first_arg() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: peers_args.clone(),
@@ -1331,6 +1361,7 @@ async fn add_node_should_create_service_file_with_peers_args() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -1343,6 +1374,7 @@ async fn add_node_should_create_service_file_with_peers_args() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: peers_args.clone(),
@@ -1482,6 +1514,7 @@ async fn add_node_should_create_service_file_with_local_arg() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -1494,6 +1527,7 @@ async fn add_node_should_create_service_file_with_local_arg() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: peers_args.clone(),
@@ -1637,6 +1671,7 @@ async fn add_node_should_create_service_file_with_network_contacts_url_arg() ->
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -1649,6 +1684,7 @@ async fn add_node_should_create_service_file_with_network_contacts_url_arg() ->
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: peers_args.clone(),
@@ -1791,6 +1827,7 @@ async fn add_node_should_create_service_file_with_testnet_arg() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -1803,6 +1840,7 @@ async fn add_node_should_create_service_file_with_testnet_arg() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: peers_args.clone(),
@@ -1942,6 +1980,7 @@ async fn add_node_should_create_service_file_with_ignore_cache_arg() -> Result<(
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -1954,6 +1993,7 @@ async fn add_node_should_create_service_file_with_ignore_cache_arg() -> Result<(
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: peers_args.clone(),
@@ -2094,6 +2134,7 @@ async fn add_node_should_create_service_file_with_custom_bootstrap_cache_path()
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -2106,6 +2147,7 @@ async fn add_node_should_create_service_file_with_custom_bootstrap_cache_path()
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: peers_args.clone(),
@@ -2239,6 +2281,7 @@ async fn add_node_should_create_service_file_with_network_id() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -2251,6 +2294,7 @@ async fn add_node_should_create_service_file_with_network_id() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: Some(5),
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: Default::default(),
@@ -2382,6 +2426,7 @@ async fn add_node_should_use_custom_ip() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -2394,6 +2439,7 @@ async fn add_node_should_use_custom_ip() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: Some(custom_ip),
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -2471,6 +2517,7 @@ async fn add_node_should_use_custom_ports_for_one_service() -> Result<()> {
         .returning(|| Ok(12001))
         .in_sequence(&mut seq);
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -2490,6 +2537,7 @@ async fn add_node_should_use_custom_ports_for_one_service() -> Result<()> {
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: Some(custom_port),
@@ -2514,6 +2562,7 @@ async fn add_node_should_use_custom_ports_for_one_service() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -2526,6 +2575,7 @@ async fn add_node_should_use_custom_ports_for_one_service() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(PortRange::Single(custom_port)),
             peers_args: PeersArgs::default(),
@@ -2771,6 +2821,7 @@ async fn add_node_should_use_a_custom_port_range() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(3),
@@ -2783,6 +2834,7 @@ async fn add_node_should_use_a_custom_port_range() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(PortRange::Range(12000, 12002)),
             peers_args: PeersArgs::default(),
@@ -2837,6 +2889,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_is_used() -> R
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2857,6 +2910,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_is_used() -> R
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(12000),
             number: 1,
@@ -2890,6 +2944,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_is_used() -> R
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -2902,6 +2957,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_is_used() -> R
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(PortRange::Single(12000)),
             peers_args: PeersArgs::default(),
@@ -2954,6 +3010,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_in_range_is_us
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2974,6 +3031,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_in_range_is_us
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(12000),
             peers_args: PeersArgs::default(),
@@ -3007,6 +3065,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_in_range_is_us
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(3),
@@ -3019,6 +3078,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_in_range_is_us
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(PortRange::Range(12000, 12002)),
             peers_args: PeersArgs::default(),
@@ -3085,6 +3145,7 @@ async fn add_node_should_return_an_error_if_port_and_node_count_do_not_match() -
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(2),
@@ -3097,6 +3158,7 @@ async fn add_node_should_return_an_error_if_port_and_node_count_do_not_match() -
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(PortRange::Range(12000, 12002)),
             peers_args: PeersArgs::default(),
@@ -3168,6 +3230,7 @@ async fn add_node_should_return_an_error_if_multiple_services_are_specified_with
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(2),
@@ -3180,6 +3243,7 @@ async fn add_node_should_return_an_error_if_multiple_services_are_specified_with
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: Some(PortRange::Single(12000)),
             peers_args: PeersArgs::default(),
@@ -3313,6 +3377,7 @@ async fn add_node_should_set_random_ports_if_enable_metrics_server_is_true() ->
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -3325,6 +3390,7 @@ async fn add_node_should_set_random_ports_if_enable_metrics_server_is_true() ->
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -3450,6 +3516,7 @@ async fn add_node_should_set_max_archived_log_files() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(1),
@@ -3462,6 +3529,7 @@ async fn add_node_should_set_max_archived_log_files() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -3588,6 +3656,7 @@ async fn add_node_should_set_max_log_files() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(1),
@@ -3600,6 +3669,7 @@ async fn add_node_should_set_max_log_files() -> Result<()> {
             max_log_files: Some(20),
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -3840,6 +3910,7 @@ async fn add_node_should_use_a_custom_port_range_for_metrics_server() -> Result<
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(3),
@@ -3852,6 +3923,7 @@ async fn add_node_should_use_a_custom_port_range_for_metrics_server() -> Result<
             max_log_files: None,
             metrics_port: Some(PortRange::Range(12000, 12002)),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -3903,6 +3975,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_is_use
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -3923,6 +3996,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_is_use
             max_log_files: None,
             metrics_port: Some(12000),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -3956,6 +4030,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_is_use
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -3968,6 +4043,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_is_use
             max_log_files: None,
             metrics_port: Some(PortRange::Single(12000)),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -4021,6 +4097,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_in_ran
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4041,6 +4118,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_in_ran
             max_log_files: None,
             metrics_port: Some(12000),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -4074,6 +4152,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_in_ran
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(3),
@@ -4086,6 +4165,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_in_ran
             max_log_files: None,
             metrics_port: Some(PortRange::Range(12000, 12002)),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -4309,6 +4389,7 @@ async fn add_node_should_use_a_custom_port_range_for_the_rpc_server() -> Result<
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(3),
@@ -4321,6 +4402,7 @@ async fn add_node_should_use_a_custom_port_range_for_the_rpc_server() -> Result<
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -4383,6 +4465,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_is_used()
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4403,6 +4486,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_is_used()
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -4436,6 +4520,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_is_used()
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: None,
@@ -4448,6 +4533,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_is_used()
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -4501,6 +4587,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_in_range_i
         save_path: node_reg_path.to_path_buf(),
         nat_status: None,
         nodes: vec![NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4521,6 +4608,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_in_range_i
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -4554,6 +4642,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_in_range_i
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(2),
@@ -4566,6 +4655,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_in_range_i
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -4641,6 +4731,7 @@ async fn add_node_should_disable_upnp_and_home_network_if_nat_status_is_public()
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -4660,6 +4751,7 @@ async fn add_node_should_disable_upnp_and_home_network_if_nat_status_is_public()
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -4683,6 +4775,7 @@ async fn add_node_should_disable_upnp_and_home_network_if_nat_status_is_public()
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: true,
             count: None,
@@ -4695,6 +4788,7 @@ async fn add_node_should_disable_upnp_and_home_network_if_nat_status_is_public()
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -4767,6 +4861,7 @@ async fn add_node_should_enable_upnp_if_nat_status_is_upnp() -> Result<()> {
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -4786,6 +4881,7 @@ async fn add_node_should_enable_upnp_if_nat_status_is_upnp() -> Result<()> {
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -4809,6 +4905,7 @@ async fn add_node_should_enable_upnp_if_nat_status_is_upnp() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: true,
             count: None,
@@ -4821,6 +4918,7 @@ async fn add_node_should_enable_upnp_if_nat_status_is_upnp() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -4893,6 +4991,7 @@ async fn add_node_should_enable_home_network_if_nat_status_is_private() -> Resul
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -4912,6 +5011,7 @@ async fn add_node_should_enable_home_network_if_nat_status_is_private() -> Resul
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -4935,6 +5035,7 @@ async fn add_node_should_enable_home_network_if_nat_status_is_private() -> Resul
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: true,
             count: None,
@@ -4947,6 +5048,7 @@ async fn add_node_should_enable_home_network_if_nat_status_is_private() -> Resul
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -5021,6 +5123,7 @@ async fn add_node_should_return_an_error_if_nat_status_is_none_but_auto_set_nat_
 
     let result = add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: true,
             count: None,
@@ -5033,6 +5136,7 @@ async fn add_node_should_return_an_error_if_nat_status_is_none_but_auto_set_nat_
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -5634,6 +5738,7 @@ async fn add_node_should_not_delete_the_source_binary_if_path_arg_is_used() -> R
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -5653,6 +5758,7 @@ async fn add_node_should_not_delete_the_source_binary_if_path_arg_is_used() -> R
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -5677,6 +5783,7 @@ async fn add_node_should_not_delete_the_source_binary_if_path_arg_is_used() -> R
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(1),
@@ -5689,6 +5796,7 @@ async fn add_node_should_not_delete_the_source_binary_if_path_arg_is_used() -> R
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -5762,6 +5870,7 @@ async fn add_node_should_apply_the_home_network_flag_if_it_is_used() -> Result<(
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -5781,6 +5890,7 @@ async fn add_node_should_apply_the_home_network_flag_if_it_is_used() -> Result<(
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -5805,6 +5915,7 @@ async fn add_node_should_apply_the_home_network_flag_if_it_is_used() -> Result<(
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(1),
@@ -5817,6 +5928,7 @@ async fn add_node_should_apply_the_home_network_flag_if_it_is_used() -> Result<(
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -5890,6 +6002,7 @@ async fn add_node_should_add_the_node_in_user_mode() -> Result<()> {
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -5909,6 +6022,7 @@ async fn add_node_should_add_the_node_in_user_mode() -> Result<()> {
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -5933,6 +6047,7 @@ async fn add_node_should_add_the_node_in_user_mode() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(1),
@@ -5945,6 +6060,7 @@ async fn add_node_should_add_the_node_in_user_mode() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -6015,6 +6131,7 @@ async fn add_node_should_add_the_node_with_upnp_enabled() -> Result<()> {
         .in_sequence(&mut seq);
 
     let install_ctx = InstallNodeServiceCtxBuilder {
+        allowed_peers_file: None,
         autostart: false,
         data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
         env_variables: None,
@@ -6034,6 +6151,7 @@ async fn add_node_should_add_the_node_with_upnp_enabled() -> Result<()> {
         max_log_files: None,
         metrics_port: None,
         network_id: None,
+        network_key_file: None,
         name: "antnode1".to_string(),
         node_ip: None,
         node_port: None,
@@ -6058,6 +6176,7 @@ async fn add_node_should_add_the_node_with_upnp_enabled() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: false,
             auto_set_nat_flags: false,
             count: Some(1),
@@ -6070,6 +6189,7 @@ async fn add_node_should_add_the_node_with_upnp_enabled() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -6192,6 +6312,7 @@ async fn add_node_should_auto_restart() -> Result<()> {
 
     add_node(
         AddNodeServiceOptions {
+            allowed_peers_file: None,
             auto_restart: true,
             auto_set_nat_flags: false,
             count: None,
@@ -6204,6 +6325,7 @@ async fn add_node_should_auto_restart() -> Result<()> {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             peers_args: PeersArgs::default(),
@@ -77,6 +77,12 @@ pub enum SubCmd {
     /// distributions, however, use Systemd, which *does* support user-mode services.
     #[clap(name = "add")]
     Add {
+        /// Provide the path of a file listing the peer IDs of the nodes and clients allowed in a
+        /// private network, one per line.
+        ///
+        /// The file must be readable by the service user.
+        #[clap(long, verbatim_doc_comment)]
+        allowed_peers_file: Option<PathBuf>,
         /// Set to automatically restart antnode services upon OS reboot.
         ///
         /// If not used, any added services will *not* restart automatically when the OS reboots
@@ -177,6 +183,13 @@ pub enum SubCmd {
         /// By default, the network ID is set to 1, which represents the mainnet.
         #[clap(long, verbatim_doc_comment)]
         network_id: Option<u8>,
+        /// Provide the path of the pre-shared key file of a private network, in the libp2p
+        /// `swarm.key` format.
+        ///
+        /// The services then listen on TCP instead of QUIC. The file must be readable by the service
+        /// user.
+        #[clap(long, verbatim_doc_comment)]
+        network_key_file: Option<PathBuf>,
         /// Specify the IP address for the antnode service(s).
         ///
         /// If not set, we bind to all the available network interfaces.
@@ -765,6 +778,23 @@ pub enum LocalSubCmd {
         /// Set to skip the network validation process
         #[clap(long)]
         skip_validation: bool,
+        /// Set to enable the TCP transport on the nodes, alongside QUIC. The ports will be selected at random.
+        #[clap(long)]
+        tcp: bool,
+        /// Set to enable the WebSocket transport on the nodes, alongside QUIC. The ports will be selected at
+        /// random.
+        #[clap(long)]
+        websocket: bool,
+        /// Provide the path of the pre-shared key file of a private network, in the libp2p `swarm.key`
+        /// format.
+        ///
+        /// The nodes then listen on TCP instead of QUIC.
+        #[clap(long, verbatim_doc_comment)]
+        network_key_file: Option<PathBuf>,
+        /// Provide the path of a file listing the peer IDs of the nodes and clients allowed in a
+        /// private network, one per line.
+        #[clap(long, verbatim_doc_comment)]
+        allowed_peers_file: Option<PathBuf>,
     },
     /// Run a local network.
     ///
@@ -862,6 +892,23 @@ pub enum LocalSubCmd {
         /// Set to skip the network validation process
         #[clap(long)]
         skip_validation: bool,
+        /// Set to enable the TCP transport on the nodes, alongside QUIC. The ports will be selected at random.
+        #[clap(long)]
+        tcp: bool,
+        /// Set to enable the WebSocket transport on the nodes, alongside QUIC. The ports will be selected at
+        /// random.
+        #[clap(long)]
+        websocket: bool,
+        /// Provide the path of the pre-shared key file of a private network, in the libp2p `swarm.key`
+        /// format.
+        ///
+        /// The nodes then listen on TCP instead of QUIC.
+        #[clap(long, verbatim_doc_comment)]
+        network_key_file: Option<PathBuf>,
+        /// Provide the path of a file listing the peer IDs of the nodes and clients allowed in a
+        /// private network, one per line.
+        #[clap(long, verbatim_doc_comment)]
+        allowed_peers_file: Option<PathBuf>,
     },
     /// Get the status of the local nodes.
     #[clap(name = "status")]
@@ -925,6 +972,7 @@ async fn main() -> Result<()> {
 
     match args.cmd {
         Some(SubCmd::Add {
+            allowed_peers_file,
             auto_restart,
             auto_set_nat_flags,
             count,
@@ -939,6 +987,7 @@ async fn main() -> Result<()> {
             max_log_files,
             metrics_port,
             network_id,
+            network_key_file,
             node_ip,
             node_port,
             path,
@@ -952,6 +1001,7 @@ async fn main() -> Result<()> {
             version,
         }) => {
             cmd::node::add(
+                allowed_peers_file,
                 auto_restart,
                 auto_set_nat_flags,
                 count,
@@ -966,6 +1016,7 @@ async fn main() -> Result<()> {
                 max_log_files,
                 metrics_port,
                 network_id,
+                network_key_file,
                 node_ip,
                 node_port,
                 peers,
@@ -1052,6 +1103,10 @@ async fn main() -> Result<()> {
                 rewards_address,
                 evm_network,
                 skip_validation: _,
+                tcp,
+                websocket,
+                network_key_file,
+                allowed_peers_file,
             } => {
                 let evm_network = if let Some(evm_network) = evm_network {
                     Some(evm_network.try_into()?)
@@ -1073,6 +1128,10 @@ async fn main() -> Result<()> {
                     rewards_address,
                     evm_network,
                     true,
+                    tcp,
+                    websocket,
+                    network_key_file,
+                    allowed_peers_file,
                     verbosity,
                 )
                 .await
@@ -1093,6 +1152,10 @@ async fn main() -> Result<()> {
                 rewards_address,
                 evm_network,
                 skip_validation: _,
+                tcp,
+                websocket,
+                network_key_file,
+                allowed_peers_file,
             } => {
                 let evm_network = if let Some(evm_network) = evm_network {
                     Some(evm_network.try_into()?)
@@ -1114,6 +1177,10 @@ async fn main() -> Result<()> {
                     rewards_address,
                     evm_network,
                     true,
+                    tcp,
+                    websocket,
+                    network_key_file,
+                    allowed_peers_file,
                     verbosity,
                 )
                 .await
@@ -39,6 +39,10 @@ pub async fn join(
     rewards_address: RewardsAddress,
     evm_network: Option<EvmNetwork>,
     skip_validation: bool,
+    tcp: bool,
+    websocket: bool,
+    network_key_file: Option<PathBuf>,
+    allowed_peers_file: Option<PathBuf>,
     verbosity: VerbosityLevel,
 ) -> Result<(), Report> {
     if verbosity != VerbosityLevel::Minimal {
@@ -82,6 +86,10 @@ pub async fn join(
         log_format,
         rewards_address,
         evm_network,
+        tcp,
+        websocket,
+        network_key_file,
+        allowed_peers_file,
     };
     run_network(options, &mut local_node_registry, &ServiceController {}).await?;
     Ok(())
@@ -119,6 +127,10 @@ pub async fn run(
     rewards_address: RewardsAddress,
     evm_network: Option<EvmNetwork>,
     skip_validation: bool,
+    tcp: bool,
+    websocket: bool,
+    network_key_file: Option<PathBuf>,
+    allowed_peers_file: Option<PathBuf>,
     verbosity: VerbosityLevel,
 ) -> Result<(), Report> {
     if (enable_metrics_server || metrics_port.is_some()) && !cfg!(feature = "open-metrics") && build
@@ -188,6 +200,10 @@ pub async fn run(
         log_format,
         rewards_address,
         evm_network,
+        tcp,
+        websocket,
+        network_key_file,
+        allowed_peers_file,
     };
     run_network(options, &mut local_node_registry, &ServiceController {}).await?;
 
@@ -36,6 +36,7 @@ use tracing::debug;
 
 /// Returns the added service names
 pub async fn add(
+    allowed_peers_file: Option<PathBuf>,
     auto_restart: bool,
     auto_set_nat_flags: bool,
     count: Option<u16>,
@@ -50,6 +51,7 @@ pub async fn add(
     max_log_files: Option<usize>,
     metrics_port: Option<PortRange>,
     network_id: Option<u8>,
+    network_key_file: Option<PathBuf>,
     node_ip: Option<Ipv4Addr>,
     node_port: Option<PortRange>,
     mut peers_args: PeersArgs,
@@ -112,7 +114,16 @@ pub async fn add(
     peers_args.addrs.extend(PeersArgs::read_addr_from_env());
     peers_args.bootstrap_cache_dir = bootstrap_cache_dir;
 
+    // The services don't run from the current dir, so they are given the absolute paths
+    let allowed_peers_file = allowed_peers_file
+        .map(|path| path.canonicalize())
+        .transpose()?;
+    let network_key_file = network_key_file
+        .map(|path| path.canonicalize())
+        .transpose()?;
+
     let options = AddNodeServiceOptions {
+        allowed_peers_file,
         auto_restart,
         auto_set_nat_flags,
         count,
@@ -126,6 +137,7 @@ pub async fn add(
         max_log_files,
         metrics_port,
         network_id,
+        network_key_file,
         node_ip,
         node_port,
         peers_args,
@@ -684,6 +696,7 @@ pub async fn maintain_n_running_nodes(
 
                 for (i, port) in ports_to_use.into_iter().enumerate() {
                     let added_service = add(
+                        None,
                         auto_restart,
                         auto_set_nat_flags,
                         Some(1),
@@ -698,6 +711,7 @@ pub async fn maintain_n_running_nodes(
                         max_log_files,
                         metrics_port.clone(),
                         network_id,
+                        None,
                         node_ip,
                         Some(PortRange::Single(port)),
                         peers_args.clone(),
@@ -748,6 +748,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -768,6 +769,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -861,6 +863,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -881,6 +884,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -939,6 +943,7 @@ mod tests {
             .returning(|_| Ok(100));
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -959,6 +964,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1057,6 +1063,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1077,6 +1084,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1148,6 +1156,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1168,6 +1177,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1249,6 +1259,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1269,6 +1280,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1349,6 +1361,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1369,6 +1382,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1419,6 +1433,7 @@ mod tests {
             .returning(|_| Ok(100));
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1439,6 +1454,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1481,6 +1497,7 @@ mod tests {
     #[tokio::test]
     async fn stop_should_not_return_error_for_attempt_to_stop_installed_service() -> Result<()> {
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1501,6 +1518,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1541,6 +1559,7 @@ mod tests {
     async fn stop_should_return_ok_when_attempting_to_stop_service_that_was_already_stopped(
     ) -> Result<()> {
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1561,6 +1580,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1604,6 +1624,7 @@ mod tests {
     #[tokio::test]
     async fn stop_should_return_ok_when_attempting_to_stop_a_removed_service() -> Result<()> {
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1624,6 +1645,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1678,6 +1700,7 @@ mod tests {
             .returning(|_| Ok(100));
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1698,6 +1721,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1817,6 +1841,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1837,6 +1862,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -1917,6 +1943,7 @@ mod tests {
         let mock_rpc_client = MockRpcClient::new();
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -1937,6 +1964,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -2062,6 +2090,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2082,6 +2111,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -2219,6 +2249,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2239,6 +2270,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -2371,6 +2403,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2391,6 +2424,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -2524,6 +2558,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2544,6 +2579,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -2710,6 +2746,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2722,6 +2759,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -2881,6 +2919,7 @@ mod tests {
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -2893,6 +2932,7 @@ mod tests {
             max_log_files: None,
             metrics_port: None,
 network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -3058,6 +3098,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -3070,6 +3111,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: Some(5),
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -3115,6 +3157,178 @@ network_id: None,
         Ok(())
     }
 
+    #[tokio::test]
+    async fn upgrade_should_retain_the_private_network_args() -> Result<()> {
+        let current_version = "0.1.0";
+        let target_version = "0.2.0";
+
+        let tmp_data_dir = assert_fs::TempDir::new()?;
+        let current_install_dir = tmp_data_dir.child("antnode_install");
+        current_install_dir.create_dir_all()?;
+
+        let current_node_bin = current_install_dir.child("antnode");
+        current_node_bin.write_binary(b"fake antnode binary")?;
+        let target_node_bin = tmp_data_dir.child("antnode");
+        target_node_bin.write_binary(b"fake antnode binary")?;
+
+        let mut mock_service_control = MockServiceControl::new();
+        let mut mock_rpc_client = MockRpcClient::new();
+
+        // before binary upgrade
+        mock_service_control
+            .expect_get_process_pid()
+            .with(eq(current_node_bin.to_path_buf().clone()))
+            .times(1)
+            .returning(|_| Ok(1000));
+        mock_service_control
+            .expect_stop()
+            .with(eq("antnode1"), eq(false))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // after binary upgrade
+        mock_service_control
+            .expect_uninstall()
+            .with(eq("antnode1"), eq(false))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_service_control
+            .expect_install()
+            .with(
+                eq(ServiceInstallCtx {
+                    args: vec![
+                        OsString::from("--rpc"),
+                        OsString::from("127.0.0.1:8081"),
+                        OsString::from("--root-dir"),
+                        OsString::from("/var/antctl/services/antnode1"),
+                        OsString::from("--log-output-dest"),
+                        OsString::from("/var/log/antnode/antnode1"),
+                        OsString::from("--network-key-file"),
+                        OsString::from("/var/antctl/swarm.key"),
+                        OsString::from("--allowed-peers-file"),
+                        OsString::from("/var/antctl/allowed_peers"),
+                        OsString::from("--rewards-address"),
+                        OsString::from("0x03B770D9cD32077cC0bF330c13C114a87643B124"),
+                        OsString::from("evm-arbitrum-one"),
+                    ],
+                    autostart: false,
+                    contents: None,
+                    environment: None,
+                    label: "antnode1".parse()?,
+                    program: current_node_bin.to_path_buf(),
+                    username: Some("ant".to_string()),
+                    working_directory: None,
+                }),
+                eq(false),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // after service restart
+        mock_service_control
+            .expect_start()
+            .with(eq("antnode1"), eq(false))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_service_control
+            .expect_wait()
+            .with(eq(3000))
+            .times(1)
+            .returning(|_| ());
+        mock_service_control
+            .expect_get_process_pid()
+            .with(eq(current_node_bin.to_path_buf().clone()))
+            .times(1)
+            .returning(|_| Ok(100));
+
+        mock_rpc_client.expect_node_info().times(1).returning(|| {
+            Ok(NodeInfo {
+                pid: 2000,
+                peer_id: PeerId::from_str("12D3KooWS2tpXGGTmg2AHFiDh57yPQnat49YHnyqoggzXZWpqkCR")?,
+                data_path: PathBuf::from("/var/antctl/services/antnode1"),
+                log_path: PathBuf::from("/var/log/antnode/antnode1"),
+                version: target_version.to_string(),
+                uptime: std::time::Duration::from_secs(1), // the service was just started
+                wallet_balance: 0,
+            })
+        });
+        mock_rpc_client
+            .expect_network_info()
+            .times(1)
+            .returning(|| {
+                Ok(NetworkInfo {
+                    connected_peers: Vec::new(),
+                    listeners: Vec::new(),
+                })
+            });
+
+        let mut service_data = NodeServiceData {
+            allowed_peers_file: Some(PathBuf::from("/var/antctl/allowed_peers")),
+            auto_restart: false,
+            connected_peers: None,
+            data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
+            evm_network: EvmNetwork::ArbitrumOne,
+            home_network: false,
+            listen_addr: None,
+            log_dir_path: PathBuf::from("/var/log/antnode/antnode1"),
+            log_format: None,
+            max_archived_log_files: None,
+            max_log_files: None,
+            metrics_port: None,
+            network_id: None,
+            network_key_file: Some(PathBuf::from("/var/antctl/swarm.key")),
+            node_ip: None,
+            node_port: None,
+            number: 1,
+            peer_id: Some(PeerId::from_str(
+                "12D3KooWS2tpXGGTmg2AHFiDh57yPQnat49YHnyqoggzXZWpqkCR",
+            )?),
+            peers_args: Default::default(),
+            pid: Some(1000),
+            rewards_address: RewardsAddress::from_str(
+                "0x03B770D9cD32077cC0bF330c13C114a87643B124",
+            )?,
+            reward_balance: Some(AttoTokens::zero()),
+            rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8081),
+            antnode_path: current_node_bin.to_path_buf(),
+            service_name: "antnode1".to_string(),
+            status: ServiceStatus::Running,
+            upnp: false,
+            user: Some("ant".to_string()),
+            user_mode: false,
+            version: current_version.to_string(),
+        };
+        let service = NodeService::new(&mut service_data, Box::new(mock_rpc_client));
+
+        let mut service_manager = ServiceManager::new(
+            service,
+            Box::new(mock_service_control),
+            VerbosityLevel::Normal,
+        );
+
+        service_manager
+            .upgrade(UpgradeOptions {
+                auto_restart: false,
+                env_variables: None,
+                force: false,
+                start_service: true,
+                target_bin_path: target_node_bin.to_path_buf(),
+                target_version: Version::parse(target_version).unwrap(),
+            })
+            .await?;
+
+        assert_eq!(
+            service_manager.service.service_data.network_key_file,
+            Some(PathBuf::from("/var/antctl/swarm.key"))
+        );
+        assert_eq!(
+            service_manager.service.service_data.allowed_peers_file,
+            Some(PathBuf::from("/var/antctl/allowed_peers"))
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn upgrade_should_retain_the_local_flag() -> Result<()> {
         let current_version = "0.1.0";
@@ -3218,6 +3432,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -3230,6 +3445,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -3387,6 +3603,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -3399,6 +3616,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -3566,6 +3784,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -3578,6 +3797,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -3740,6 +3960,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -3752,6 +3973,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -3909,6 +4131,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -3921,6 +4144,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -4088,6 +4312,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4100,6 +4325,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -4249,6 +4475,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4261,6 +4488,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -4413,6 +4641,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4425,6 +4654,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -4574,6 +4804,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4586,6 +4817,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             number: 1,
             node_ip: Some(Ipv4Addr::new(192, 168, 1, 1)),
             node_port: None,
@@ -4738,6 +4970,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4750,6 +4983,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             number: 1,
             node_ip: None,
             node_port: Some(12000),
@@ -4899,6 +5133,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -4910,6 +5145,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -5063,6 +5299,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -5074,6 +5311,7 @@ network_id: None,
             max_log_files: Some(20),
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -5224,6 +5462,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -5236,6 +5475,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: Some(12000),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -5388,6 +5628,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -5400,6 +5641,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: Some(12000),
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -5550,6 +5792,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: true,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -5562,6 +5805,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -5715,6 +5959,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: true,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -5735,6 +5980,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -5889,6 +6135,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: true,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -5909,6 +6156,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -6061,6 +6309,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -6073,6 +6322,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -6137,6 +6387,7 @@ network_id: None,
             .returning(|_, _| Ok(()));
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: data_dir.to_path_buf(),
@@ -6157,6 +6408,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -6205,6 +6457,7 @@ network_id: None,
             .returning(|_| Ok(1000));
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -6225,6 +6478,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -6288,6 +6542,7 @@ network_id: None,
             });
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: PathBuf::from("/var/antctl/services/antnode1"),
@@ -6308,6 +6563,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -6366,6 +6622,7 @@ network_id: None,
             .returning(|_, _| Ok(()));
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: data_dir.to_path_buf(),
@@ -6386,6 +6643,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
@@ -6442,6 +6700,7 @@ network_id: None,
             .returning(|_, _| Ok(()));
 
         let mut service_data = NodeServiceData {
+            allowed_peers_file: None,
             auto_restart: false,
             connected_peers: None,
             data_dir_path: data_dir.to_path_buf(),
@@ -6462,6 +6721,7 @@ network_id: None,
             max_log_files: None,
             metrics_port: None,
             network_id: None,
+            network_key_file: None,
             node_ip: None,
             node_port: None,
             number: 1,
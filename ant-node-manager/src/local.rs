@@ -46,6 +46,10 @@ pub trait Launcher {
         rpc_socket_addr: SocketAddr,
         rewards_address: RewardsAddress,
         evm_network: Option<EvmNetwork>,
+        tcp: bool,
+        websocket: bool,
+        network_key_file: Option<PathBuf>,
+        allowed_peers_file: Option<PathBuf>,
     ) -> Result<()>;
     fn wait(&self, delay: u64);
 }
@@ -69,6 +73,10 @@ impl Launcher for LocalSafeLauncher {
         rpc_socket_addr: SocketAddr,
         rewards_address: RewardsAddress,
         evm_network: Option<EvmNetwork>,
+        tcp: bool,
+        websocket: bool,
+        network_key_file: Option<PathBuf>,
+        allowed_peers_file: Option<PathBuf>,
     ) -> Result<()> {
         let mut args = Vec::new();
 
@@ -91,6 +99,27 @@ impl Launcher for LocalSafeLauncher {
             args.push(node_port.to_string());
         }
 
+        // The TCP and WebSocket ports are selected at random, alongside the QUIC port
+        if tcp {
+            args.push("--tcp-port".to_string());
+            args.push("0".to_string());
+        }
+
+        if websocket {
+            args.push("--ws-port".to_string());
+            args.push("0".to_string());
+        }
+
+        if let Some(path) = network_key_file {
+            args.push("--network-key-file".to_string());
+            args.push(path.to_string_lossy().to_string());
+        }
+
+        if let Some(path) = allowed_peers_file {
+            args.push("--allowed-peers-file".to_string());
+            args.push(path.to_string_lossy().to_string());
+        }
+
         args.push("--local".to_string());
         args.push("--rpc".to_string());
         args.push(rpc_socket_addr.to_string());
@@ -217,6 +246,10 @@ pub struct LocalNetworkOptions {
     pub log_format: Option<LogFormat>,
     pub rewards_address: RewardsAddress,
     pub evm_network: Option<EvmNetwork>,
+    pub tcp: bool,
+    pub websocket: bool,
+    pub network_key_file: Option<PathBuf>,
+    pub allowed_peers_file: Option<PathBuf>,
 }
 
 pub async fn run_network(
@@ -292,6 +325,10 @@ pub async fn run_network(
                 rewards_address: options.rewards_address,
                 evm_network: options.evm_network.clone(),
                 version: get_bin_version(&launcher.get_antnode_path())?,
+                tcp: options.tcp,
+                websocket: options.websocket,
+                network_key_file: options.network_key_file.clone(),
+                allowed_peers_file: options.allowed_peers_file.clone(),
             },
             &launcher,
             &rpc_client,
@@ -338,6 +375,10 @@ pub async fn run_network(
                 rewards_address: options.rewards_address,
                 evm_network: options.evm_network.clone(),
                 version: get_bin_version(&launcher.get_antnode_path())?,
+                tcp: options.tcp,
+                websocket: options.websocket,
+                network_key_file: options.network_key_file.clone(),
+                allowed_peers_file: options.allowed_peers_file.clone(),
             },
             &launcher,
             &rpc_client,
@@ -377,6 +418,10 @@ pub struct RunNodeOptions {
     pub rewards_address: RewardsAddress,
     pub evm_network: Option<EvmNetwork>,
     pub version: String,
+    pub tcp: bool,
+    pub websocket: bool,
+    pub network_key_file: Option<PathBuf>,
+    pub allowed_peers_file: Option<PathBuf>,
 }
 
 pub async fn run_node(
@@ -394,6 +439,10 @@ pub async fn run_node(
         run_options.rpc_socket_addr,
         run_options.rewards_address,
         run_options.evm_network.clone(),
+        run_options.tcp,
+        run_options.websocket,
+        run_options.network_key_file.clone(),
+        run_options.allowed_peers_file.clone(),
     )?;
     launcher.wait(run_options.interval);
 
@@ -408,6 +457,7 @@ pub async fn run_node(
         .collect();
 
     Ok(NodeServiceData {
+        allowed_peers_file: run_options.allowed_peers_file,
         antnode_path: launcher.get_antnode_path(),
         auto_restart: false,
         connected_peers,
@@ -421,6 +471,7 @@ pub async fn run_node(
         max_log_files: None,
         metrics_port: run_options.metrics_port,
         network_id: None,
+        network_key_file: run_options.network_key_file,
         node_ip: None,
         node_port: run_options.node_port,
         number: run_options.number,
@@ -544,9 +595,13 @@ mod tests {
                 eq(rpc_socket_addr),
                 eq(rewards_address),
                 eq(None),
+                eq(false),
+                eq(false),
+                eq(None),
+                eq(None),
             )
             .times(1)
-            .returning(|_, _, _, _, _, _, _| Ok(()));
+            .returning(|_, _, _, _, _, _, _, _, _, _, _| Ok(()));
         mock_launcher
             .expect_wait()
             .with(eq(100))
@@ -593,6 +648,10 @@ mod tests {
                 rewards_address,
                 evm_network: None,
                 version: "0.100.12".to_string(),
+                tcp: false,
+                websocket: false,
+                network_key_file: None,
+                allowed_peers_file: None,
             },
             &mock_launcher,
             &mock_rpc_client,
@@ -62,6 +62,7 @@ pub async fn restart_node_service(
                 )
             })?;
         let install_ctx = InstallNodeServiceCtxBuilder {
+            allowed_peers_file: current_node_clone.allowed_peers_file.clone(),
             antnode_path: current_node_clone.antnode_path.clone(),
             autostart: current_node_clone.auto_restart,
             data_dir_path: current_node_clone.data_dir_path.clone(),
@@ -75,6 +76,7 @@ pub async fn restart_node_service(
             metrics_port: None,
             name: current_node_clone.service_name.clone(),
             network_id: current_node_clone.network_id,
+            network_key_file: current_node_clone.network_key_file.clone(),
             node_ip: current_node_clone.node_ip,
             node_port: current_node_clone.get_antnode_port(),
             peers_args: current_node_clone.peers_args.clone(),
@@ -178,6 +180,7 @@ pub async fn restart_node_service(
         };
 
         let install_ctx = InstallNodeServiceCtxBuilder {
+            allowed_peers_file: current_node_clone.allowed_peers_file.clone(),
             autostart: current_node_clone.auto_restart,
             data_dir_path: data_dir_path.clone(),
             env_variables: node_registry.environment_variables.clone(),
@@ -190,6 +193,7 @@ pub async fn restart_node_service(
             max_log_files: current_node_clone.max_log_files,
             metrics_port: None,
             network_id: current_node_clone.network_id,
+            network_key_file: current_node_clone.network_key_file.clone(),
             node_ip: current_node_clone.node_ip,
             node_port: None,
             peers_args: current_node_clone.peers_args.clone(),
@@ -205,6 +209,7 @@ pub async fn restart_node_service(
         })?;
 
         let mut node = NodeServiceData {
+            allowed_peers_file: current_node_clone.allowed_peers_file.clone(),
             antnode_path,
             auto_restart: current_node_clone.auto_restart,
             connected_peers: None,
@@ -218,6 +223,7 @@ pub async fn restart_node_service(
             max_log_files: current_node_clone.max_log_files,
             metrics_port: None,
             network_id: current_node_clone.network_id,
+            network_key_file: current_node_clone.network_key_file.clone(),
             node_ip: current_node_clone.node_ip,
             node_port: None,
             number: new_node_number as u16,
@@ -18,8 +18,12 @@ use ant_evm::{get_evm_network, EvmNetwork, RewardsAddress};
 use ant_logging::metrics::init_metrics;
 use ant_logging::{Level, LogFormat, LogOutputDest, ReloadHandle};
 use ant_node::utils::get_root_dir_and_keypair;
-use ant_node::{Marker, NodeBuilder, NodeEvent, NodeEventsReceiver};
+use ant_node::{
+    BandwidthLimits, Marker, NodeBuilder, NodeEvent, NodeEventsReceiver, PrivateNetwork,
+    TransportConfig,
+};
 use ant_protocol::{
+    network_params::{self, NetworkParams},
     node::get_antnode_root_dir,
     node_rpc::{NodeCtrl, StopResult},
     version,
@@ -131,6 +135,39 @@ struct Opt {
     #[clap(long, verbatim_doc_comment)]
     network_id: Option<u8>,
 
+    /// Specify the close group size of the network, i.e. the number of nodes responsible for a record.
+    ///
+    /// All the nodes and clients of a network must use the same value. By default, it is set to 5,
+    /// the size used by the mainnet. Other values require a network ID other than the mainnet's.
+    #[clap(long, verbatim_doc_comment)]
+    close_group_size: Option<usize>,
+
+    /// Specify the replication factor of the network, i.e. the number of nodes a record is replicated to.
+    ///
+    /// By default, it is set to the close group size + 2.
+    #[clap(long, verbatim_doc_comment)]
+    replication_factor: Option<usize>,
+
+    /// Specify the number of quotes a client must pay for to store a record on the network.
+    ///
+    /// By default, it is set to the close group size.
+    #[clap(long, verbatim_doc_comment)]
+    min_quotes_to_pay: Option<usize>,
+
+    /// Specify the file holding the pre-shared key of a private network, in the libp2p `swarm.key` format.
+    ///
+    /// Only the nodes and clients holding the same key are able to connect. QUIC is disabled, and the
+    /// node listens on TCP instead, on the `--tcp-port` if provided, else on the `--port`.
+    #[clap(long, verbatim_doc_comment)]
+    network_key_file: Option<PathBuf>,
+
+    /// Specify the file listing the peer IDs of the nodes and clients allowed in the network, one per
+    /// line.
+    ///
+    /// The connections with any other peer are denied.
+    #[clap(long, verbatim_doc_comment)]
+    allowed_peers_file: Option<PathBuf>,
+
     /// Specify the rewards address.
     /// The rewards address is the address that will receive the rewards for the node.
     /// It should be a valid EVM address.
@@ -166,6 +203,40 @@ struct Opt {
     #[clap(long, default_value_t = IpAddr::V4(Ipv4Addr::UNSPECIFIED))]
     ip: IpAddr,
 
+    /// Enable the TCP transport alongside QUIC and specify the port to listen on.
+    ///
+    /// The special value `0` will cause the OS to assign a random port.
+    #[clap(long)]
+    tcp_port: Option<u16>,
+
+    /// Enable the WebSocket transport alongside QUIC and specify the port to listen on.
+    ///
+    /// The special value `0` will cause the OS to assign a random port.
+    #[clap(long)]
+    ws_port: Option<u16>,
+
+    /// Limit the upload bandwidth across all the peers, in bytes per second.
+    ///
+    /// Replication is held back while the limit is being hit, to leave the bandwidth to the
+    /// client requests.
+    #[clap(long)]
+    max_upload_rate: Option<u64>,
+
+    /// Limit the download bandwidth across all the peers, in bytes per second.
+    ///
+    /// Replication is held back while the limit is being hit, to leave the bandwidth to the
+    /// client requests.
+    #[clap(long)]
+    max_download_rate: Option<u64>,
+
+    /// Limit the upload bandwidth to each peer, in bytes per second.
+    #[clap(long)]
+    max_peer_upload_rate: Option<u64>,
+
+    /// Limit the download bandwidth from each peer, in bytes per second.
+    #[clap(long)]
+    max_peer_download_rate: Option<u64>,
+
     #[command(flatten)]
     peers: PeersArgs,
 
@@ -219,6 +290,11 @@ fn main() -> Result<()> {
     if let Some(network_id) = opt.network_id {
         version::set_network_id(network_id);
     }
+    network_params::set_network_params(NetworkParams::with_overrides(
+        opt.close_group_size,
+        opt.replication_factor,
+        opt.min_quotes_to_pay,
+    ))?;
 
     let identify_protocol_str = version::IDENTIFY_PROTOCOL_STR
         .read()
@@ -270,6 +346,10 @@ fn main() -> Result<()> {
 
     let node_socket_addr = SocketAddr::new(opt.ip, opt.port);
     let (root_dir, keypair) = get_root_dir_and_keypair(&opt.root_dir)?;
+    let private_network = PrivateNetwork::from_files(
+        opt.network_key_file.as_deref(),
+        opt.allowed_peers_file.as_deref(),
+    )?;
 
     let (log_output_dest, log_reload_handle, _log_appender_guard) =
         init_logging(&opt, keypair.public().to_peer_id())?;
@@ -322,6 +402,17 @@ fn main() -> Result<()> {
         node_builder.initial_peers(initial_peers);
         node_builder.bootstrap_cache(bootstrap_cache);
         node_builder.is_behind_home_network(opt.home_network);
+        node_builder.transports(TransportConfig {
+            tcp: opt.tcp_port,
+            websocket: opt.ws_port,
+        });
+        node_builder.private_network(private_network);
+        node_builder.bandwidth_limits(BandwidthLimits {
+            upload: opt.max_upload_rate,
+            download: opt.max_download_rate,
+            peer_upload: opt.max_peer_upload_rate,
+            peer_download: opt.max_peer_download_rate,
+        });
         #[cfg(feature = "open-metrics")]
         let mut node_builder = node_builder;
         // if enable flag is provided or only if the port is specified then enable the server by setting Some()
@@ -10,8 +10,9 @@ use ant_logging::ReloadHandle;
 use ant_node::RunningNode;
 use ant_protocol::antnode_proto::{
     ant_node_server::{AntNode, AntNodeServer},
-    k_buckets_response, KBucketsRequest, KBucketsResponse, NetworkInfoRequest, NetworkInfoResponse,
-    NodeEvent, NodeEventsRequest, NodeInfoRequest, NodeInfoResponse, RecordAddressesRequest,
+    k_buckets_response, peers_bandwidth_response, KBucketsRequest, KBucketsResponse,
+    NetworkInfoRequest, NetworkInfoResponse, NodeEvent, NodeEventsRequest, NodeInfoRequest,
+    NodeInfoResponse, PeersBandwidthRequest, PeersBandwidthResponse, RecordAddressesRequest,
     RecordAddressesResponse, RestartRequest, RestartResponse, StopRequest, StopResponse,
     UpdateLogLevelRequest, UpdateLogLevelResponse, UpdateRequest, UpdateResponse,
 };
@@ -185,6 +186,40 @@ impl AntNode for SafeNodeRpcService {
         Ok(Response::new(KBucketsResponse { kbuckets }))
     }
 
+    async fn peers_bandwidth(
+        &self,
+        request: Request<PeersBandwidthRequest>,
+    ) -> Result<Response<PeersBandwidthResponse>, Status> {
+        debug!(
+            "RPC request received at {}: {:?}",
+            self.addr,
+            request.get_ref()
+        );
+
+        let stats = self
+            .running_node
+            .get_bandwidth_stats()
+            .await
+            .expect("failed to get bandwidth stats");
+        let peers = stats
+            .peers
+            .into_iter()
+            .map(
+                |(peer_id, traffic)| peers_bandwidth_response::PeerBandwidth {
+                    peer_id: peer_id.to_bytes(),
+                    inbound_bytes: traffic.inbound_bytes,
+                    outbound_bytes: traffic.outbound_bytes,
+                },
+            )
+            .collect();
+
+        Ok(Response::new(PeersBandwidthResponse {
+            total_inbound_bytes: stats.total.inbound_bytes,
+            total_outbound_bytes: stats.total.outbound_bytes,
+            peers,
+        }))
+    }
+
     async fn stop(&self, request: Request<StopRequest>) -> Result<Response<StopResponse>, Status> {
         debug!(
             "RPC request received at {}: {:?}",
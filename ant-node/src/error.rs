@@ -7,7 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use ant_evm::AttoTokens;
-use ant_protocol::{NetworkAddress, PrettyPrintRecordKey};
+use ant_protocol::{messages::PutRecordRejection, NetworkAddress, PrettyPrintRecordKey};
 use thiserror::Error;
 
 pub(super) type Result<T, E = Error> = std::result::Result<T, E>;
@@ -48,10 +48,20 @@ pub enum Error {
     #[error("Scratchpad too big: {0}, max size is {SCRATCHPAD_MAX_SIZE}")]
     ScratchpadTooBig(usize),
 
+    // ------------ GraphEntry Errors
+    #[error("The record holds no GraphEntry")]
+    EmptyGraphEntryRecord,
+
+    // ------------ Pointer Errors
+    #[error("A newer version of this Pointer already exists")]
+    IgnoringOutdatedPointerPut,
+
     #[error("Invalid signature")]
     InvalidSignature,
 
     // ---------- Payment Errors
+    #[error("The payment for the record is invalid: {0}")]
+    InvalidPayment(String),
     #[error("The content of the payment quote is invalid")]
     InvalidQuoteContent,
     #[error("The payment quote's signature is invalid")]
@@ -87,3 +97,37 @@ pub enum Error {
     #[error("EVM Network error: {0}")]
     EvmNetwork(String),
 }
+
+impl From<Error> for PutRecordRejection {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::InvalidPutWithoutPayment(_)
+            | Error::UnexpectedRecordWithPayment(_)
+            | Error::InvalidPayment(_)
+            | Error::InvalidQuoteContent
+            | Error::InvalidQuoteSignature
+            | Error::QuoteExpired(_)
+            | Error::NoPaymentToOurNode(_)
+            | Error::NoNetworkRoyaltiesPayment(_)
+            | Error::PaymentInsufficientAmount { .. }
+            | Error::ReusedPayment => PutRecordRejection::InvalidPayment(err.to_string()),
+            Error::IgnoringOutdatedScratchpadPut | Error::IgnoringOutdatedPointerPut => {
+                PutRecordRejection::OutdatedCounter
+            }
+            Error::ScratchpadTooBig(size) => PutRecordRejection::TooBig {
+                size,
+                max: SCRATCHPAD_MAX_SIZE,
+            },
+            Error::InvalidScratchpadSignature | Error::InvalidSignature => {
+                PutRecordRejection::InvalidSignature
+            }
+            Error::RecordKeyMismatch => PutRecordRejection::KeyMismatch,
+            Error::EmptyGraphEntryRecord
+            | Error::Protocol(ant_protocol::Error::RecordHeaderParsingFailed)
+            | Error::Protocol(ant_protocol::Error::RecordParsingFailed) => {
+                PutRecordRejection::InvalidRecord
+            }
+            other => PutRecordRejection::Other(other.to_string()),
+        }
+    }
+}
@@ -42,6 +42,7 @@ pub use self::{
     log_markers::Marker,
     node::{NodeBuilder, PERIODIC_REPLICATION_INTERVAL_MAX_S},
 };
+pub use ant_networking::{BandwidthLimits, BandwidthStats, PrivateNetwork, TransportConfig};
 
 use crate::error::{Error, Result};
 
@@ -145,6 +146,12 @@ impl RunningNode {
         Ok(kbuckets)
     }
 
+    /// Returns the traffic of the node since it started, and of each connected peer.
+    pub async fn get_bandwidth_stats(&self) -> Result<BandwidthStats> {
+        let stats = self.network.get_bandwidth_stats().await?;
+        Ok(stats)
+    }
+
     /// Returns the node's reward address
     pub fn reward_address(&self) -> &RewardsAddress {
         &self.rewards_address
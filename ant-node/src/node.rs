@@ -18,17 +18,26 @@ use ant_evm::RewardsAddress;
 #[cfg(feature = "open-metrics")]
 use ant_networking::MetricsRegistries;
 use ant_networking::{
-    time::sleep, Instant, Network, NetworkBuilder, NetworkEvent, NodeIssue, SwarmDriver,
+    time::sleep, BandwidthLimits, Instant, MsgResponder, Network, NetworkBuilder, NetworkEvent,
+    NodeIssue, PrivateNetwork, SwarmDriver, TransportConfig,
 };
 use ant_protocol::{
+    close_group_size,
     error::Error as ProtocolError,
-    messages::{ChunkProof, CmdResponse, Nonce, Query, QueryResponse, Request, Response},
+    messages::{
+        closest_peers_bytes, put_record_ack_bytes, ChunkProof, CmdResponse, Nonce,
+        PutRecordRejection, Query, QueryResponse, Request, Response,
+    },
     storage::ValidationType,
-    NetworkAddress, PrettyPrintRecordKey, CLOSE_GROUP_SIZE,
+    NetworkAddress, PrettyPrintRecordKey,
 };
 use bytes::Bytes;
 use itertools::Itertools;
-use libp2p::{identity::Keypair, kad::U256, Multiaddr, PeerId};
+use libp2p::{
+    identity::Keypair,
+    kad::{Record, U256},
+    Multiaddr, PeerId,
+};
 use num_traits::cast::ToPrimitive;
 use rand::{
     rngs::{OsRng, StdRng},
@@ -44,7 +53,7 @@ use std::{
     },
     time::Duration,
 };
-use tokio::sync::watch;
+use tokio::sync::{watch, OwnedSemaphorePermit, Semaphore};
 use tokio::{
     sync::mpsc::Receiver,
     task::{spawn, JoinSet},
@@ -58,6 +67,11 @@ pub const PERIODIC_REPLICATION_INTERVAL_MAX_S: u64 = 180;
 /// This is the max time it should take. Minimum interval at any node will be half this
 const STORE_CHALLENGE_INTERVAL_MAX_S: u64 = 7200;
 
+/// Max number of PUT requests from peers being validated at the same time.
+/// Validation can involve verifying the payment against the EVM network, so further requests are
+/// rejected as busy rather than queued, the peers retry them later.
+const MAX_CONCURRENT_PUT_RECORD_VALIDATIONS: usize = 64;
+
 /// Interval to update the nodes uptime metric
 const UPTIME_METRICS_UPDATE_INTERVAL: Duration = Duration::from_secs(10);
 
@@ -80,6 +94,7 @@ const NETWORK_DENSITY_SAMPLING_INTERVAL_MAX_S: u64 = 200;
 
 /// Helper to build and run a Node
 pub struct NodeBuilder {
+    bandwidth_limits: BandwidthLimits,
     bootstrap_cache: Option<BootstrapCacheStore>,
     initial_peers: Vec<Multiaddr>,
     identity_keypair: Keypair,
@@ -93,6 +108,9 @@ pub struct NodeBuilder {
     metrics_server_port: Option<u16>,
     /// Enable hole punching for nodes connecting from home networks.
     is_behind_home_network: bool,
+    /// The transports enabled alongside QUIC
+    transports: TransportConfig,
+    private_network: PrivateNetwork,
     upnp: bool,
 }
 
@@ -109,6 +127,7 @@ impl NodeBuilder {
         upnp: bool,
     ) -> Self {
         Self {
+            bandwidth_limits: BandwidthLimits::default(),
             bootstrap_cache: None,
             initial_peers: vec![],
             identity_keypair,
@@ -120,6 +139,8 @@ impl NodeBuilder {
             #[cfg(feature = "open-metrics")]
             metrics_server_port: None,
             is_behind_home_network: false,
+            transports: TransportConfig::default(),
+            private_network: PrivateNetwork::default(),
             upnp,
         }
     }
@@ -130,6 +151,11 @@ impl NodeBuilder {
         self.metrics_server_port = port;
     }
 
+    /// Limit the upload and download bandwidth of the node, globally and per peer.
+    pub fn bandwidth_limits(&mut self, limits: BandwidthLimits) {
+        self.bandwidth_limits = limits;
+    }
+
     /// Set the initialized bootstrap cache.
     pub fn bootstrap_cache(&mut self, cache: BootstrapCacheStore) {
         self.bootstrap_cache = Some(cache);
@@ -145,6 +171,16 @@ impl NodeBuilder {
         self.is_behind_home_network = is_behind_home_network;
     }
 
+    /// Enable the TCP and/or WebSocket transports alongside QUIC, listening on the given ports
+    pub fn transports(&mut self, transports: TransportConfig) {
+        self.transports = transports;
+    }
+
+    /// Restrict the network with a pre-shared key and/or the nodes allowed in it
+    pub fn private_network(&mut self, private_network: PrivateNetwork) {
+        self.private_network = private_network;
+    }
+
     /// Asynchronously runs a new node instance, setting up the swarm driver,
     /// creating a data storage, and handling network events. Returns the
     /// created `RunningNode` which contains a `NodeEventsChannel` for listening
@@ -182,6 +218,9 @@ impl NodeBuilder {
         }
 
         network_builder.upnp(self.upnp);
+        network_builder.transports(self.transports);
+        network_builder.private_network(self.private_network);
+        network_builder.bandwidth_limits(self.bandwidth_limits);
 
         let (network, network_event_receiver, swarm_driver) =
             network_builder.build_node(self.root_dir.clone())?;
@@ -196,6 +235,7 @@ impl NodeBuilder {
             #[cfg(feature = "open-metrics")]
             metrics_recorder,
             evm_network: self.evm_network,
+            put_record_validations: Arc::new(Semaphore::new(MAX_CONCURRENT_PUT_RECORD_VALIDATIONS)),
         };
 
         let node = Node {
@@ -239,6 +279,8 @@ struct NodeInner {
     metrics_recorder: Option<NodeMetricsRecorder>,
     reward_address: RewardsAddress,
     evm_network: EvmNetwork,
+    // Bounds the PUT requests from peers being validated at the same time
+    put_record_validations: Arc<Semaphore>,
 }
 
 impl Node {
@@ -440,7 +482,7 @@ impl Node {
                 event_header = "PeerAdded";
                 // increment peers_connected and send ConnectedToNetwork event if have connected to K_VALUE peers
                 let _ = peers_connected.fetch_add(1, Ordering::SeqCst);
-                if peers_connected.load(Ordering::SeqCst) == CLOSE_GROUP_SIZE {
+                if peers_connected.load(Ordering::SeqCst) == close_group_size() {
                     self.events_channel()
                         .broadcast(NodeEvent::ConnectedToNetwork);
                 }
@@ -521,6 +563,23 @@ impl Node {
                     }
                 });
             }
+            NetworkEvent::PutRecordRequestReceived { record, channel } => {
+                event_header = "PutRecordRequestReceived";
+                match Arc::clone(&self.inner.put_record_validations).try_acquire_owned() {
+                    Ok(permit) => self.handle_put_record_request(record, channel, permit),
+                    Err(_) => {
+                        warn!(
+                            "Too many records being validated, rejecting the PUT of {:?} as busy",
+                            PrettyPrintRecordKey::from(&record.key)
+                        );
+                        let resp = Response::Cmd(CmdResponse::PutRecord {
+                            peer_address: NetworkAddress::from_peer(self.network().peer_id()),
+                            result: Err(PutRecordRejection::Busy),
+                        });
+                        self.network().send_response(resp, channel);
+                    }
+                }
+            }
 
             NetworkEvent::TerminateNode { reason } => {
                 event_header = "TerminateNode";
@@ -577,6 +636,43 @@ impl Node {
         );
     }
 
+    // Validate and store a record sent by a peer, holding the validation permit until it is done
+    // The peer awaits the outcome of the validation, so only respond once it is done
+    fn handle_put_record_request(
+        &self,
+        record: Record,
+        channel: MsgResponder,
+        permit: OwnedSemaphorePermit,
+    ) {
+        let self_clone = self.clone();
+        let _handle = spawn(async move {
+            let network = self_clone.network().clone();
+            let key = PrettyPrintRecordKey::from(&record.key).into_owned();
+            let ack_bytes =
+                put_record_ack_bytes(&NetworkAddress::from_record_key(&record.key), &record.value);
+            let result = match self_clone.validate_and_store_record(record).await {
+                Ok(()) => {
+                    debug!("Record {key} sent by a peer has been stored");
+                    network.sign(&ack_bytes).map_err(|err| {
+                        PutRecordRejection::Other(format!(
+                            "Failed to sign the acknowledgement: {err}"
+                        ))
+                    })
+                }
+                Err(err) => {
+                    self_clone.record_metrics(Marker::RecordRejected(&key, &err));
+                    Err(PutRecordRejection::from(err))
+                }
+            };
+            drop(permit);
+            let resp = Response::Cmd(CmdResponse::PutRecord {
+                peer_address: NetworkAddress::from_peer(network.peer_id()),
+                result,
+            });
+            network.send_response(resp, channel);
+        });
+    }
+
     // Handle the response that was not awaited at the call site
     fn handle_response(&self, response: Response) -> Result<()> {
         match response {
@@ -735,12 +831,12 @@ impl Node {
         };
 
         let signature = if sign_result {
-            let mut bytes = rmp_serde::to_vec(&target).unwrap_or_default();
-            bytes.extend_from_slice(&rmp_serde::to_vec(&peers).unwrap_or_default());
-            if let Ok(sig) = network.sign(&bytes) {
-                Some(sig)
-            } else {
-                None
+            match network.sign(&closest_peers_bytes(&target, &peers)) {
+                Ok(sig) => Some(sig),
+                Err(err) => {
+                    error!("Failed to sign the closest peers to {target:?}: {err:?}");
+                    None
+                }
             }
         } else {
             None
@@ -835,7 +931,7 @@ impl Node {
                 all_chunk_addrs.sort_by_key(|addr| key.distance(addr));
 
                 // TODO: this shall be deduced from resource usage dynamically
-                let workload_factor = std::cmp::min(difficulty, CLOSE_GROUP_SIZE);
+                let workload_factor = std::cmp::min(difficulty, close_group_size());
 
                 for addr in all_chunk_addrs.iter().take(workload_factor) {
                     if let Ok(Some(record)) = network.get_local_record(&addr.to_record_key()).await
@@ -865,17 +961,17 @@ impl Node {
             if let Ok(closest_peers) = network.get_closest_k_value_local_peers().await {
                 closest_peers
                     .into_iter()
-                    .take(CLOSE_GROUP_SIZE)
+                    .take(close_group_size())
                     .collect_vec()
             } else {
                 error!("Cannot get local neighbours");
                 return;
             };
-        if closest_peers.len() < CLOSE_GROUP_SIZE {
+        if closest_peers.len() < close_group_size() {
             debug!(
                 "Not enough neighbours ({}/{}) to carry out storage challenge.",
                 closest_peers.len(),
-                CLOSE_GROUP_SIZE
+                close_group_size()
             );
             return;
         }
@@ -909,7 +1005,7 @@ impl Node {
         let index: usize = OsRng.gen_range(0..num_of_targets / 2);
         let target = verify_candidates[index].clone();
         // TODO: workload shall be dynamically deduced from resource usage
-        let difficulty = CLOSE_GROUP_SIZE;
+        let difficulty = close_group_size();
         verify_candidates.sort_by_key(|addr| target.distance(addr));
         let expected_targets = verify_candidates.into_iter().take(difficulty);
         let nonce: Nonce = thread_rng().gen::<u64>();
@@ -978,10 +1074,10 @@ impl Node {
             // Result is sorted and only return CLOSE_GROUP_SIZE entries
             let peers = network.node_get_closest_peers(&target).await;
             if let Ok(peers) = peers {
-                if peers.len() >= CLOSE_GROUP_SIZE {
+                if peers.len() >= close_group_size() {
                     // Calculate the distance to the farthest.
                     let distance =
-                        target.distance(&NetworkAddress::from_peer(peers[CLOSE_GROUP_SIZE - 1]));
+                        target.distance(&NetworkAddress::from_peer(peers[close_group_size() - 1]));
                     network.add_network_density_sample(distance);
                 }
             }
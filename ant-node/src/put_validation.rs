@@ -541,6 +541,11 @@ impl Node {
         let pretty_key = PrettyPrintRecordKey::from(record_key);
         debug!("Validating GraphEntries before storage at {pretty_key:?}");
 
+        if entries.is_empty() {
+            warn!("Found no GraphEntries in the record at {pretty_key:?}");
+            return Err(Error::EmptyGraphEntryRecord);
+        }
+
         // only keep GraphEntries that match the record key
         let entries_for_key: Vec<GraphEntry> = entries
             .into_iter()
@@ -560,10 +565,8 @@ impl Node {
 
         // if we have no GraphEntries to verify, return early
         if entries_for_key.is_empty() {
-            warn!("Found no valid GraphEntries to verify upon validation for {pretty_key:?}");
-            return Err(Error::InvalidRequest(format!(
-                "No GraphEntries to verify when validating {pretty_key:?}"
-            )));
+            warn!("Found no GraphEntries for the record key upon validation for {pretty_key:?}");
+            return Err(Error::RecordKeyMismatch);
         }
 
         // verify the GraphEntries
@@ -572,11 +575,11 @@ impl Node {
             .filter(|t| t.verify_signature())
             .collect();
 
-        // skip if none are valid
+        // reject the record if none are valid
         let addr = match validated_entries.first() {
             None => {
                 warn!("Found no validated GraphEntries to store at {pretty_key:?}");
-                return Ok(());
+                return Err(Error::InvalidSignature);
             }
             Some(t) => t.address(),
         };
@@ -633,7 +636,7 @@ impl Node {
         let self_peer_id = self.network().peer_id();
         if !payment.verify_for(self_peer_id) {
             warn!("Payment is not valid for record {pretty_key}");
-            return Err(Error::InvalidRequest(format!(
+            return Err(Error::InvalidPayment(format!(
                 "Payment is not valid for record {pretty_key}"
             )));
         }
@@ -641,7 +644,7 @@ impl Node {
         // verify quote expiration
         if payment.has_expired() {
             warn!("Payment quote has expired for record {pretty_key}");
-            return Err(Error::InvalidRequest(format!(
+            return Err(Error::InvalidPayment(format!(
                 "Payment quote has expired for record {pretty_key}"
             )));
         }
@@ -649,7 +652,7 @@ impl Node {
         // verify data type matches
         if !payment.verify_data_type(data_type.get_index()) {
             warn!("Payment quote has wrong data type for record {pretty_key}");
-            return Err(Error::InvalidRequest(format!(
+            return Err(Error::InvalidPayment(format!(
                 "Payment quote has wrong data type for record {pretty_key}"
             )));
         }
@@ -660,7 +663,7 @@ impl Node {
         payees.retain(|peer_id| !closest_k_peers.contains(peer_id));
         if !payees.is_empty() {
             warn!("Payment quote has out-of-range payees for record {pretty_key}");
-            return Err(Error::InvalidRequest(format!(
+            return Err(Error::InvalidPayment(format!(
                 "Payment quote has out-of-range payees {payees:?}"
             )));
         }
@@ -816,13 +819,17 @@ impl Node {
 
         // Keep the pointer with the highest counter
         if let Some(local_pointer) = self.get_local_pointer(pointer.address()).await {
+            if pointer == local_pointer {
+                info!("Pointer at {key:?} is already stored");
+                return Ok(());
+            }
             if pointer.counter() <= local_pointer.counter() {
                 info!(
-                    "Ignoring Pointer PUT at {key:?} with counter less than or equal to the current counter ({} <= {})",
+                    "Rejecting Pointer PUT at {key:?} with counter less than or equal to the current counter ({} <= {})",
                     pointer.counter(),
                     local_pointer.counter()
                 );
-                return Ok(());
+                return Err(Error::IgnoringOutdatedPointerPut);
             }
         }
 
@@ -69,6 +69,10 @@ pub enum Error {
     // The record already exists at this node
     #[error("The record already exists, so do not charge for it: {0:?}")]
     RecordExists(PrettyPrintRecordKey<'static>),
+
+    // ---------- network errors
+    #[error("Invalid network parameters: {0}")]
+    InvalidNetworkParams(String),
 }
 
 impl From<Error> for store::Error {
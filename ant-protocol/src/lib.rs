@@ -13,6 +13,8 @@ extern crate tracing;
 pub mod error;
 /// Messages types
 pub mod messages;
+/// The redundancy parameters of the network
+pub mod network_params;
 /// Helpers for antnode
 pub mod node;
 /// RPC commands to node
@@ -30,6 +32,7 @@ pub mod antnode_proto {
 }
 pub use error::Error;
 pub use error::Error as NetworkError;
+pub use network_params::{close_group_size, min_quotes_to_pay, replication_factor};
 use storage::ScratchpadAddress;
 
 use self::storage::{ChunkAddress, GraphEntryAddress, PointerAddress};
@@ -53,6 +56,9 @@ use std::{
 /// an item in the network.
 /// The peer should be present among the CLOSE_GROUP_SIZE if we're fetching the close_group(peer)
 /// The size has been set to 5 for improved performance.
+///
+/// This is the size used by the mainnet, other networks can use another one, see
+/// [`close_group_size`].
 pub const CLOSE_GROUP_SIZE: usize = 5;
 
 /// Returns the UDP port from the provided MultiAddr.
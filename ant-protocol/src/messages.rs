@@ -8,23 +8,31 @@
 
 //! Data messages and their possible responses.
 mod chunk_proof;
+mod closest_peers;
 mod cmd;
 mod node_id;
+mod put_record;
 mod query;
 mod response;
 
 pub use self::{
     chunk_proof::{ChunkProof, Nonce},
+    closest_peers::{closest_peers_bytes, verify_closest_peers},
     cmd::Cmd,
     node_id::NodeId,
+    put_record::{put_record_ack_bytes, verify_put_record_ack, PutRecordRejection},
     query::Query,
     response::{CmdResponse, QueryResponse},
 };
 
 use super::NetworkAddress;
 
+use libp2p::{identity::PublicKey, PeerId};
 use serde::{Deserialize, Serialize};
 
+/// Code of the identity multihash, used by `PeerId`s that inline their public key
+const IDENTITY_MULTIHASH_CODE: u64 = 0x00;
+
 /// A request to peers in the network
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Request {
@@ -58,3 +66,15 @@ impl std::fmt::Display for Response {
         write!(f, "{self:?}")
     }
 }
+
+/// Extract the public key of the peer from its `PeerId`, to verify the signatures made by the peer.
+///
+/// This only works for the keys small enough to be inlined into the `PeerId`, such as the ed25519
+/// keys used by nodes.
+fn public_key_from_peer_id(peer: &PeerId) -> Option<PublicKey> {
+    let multihash: &libp2p::multihash::Multihash<64> = peer.as_ref();
+    if multihash.code() != IDENTITY_MULTIHASH_CODE {
+        return None;
+    }
+    PublicKey::try_decode_protobuf(multihash.digest()).ok()
+}
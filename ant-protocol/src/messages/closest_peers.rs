@@ -0,0 +1,93 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::public_key_from_peer_id;
+use crate::NetworkAddress;
+use libp2p::{Multiaddr, PeerId};
+
+/// The bytes a node signs when answering a [`Query::GetClosestPeers`] with `sign_result` set.
+/// The signature covers the target along with the peers, so the answer can't be replayed for
+/// another target.
+///
+/// [`Query::GetClosestPeers`]: crate::messages::Query::GetClosestPeers
+pub fn closest_peers_bytes(
+    target: &NetworkAddress,
+    peers: &[(NetworkAddress, Vec<Multiaddr>)],
+) -> Vec<u8> {
+    let mut bytes = b"GetClosestPeers".to_vec();
+    // serializing these types can't fail, and would fail the verification anyway
+    bytes.extend(rmp_serde::to_vec(&(target, peers)).unwrap_or_default());
+    bytes
+}
+
+/// Verify that the answer to a [`Query::GetClosestPeers`] has been signed by the peer.
+///
+/// [`Query::GetClosestPeers`]: crate::messages::Query::GetClosestPeers
+pub fn verify_closest_peers(
+    peer: &PeerId,
+    target: &NetworkAddress,
+    peers: &[(NetworkAddress, Vec<Multiaddr>)],
+    signature: &[u8],
+) -> bool {
+    match public_key_from_peer_id(peer) {
+        Some(public_key) => public_key.verify(&closest_peers_bytes(target, peers), signature),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libp2p::identity::Keypair;
+
+    #[test]
+    fn test_closest_peers_signature() {
+        let keypair = Keypair::generate_ed25519();
+        let peer = PeerId::from(keypair.public());
+        let target = NetworkAddress::from_peer(PeerId::random());
+        let mut peers = vec![
+            (
+                NetworkAddress::from_peer(PeerId::random()),
+                vec!["/ip4/10.0.0.1/udp/12000/quic-v1"
+                    .parse()
+                    .expect("Failed to parse multiaddr")],
+            ),
+            (
+                NetworkAddress::from_peer(PeerId::random()),
+                vec!["/ip4/10.0.0.2/udp/12000/quic-v1"
+                    .parse()
+                    .expect("Failed to parse multiaddr")],
+            ),
+        ];
+
+        let signature = keypair
+            .sign(&closest_peers_bytes(&target, &peers))
+            .expect("Failed to sign");
+        assert!(verify_closest_peers(&peer, &target, &peers, &signature));
+
+        // another target, another peer, or tampered peers, don't match the signature
+        let other_target = NetworkAddress::from_peer(PeerId::random());
+        assert!(!verify_closest_peers(
+            &peer,
+            &other_target,
+            &peers,
+            &signature
+        ));
+        let other_peer = PeerId::from(Keypair::generate_ed25519().public());
+        assert!(!verify_closest_peers(
+            &other_peer,
+            &target,
+            &peers,
+            &signature
+        ));
+        peers[1].1 = vec!["/ip4/10.0.0.3/udp/12000/quic-v1"
+            .parse()
+            .expect("Failed to parse multiaddr")];
+        assert!(!verify_closest_peers(&peer, &target, &peers, &signature));
+    }
+}
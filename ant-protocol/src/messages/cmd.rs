@@ -10,6 +10,7 @@
 use crate::storage::DataTypes;
 use crate::{storage::ValidationType, NetworkAddress};
 use ant_evm::ProofOfPayment;
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
 /// Ant protocol cmds
@@ -49,6 +50,14 @@ pub enum Cmd {
         bad_peer: NetworkAddress,
         bad_behaviour: String,
     },
+    /// Write operation to store a record at the peer.
+    /// The peer only responds once the record has been validated and stored, or rejected.
+    PutRecord {
+        /// Address of the record.
+        address: NetworkAddress,
+        /// The serialized record, i.e. the value of the kad `Record`.
+        record: Bytes,
+    },
 }
 
 impl std::fmt::Debug for Cmd {
@@ -80,6 +89,11 @@ impl std::fmt::Debug for Cmd {
                 .field("bad_peer", bad_peer)
                 .field("bad_behaviour", bad_behaviour)
                 .finish(),
+            Cmd::PutRecord { address, record } => f
+                .debug_struct("Cmd::PutRecord")
+                .field("address", address)
+                .field("record_len", &record.len())
+                .finish(),
         }
     }
 }
@@ -91,6 +105,7 @@ impl Cmd {
             Cmd::Replicate { holder, .. } => holder.clone(),
             Cmd::FreshReplicate { holder, .. } => holder.clone(),
             Cmd::PeerConsideredAsBad { bad_peer, .. } => bad_peer.clone(),
+            Cmd::PutRecord { address, .. } => address.clone(),
         }
     }
 }
@@ -123,6 +138,9 @@ impl std::fmt::Display for Cmd {
                     f,
                     "Cmd::PeerConsideredAsBad({detected_by:?} consider peer {bad_peer:?} as bad, due to {bad_behaviour:?})")
             }
+            Cmd::PutRecord { address, record } => {
+                write!(f, "Cmd::PutRecord({address:?} with {} bytes)", record.len())
+            }
         }
     }
 }
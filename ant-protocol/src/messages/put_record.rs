@@ -0,0 +1,120 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::public_key_from_peer_id;
+use crate::NetworkAddress;
+use libp2p::PeerId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use xor_name::XorName;
+
+/// The reason why a node refused to store a record sent with [`Cmd::PutRecord`]
+///
+/// [`Cmd::PutRecord`]: crate::messages::Cmd::PutRecord
+#[derive(Error, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum PutRecordRejection {
+    #[error("The payment for the record is missing or invalid: {0}")]
+    InvalidPayment(String),
+    #[error("A newer or equal version of the record is already stored")]
+    OutdatedCounter,
+    #[error("The record is too big: {size} bytes, max size is {max} bytes")]
+    TooBig { size: usize, max: usize },
+    #[error("The signature of the record is invalid")]
+    InvalidSignature,
+    #[error("The record key does not match with the key derived from its content")]
+    KeyMismatch,
+    #[error("The record could not be deserialized")]
+    InvalidRecord,
+    #[error("The node is busy validating other records")]
+    Busy,
+    #[error("The node failed to store the record: {0}")]
+    Other(String),
+}
+
+impl PutRecordRejection {
+    /// Whether sending the same record to the same node again could succeed.
+    /// Permanent rejections are caused by the record itself, so there is no point retrying them.
+    pub fn is_permanent(&self) -> bool {
+        !matches!(
+            self,
+            PutRecordRejection::Busy | PutRecordRejection::Other(_)
+        )
+    }
+}
+
+/// The bytes a node signs to acknowledge that it stored the record at the address.
+/// The signature covers the content, so an acknowledgement can't be replayed for another version.
+pub fn put_record_ack_bytes(address: &NetworkAddress, record: &[u8]) -> Vec<u8> {
+    let mut bytes = b"PutRecordAck".to_vec();
+    bytes.extend(address.as_bytes());
+    bytes.extend(XorName::from_content(record).0);
+    bytes
+}
+
+/// Verify that the acknowledgement of a [`Cmd::PutRecord`] has been signed by the peer.
+///
+/// The public key is taken from the `PeerId`, which only works for the keys small enough to be
+/// inlined into it, such as the ed25519 keys used by nodes.
+///
+/// [`Cmd::PutRecord`]: crate::messages::Cmd::PutRecord
+pub fn verify_put_record_ack(
+    peer: &PeerId,
+    address: &NetworkAddress,
+    record: &[u8],
+    signature: &[u8],
+) -> bool {
+    match public_key_from_peer_id(peer) {
+        Some(public_key) => public_key.verify(&put_record_ack_bytes(address, record), signature),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::ChunkAddress;
+    use libp2p::identity::Keypair;
+
+    #[test]
+    fn test_put_record_ack_signature() {
+        let keypair = Keypair::generate_ed25519();
+        let peer = PeerId::from(keypair.public());
+        let address = NetworkAddress::from_chunk_address(ChunkAddress::new(XorName::random(
+            &mut rand::thread_rng(),
+        )));
+        let record = b"record content";
+
+        let signature = keypair
+            .sign(&put_record_ack_bytes(&address, record))
+            .expect("Failed to sign");
+        assert!(verify_put_record_ack(&peer, &address, record, &signature));
+
+        // another version of the record, or another peer, can't use the acknowledgement
+        assert!(!verify_put_record_ack(
+            &peer,
+            &address,
+            b"other content",
+            &signature
+        ));
+        let other_peer = PeerId::from(Keypair::generate_ed25519().public());
+        assert!(!verify_put_record_ack(
+            &other_peer,
+            &address,
+            record,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_busy_rejection_is_retried() {
+        assert!(!PutRecordRejection::Busy.is_permanent());
+        assert!(!PutRecordRejection::Other("failed".to_string()).is_permanent());
+        assert!(PutRecordRejection::OutdatedCounter.is_permanent());
+    }
+}
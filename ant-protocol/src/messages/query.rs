@@ -68,7 +68,7 @@ pub enum Query {
         num_of_peers: Option<usize>,
         // Defines the range that replied peers shall be within
         range: Option<[u8; 32]>,
-        // For future econ usage,
+        // Whether the queried node shall sign its answer, so it can be held accountable for it
         sign_result: bool,
     },
 }
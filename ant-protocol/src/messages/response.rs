@@ -8,7 +8,7 @@
 
 use crate::{error::Result, NetworkAddress};
 
-use super::ChunkProof;
+use super::{ChunkProof, PutRecordRejection};
 use ant_evm::PaymentQuote;
 use bytes::Bytes;
 use core::fmt;
@@ -63,7 +63,7 @@ pub enum QueryResponse {
         // `Multiaddr` is required to allow the requester to dial the peer
         // Note: the list doesn't contain the node that being queried.
         peers: Vec<(NetworkAddress, Vec<Multiaddr>)>,
-        // Signature of signing the above (if requested), for future economic model usage.
+        // Signature of the above by the queried node, if requested. See `verify_closest_peers`.
         signature: Option<Vec<u8>>,
     },
 }
@@ -137,4 +137,18 @@ pub enum CmdResponse {
     //
     /// Response to the considered as bad notification
     PeerConsideredAsBad(Result<()>),
+    //
+    // ===== PutRecord =====
+    //
+    /// Response to [`PutRecord`], sent once the record has been validated.
+    /// On success the node signs [`put_record_ack_bytes`] with its keypair.
+    ///
+    /// [`PutRecord`]: crate::messages::Cmd::PutRecord
+    /// [`put_record_ack_bytes`]: crate::messages::put_record_ack_bytes
+    PutRecord {
+        /// Node's Peer Address
+        peer_address: NetworkAddress,
+        /// The signature of the acknowledgement, or the reason the record was rejected
+        result: std::result::Result<Vec<u8>, PutRecordRejection>,
+    },
 }
@@ -0,0 +1,256 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    error::{Error, Result},
+    version::NETWORK_ID,
+    CLOSE_GROUP_SIZE,
+};
+use lazy_static::lazy_static;
+use libp2p::kad::K_VALUE;
+use std::{fmt, str::FromStr, sync::RwLock};
+
+/// The number of quotes paid by a client, the payment vault contract verifying the payments of
+/// the 3 most expensive quotes only.
+pub const PAID_QUOTES: usize = 3;
+
+/// The network id of the mainnet, which always runs with the default parameters.
+const MAINNET_ID: u8 = 1;
+
+lazy_static! {
+    /// The parameters of the network the node or client is part of.
+    /// The default is set to the parameters of the mainnet.
+    static ref NETWORK_PARAMS: RwLock<NetworkParams> = RwLock::new(NetworkParams::default());
+}
+
+/// The redundancy parameters of a network.
+///
+/// All the nodes and clients of a network must agree on them, hence a network running with custom
+/// parameters has them appended to its protocol strings, so peers with other parameters are
+/// rejected at identify time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkParams {
+    /// The number of peers closest to a record that are responsible for it
+    pub close_group_size: usize,
+    /// The number of peers a record is replicated to
+    pub replication_factor: usize,
+    /// The number of quotes a client collects and pays for to store a record
+    pub min_quotes_to_pay: usize,
+}
+
+impl Default for NetworkParams {
+    fn default() -> Self {
+        Self::with_close_group_size(CLOSE_GROUP_SIZE)
+    }
+}
+
+impl NetworkParams {
+    /// The parameters derived from the close group size, in the same proportions as the mainnet.
+    pub fn with_close_group_size(close_group_size: usize) -> Self {
+        Self {
+            close_group_size,
+            replication_factor: close_group_size + 2,
+            min_quotes_to_pay: close_group_size,
+        }
+    }
+
+    /// The parameters derived from the close group size, or the mainnet's one, except for the
+    /// ones explicitly set, e.g. on the command line.
+    pub fn with_overrides(
+        close_group_size: Option<usize>,
+        replication_factor: Option<usize>,
+        min_quotes_to_pay: Option<usize>,
+    ) -> Self {
+        let derived = Self::with_close_group_size(close_group_size.unwrap_or(CLOSE_GROUP_SIZE));
+        Self {
+            close_group_size: derived.close_group_size,
+            replication_factor: replication_factor.unwrap_or(derived.replication_factor),
+            min_quotes_to_pay: min_quotes_to_pay.unwrap_or(derived.min_quotes_to_pay),
+        }
+    }
+
+    /// Check the parameters can be honoured by the nodes and clients.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| Err(Error::InvalidNetworkParams(reason));
+        let max_size = K_VALUE.get();
+        if self.close_group_size < PAID_QUOTES || self.close_group_size > max_size {
+            return invalid(format!(
+                "the close group size must be between {PAID_QUOTES} and {max_size}, got {}",
+                self.close_group_size
+            ));
+        }
+        if self.replication_factor < self.close_group_size || self.replication_factor > max_size {
+            return invalid(format!(
+                "the replication factor must be between the close group size ({}) and {max_size}, got {}",
+                self.close_group_size, self.replication_factor
+            ));
+        }
+        if self.min_quotes_to_pay < PAID_QUOTES || self.min_quotes_to_pay > self.close_group_size {
+            return invalid(format!(
+                "the number of quotes to pay must be between {PAID_QUOTES} and the close group size ({}), got {}",
+                self.close_group_size, self.min_quotes_to_pay
+            ));
+        }
+        Ok(())
+    }
+
+    /// The suffix appended to the protocol strings, so only the peers with the same parameters
+    /// can talk to each other. None for the default parameters, to stay compatible with the peers
+    /// that predate them.
+    pub(crate) fn protocol_suffix(&self) -> Option<String> {
+        if *self == Self::default() {
+            None
+        } else {
+            Some(format!("/{self}"))
+        }
+    }
+}
+
+impl fmt::Display for NetworkParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cg{}-rf{}-q{}",
+            self.close_group_size, self.replication_factor, self.min_quotes_to_pay
+        )
+    }
+}
+
+impl FromStr for NetworkParams {
+    type Err = Error;
+
+    /// Parse the parameters as displayed, e.g. `cg3-rf5-q3`.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidNetworkParams(format!("cannot parse {s:?}"));
+        let mut parts = s.split('-');
+        let mut next = |prefix: &str| {
+            parts
+                .next()
+                .and_then(|part| part.strip_prefix(prefix))
+                .and_then(|value| value.parse::<usize>().ok())
+                .ok_or_else(invalid)
+        };
+        let params = Self {
+            close_group_size: next("cg")?,
+            replication_factor: next("rf")?,
+            min_quotes_to_pay: next("q")?,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(params)
+    }
+}
+
+/// Update the NETWORK_PARAMS. The close group size, replication factor and number of quotes to
+/// pay will reference these values, and the protocol strings will carry them.
+///
+/// This should be called after `set_network_id` and before starting the node or client. The
+/// mainnet only accepts the default parameters.
+pub fn set_network_params(params: NetworkParams) -> Result<()> {
+    params.validate()?;
+    let network_id = *NETWORK_ID
+        .read()
+        .expect("Failed to obtain read lock for NETWORK_ID");
+    if network_id == MAINNET_ID && params != NetworkParams::default() {
+        return Err(Error::InvalidNetworkParams(format!(
+            "the mainnet runs with {}, use another network id to run with {params}",
+            NetworkParams::default()
+        )));
+    }
+
+    info!("Setting network params to: {params}");
+    let mut network_params = NETWORK_PARAMS
+        .write()
+        .expect("Failed to obtain write lock for NETWORK_PARAMS");
+    *network_params = params;
+    Ok(())
+}
+
+/// Get the parameters of the network.
+pub fn network_params() -> NetworkParams {
+    *NETWORK_PARAMS
+        .read()
+        .expect("Failed to obtain read lock for NETWORK_PARAMS")
+}
+
+/// The number of peers closest to a record that are responsible for it.
+pub fn close_group_size() -> usize {
+    network_params().close_group_size
+}
+
+/// The number of peers a record is replicated to.
+pub fn replication_factor() -> usize {
+    network_params().replication_factor
+}
+
+/// The number of quotes a client collects and pays for to store a record.
+pub fn min_quotes_to_pay() -> usize {
+    network_params().min_quotes_to_pay
+}
+
+/// Whether the peer with the given identify protocol string runs the same version of the same
+/// network, but with other parameters.
+pub fn is_network_params_mismatch(our_protocol: &str, their_protocol: &str) -> bool {
+    // e.g. `ant/0.3/2/cg3-rf5-q3`, the parameters being absent for the defaults
+    fn base(protocol: &str) -> Vec<&str> {
+        protocol.splitn(4, '/').take(3).collect()
+    }
+    our_protocol != their_protocol && base(our_protocol) == base(their_protocol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_params() {
+        let params = NetworkParams::with_close_group_size(3);
+        assert!(params.validate().is_ok());
+        assert_eq!(params.to_string(), "cg3-rf5-q3");
+        assert_eq!(
+            "cg3-rf5-q3".parse::<NetworkParams>().ok(),
+            Some(NetworkParams::with_close_group_size(3))
+        );
+        assert!("cg3-rf5".parse::<NetworkParams>().is_err());
+        assert!("cg3-rf5-q3-x1".parse::<NetworkParams>().is_err());
+
+        assert_eq!(NetworkParams::default().protocol_suffix(), None);
+        assert_eq!(params.protocol_suffix().as_deref(), Some("/cg3-rf5-q3"));
+
+        for invalid in [
+            NetworkParams::with_close_group_size(2),
+            NetworkParams::with_close_group_size(21),
+            NetworkParams {
+                replication_factor: 2,
+                ..params
+            },
+            NetworkParams {
+                min_quotes_to_pay: 4,
+                ..params
+            },
+        ] {
+            assert!(invalid.validate().is_err(), "{invalid} should be invalid");
+        }
+
+        assert!(is_network_params_mismatch(
+            "ant/0.3/2",
+            "ant/0.3/2/cg3-rf5-q3"
+        ));
+        assert!(is_network_params_mismatch(
+            "ant/0.3/2/cg8-rf10-q5",
+            "ant/0.3/2/cg3-rf5-q3"
+        ));
+        assert!(!is_network_params_mismatch("ant/0.3/2", "ant/0.3/2"));
+        assert!(!is_network_params_mismatch("ant/0.3/2", "ant/0.3/1"));
+        assert!(!is_network_params_mismatch(
+            "ant/0.3/2",
+            "ant/0.4/2/cg3-rf5-q3"
+        ));
+    }
+}
@@ -6,6 +6,7 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+use crate::network_params::network_params;
 use lazy_static::lazy_static;
 use std::sync::RwLock;
 
@@ -33,17 +34,19 @@ lazy_static! {
     /// The req/response protocol version
     pub static ref REQ_RESPONSE_VERSION_STR: RwLock<String> =
         RwLock::new(format!(
-            "/ant/{}/{}",
+            "/ant/{}/{}{}",
             get_truncate_version_str(),
             *NETWORK_ID.read().expect("Failed to obtain read lock for NETWORK_ID"),
+            network_params().protocol_suffix().unwrap_or_default(),
         ));
 
     /// The identify protocol version
     pub static ref IDENTIFY_PROTOCOL_STR: RwLock<String> =
         RwLock::new(format!(
-            "ant/{}/{}",
+            "ant/{}/{}{}",
             get_truncate_version_str(),
             *NETWORK_ID.read().expect("Failed to obtain read lock for NETWORK_ID"),
+            network_params().protocol_suffix().unwrap_or_default(),
         ));
 }
 
@@ -73,6 +76,8 @@ pub fn get_network_id() -> String {
 
 // Protocol support shall be downward compatible for patch only version update.
 // i.e. versions of `A.B.X` or `A.B.X-alpha.Y` shall be considered as a same protocol of `A.B`
+// Any change to the messages shall bump at least the minor version, e.g. `1.1` moved the client PUTs
+// from kad to the `Cmd::PutRecord` request, which `1.0` nodes can't decode.
 pub fn get_truncate_version_str() -> String {
     let version_str = env!("CARGO_PKG_VERSION");
     let parts = version_str.split('.').collect::<Vec<_>>();
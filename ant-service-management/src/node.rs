@@ -81,6 +81,14 @@ impl ServiceStateActions for NodeService<'_> {
             args.push(OsString::from("--network-id"));
             args.push(OsString::from(id.to_string()));
         }
+        if let Some(path) = &self.service_data.network_key_file {
+            args.push(OsString::from("--network-key-file"));
+            args.push(OsString::from(path.to_string_lossy().to_string()));
+        }
+        if let Some(path) = &self.service_data.allowed_peers_file {
+            args.push(OsString::from("--allowed-peers-file"));
+            args.push(OsString::from(path.to_string_lossy().to_string()));
+        }
         if self.service_data.upnp {
             args.push(OsString::from("--upnp"));
         }
@@ -264,6 +272,8 @@ impl ServiceStateActions for NodeService<'_> {
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct NodeServiceData {
+    #[serde(default)]
+    pub allowed_peers_file: Option<PathBuf>,
     pub antnode_path: PathBuf,
     #[serde(default)]
     pub auto_restart: bool,
@@ -285,6 +295,8 @@ pub struct NodeServiceData {
     pub metrics_port: Option<u16>,
     pub network_id: Option<u8>,
     #[serde(default)]
+    pub network_key_file: Option<PathBuf>,
+    #[serde(default)]
     pub node_ip: Option<Ipv4Addr>,
     #[serde(default)]
     pub node_port: Option<u16>,
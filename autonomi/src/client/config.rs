@@ -11,9 +11,9 @@ use ant_networking::{GetRecordCfg, PutRecordCfg, VerificationKind};
 use ant_protocol::messages::ChunkProof;
 use libp2p::{kad::Record, Multiaddr, PeerId};
 use rand::{thread_rng, Rng};
-use std::{collections::HashSet, num::NonZero};
+use std::{collections::HashSet, num::NonZero, path::PathBuf};
 
-pub use ant_networking::{ResponseQuorum, RetryStrategy};
+pub use ant_networking::{PrivateNetwork, ResponseQuorum, RetryStrategy, TransportConfig};
 
 /// Configuration for the [`crate::Client`] which can be provided through: [`crate::Client::init_with_config`].
 #[derive(Debug, Clone, Default)]
@@ -31,6 +31,24 @@ pub struct ClientConfig {
 
     /// Strategy for data operations by the client.
     pub strategy: ClientOperatingStrategy,
+
+    /// The transports to enable alongside QUIC, to be able to dial peers that listen on them.
+    ///
+    /// The ports are ignored, as the client doesn't listen.
+    pub transports: TransportConfig,
+
+    /// File where the peers of the routing table are persisted.
+    ///
+    /// If provided, the routing table is seeded with the peers saved by a previous run, so the
+    /// client is connected almost instantly instead of bootstrapping from scratch.
+    pub routing_table_snapshot: Option<PathBuf>,
+
+    /// The pre-shared key and/or the peers allowed in a private network.
+    ///
+    /// With a key, the client connects over TCP only, so the peers must be given by their TCP
+    /// addresses. With an allow-list, the client must connect with a fixed `identity` listed by
+    /// the nodes.
+    pub private_network: PrivateNetwork,
 }
 
 impl ClientConfig {
@@ -40,6 +58,9 @@ impl ClientConfig {
             peers,
             evm_network: EvmNetwork::new(true).unwrap_or_default(),
             strategy: Default::default(),
+            transports: Default::default(),
+            routing_table_snapshot: None,
+            private_network: Default::default(),
         }
     }
 }
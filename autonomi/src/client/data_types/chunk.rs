@@ -24,7 +24,7 @@ use ant_protocol::{
 };
 use bytes::Bytes;
 use libp2p::kad::Record;
-use self_encryption::{decrypt_full_set, DataMap, EncryptedChunk};
+use self_encryption::{decrypt_full_set, decrypt_range, ChunkInfo, DataMap, EncryptedChunk};
 use serde::{Deserialize, Serialize};
 use std::{
     hash::{DefaultHasher, Hash, Hasher},
@@ -360,11 +360,74 @@ impl Client {
         }
     }
 
+    /// Fetch and decrypt `len` bytes of the data at `position`, only fetching the chunks covering them.
+    /// The levels of data map above the first are fetched in full, as they are small.
+    pub(crate) async fn fetch_range_from_data_map_chunk(
+        &self,
+        data_map_bytes: &Bytes,
+        position: u64,
+        len: u64,
+    ) -> Result<Bytes, GetError> {
+        let mut data_map_level: DataMapLevel = rmp_serde::from_slice(data_map_bytes)
+            .map_err(GetError::InvalidDataMap)
+            .inspect_err(|err| error!("Error deserializing data map: {err:?}"))?;
+
+        let data_map = loop {
+            match data_map_level {
+                DataMapLevel::First(map) => break map,
+                DataMapLevel::Additional(map) => {
+                    let data = self.fetch_from_data_map(&map).await?;
+                    data_map_level = rmp_serde::from_slice(&data).map_err(|err| {
+                        error!("Error deserializing data map: {err:?}");
+                        GetError::InvalidDataMap(err)
+                    })?;
+                }
+            }
+        };
+
+        let Some((range_start, infos)) = chunks_for_range(&data_map, position, len) else {
+            return Ok(Bytes::new());
+        };
+        debug!(
+            "Fetching {} of the {} chunks of data map for range {position}+{len}",
+            infos.len(),
+            data_map.infos().len()
+        );
+
+        let encrypted_chunks = self.fetch_encrypted_chunks(infos).await?;
+        let data = decrypt_range(
+            &data_map,
+            &encrypted_chunks,
+            (position - range_start) as usize,
+            len as usize,
+        )
+        .map_err(|e| {
+            error!("Error decrypting encrypted_chunks: {e:?}");
+            GetError::Decryption(crate::self_encryption::Error::SelfEncryption(e))
+        })?;
+        Ok(data)
+    }
+
     /// Fetch and decrypt all chunks in the data map.
     pub(crate) async fn fetch_from_data_map(&self, data_map: &DataMap) -> Result<Bytes, GetError> {
         debug!("Fetching encrypted data chunks from data map {data_map:?}");
+        let encrypted_chunks = self.fetch_encrypted_chunks(data_map.infos()).await?;
+
+        let data = decrypt_full_set(data_map, &encrypted_chunks).map_err(|e| {
+            error!("Error decrypting encrypted_chunks: {e:?}");
+            GetError::Decryption(crate::self_encryption::Error::SelfEncryption(e))
+        })?;
+        debug!("Successfully decrypted all the chunks");
+        Ok(data)
+    }
+
+    /// Fetch the encrypted chunks of the given data map entries.
+    async fn fetch_encrypted_chunks(
+        &self,
+        infos: Vec<ChunkInfo>,
+    ) -> Result<Vec<EncryptedChunk>, GetError> {
         let mut download_tasks = vec![];
-        for info in data_map.infos() {
+        for info in infos {
             download_tasks.push(async move {
                 match self
                     .chunk_get(&ChunkAddress::new(info.dst_hash))
@@ -389,18 +452,76 @@ impl Client {
                 }
             });
         }
-        debug!("Successfully fetched all the encrypted chunks");
         let encrypted_chunks =
             process_tasks_with_max_concurrency(download_tasks, *CHUNK_DOWNLOAD_BATCH_SIZE)
                 .await
                 .into_iter()
                 .collect::<Result<Vec<EncryptedChunk>, GetError>>()?;
+        debug!("Successfully fetched all the encrypted chunks");
+        Ok(encrypted_chunks)
+    }
+}
 
-        let data = decrypt_full_set(data_map, &encrypted_chunks).map_err(|e| {
-            error!("Error decrypting encrypted_chunks: {e:?}");
-            GetError::Decryption(crate::self_encryption::Error::SelfEncryption(e))
-        })?;
-        debug!("Successfully decrypted all the chunks");
-        Ok(data)
+/// The entries of the data map covering `len` bytes of the data at `position`, with the position
+/// in the data of the first one. Returns None if the range is empty or starts after the data.
+fn chunks_for_range(data_map: &DataMap, position: u64, len: u64) -> Option<(u64, Vec<ChunkInfo>)> {
+    // the chunks are in order of index, each holding `src_size` bytes of the data
+    let end = position.saturating_add(len);
+    let mut chunk_start = 0;
+    let mut range_start = None;
+    let mut infos = vec![];
+    for info in data_map.infos() {
+        let chunk_end = chunk_start + info.src_size as u64;
+        if chunk_end > position && chunk_start < end {
+            range_start.get_or_insert(chunk_start);
+            infos.push(info);
+        }
+        chunk_start = chunk_end;
+    }
+    range_start.map(|range_start| (range_start, infos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngCore;
+
+    #[test]
+    fn test_decrypt_range_from_its_chunks_only() -> Result<(), self_encryption::Error> {
+        let mut data = vec![0u8; 5 * 1024 * 1024];
+        rand::thread_rng().fill_bytes(&mut data);
+        let data = Bytes::from(data);
+        let (data_map, chunks) = self_encryption::encrypt(data.clone())?;
+        assert!(data_map.infos().len() > 3);
+
+        let first_chunk_size = data_map.infos()[0].src_size as u64;
+        for (position, len) in [
+            (0, 10),
+            (first_chunk_size - 5, 10),
+            (first_chunk_size + 1, 3 * first_chunk_size),
+            (data.len() as u64 - 7, 100),
+        ] {
+            let (range_start, infos) =
+                chunks_for_range(&data_map, position, len).expect("range within the data");
+            assert!(infos.len() < data_map.infos().len());
+            let range_chunks: Vec<EncryptedChunk> = chunks
+                .iter()
+                .filter(|chunk| infos.iter().any(|info| info.index == chunk.index))
+                .cloned()
+                .collect();
+
+            let range = decrypt_range(
+                &data_map,
+                &range_chunks,
+                (position - range_start) as usize,
+                len as usize,
+            )?;
+            let end = (position + len).min(data.len() as u64) as usize;
+            assert_eq!(range, data.slice(position as usize..end));
+        }
+
+        assert!(chunks_for_range(&data_map, data.len() as u64, 10).is_none());
+        assert!(chunks_for_range(&data_map, 0, 0).is_none());
+        Ok(())
     }
 }
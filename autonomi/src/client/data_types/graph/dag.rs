@@ -0,0 +1,686 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::{GraphEntry, GraphEntryAddress, GraphError};
+use crate::client::Client;
+
+use ant_networking::{GetRecordError, NetworkError};
+use bls::PublicKey;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+use std::future::Future;
+
+/// Default number of graph entries fetched concurrently during a traversal
+pub const DEFAULT_TRAVERSAL_CONCURRENCY: usize = 8;
+
+/// Which links of the graph entries to follow during a traversal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TraversalDirection {
+    /// Follow the `descendants` of each entry
+    #[default]
+    Descendants,
+    /// Follow the `parents` of each entry
+    Ancestors,
+}
+
+/// The order in which graph entries are visited during a traversal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TraversalOrder {
+    #[default]
+    BreadthFirst,
+    DepthFirst,
+}
+
+/// Options of a graph traversal, see [`Client::graph_traverse`]
+#[derive(Debug, Clone)]
+pub struct GraphTraversal {
+    pub direction: TraversalDirection,
+    pub order: TraversalOrder,
+    /// Entries further than this number of links from the root are not fetched
+    pub max_depth: Option<usize>,
+    /// Stop the traversal once this number of entries have been visited
+    pub max_entries: Option<usize>,
+    /// Maximum number of entries fetched concurrently, ahead of the traversal
+    pub concurrency: usize,
+}
+
+impl Default for GraphTraversal {
+    fn default() -> Self {
+        Self {
+            direction: TraversalDirection::default(),
+            order: TraversalOrder::default(),
+            max_depth: None,
+            max_entries: None,
+            concurrency: DEFAULT_TRAVERSAL_CONCURRENCY,
+        }
+    }
+}
+
+impl GraphTraversal {
+    /// Breadth first traversal following the descendants of each entry
+    pub fn descendants() -> Self {
+        Self::default()
+    }
+
+    /// Breadth first traversal following the parents of each entry
+    pub fn ancestors() -> Self {
+        Self {
+            direction: TraversalDirection::Ancestors,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_order(mut self, order: TraversalOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+}
+
+/// A graph entry visited during a traversal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphDagNode {
+    /// Number of links between the root and this entry
+    pub depth: usize,
+    /// The entries found at this address, more than one if the graph forked there
+    pub entries: Vec<GraphEntry>,
+}
+
+impl GraphDagNode {
+    /// The first entry found at this address
+    pub fn entry(&self) -> &GraphEntry {
+        &self.entries[0]
+    }
+
+    /// Whether multiple entries were found at this address
+    pub fn is_fork(&self) -> bool {
+        self.entries.len() > 1
+    }
+}
+
+/// The part of a graph visited by a traversal, held in memory
+///
+/// Edges always go from parent to descendant, whatever the direction of the traversal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphDag {
+    pub root: GraphEntryAddress,
+    pub direction: TraversalDirection,
+    /// The visited entries
+    pub nodes: BTreeMap<GraphEntryAddress, GraphDagNode>,
+    /// The visited addresses, in traversal order
+    pub order: Vec<GraphEntryAddress>,
+    /// The links between the visited entries, and to the missing ones
+    pub edges: BTreeSet<(GraphEntryAddress, GraphEntryAddress)>,
+    /// Linked entries that could not be found on the network
+    pub missing: BTreeSet<GraphEntryAddress>,
+    /// Edges that close a cycle, these were not followed again
+    pub cycles: BTreeSet<(GraphEntryAddress, GraphEntryAddress)>,
+    /// Whether the traversal stopped before visiting every reachable entry, because of `max_depth` or `max_entries`
+    pub truncated: bool,
+}
+
+impl GraphDag {
+    fn new(root: GraphEntryAddress, direction: TraversalDirection) -> Self {
+        Self {
+            root,
+            direction,
+            nodes: BTreeMap::new(),
+            order: vec![],
+            edges: BTreeSet::new(),
+            missing: BTreeSet::new(),
+            cycles: BTreeSet::new(),
+            truncated: false,
+        }
+    }
+
+    /// Get a visited entry
+    pub fn get(&self, address: &GraphEntryAddress) -> Option<&GraphDagNode> {
+        self.nodes.get(address)
+    }
+
+    /// The addresses where the graph forked, with every entry found there
+    pub fn forks(&self) -> impl Iterator<Item = (&GraphEntryAddress, &Vec<GraphEntry>)> {
+        self.nodes
+            .iter()
+            .filter(|(_, node)| node.is_fork())
+            .map(|(addr, node)| (addr, &node.entries))
+    }
+
+    /// The visited entries without any parent among the visited entries
+    pub fn heads(&self) -> Vec<GraphEntryAddress> {
+        let children: HashSet<_> = self.edges.iter().map(|(_, to)| *to).collect();
+        self.nodes
+            .keys()
+            .filter(|addr| !children.contains(addr))
+            .copied()
+            .collect()
+    }
+
+    /// The visited entries without any descendant among the visited entries
+    pub fn tails(&self) -> Vec<GraphEntryAddress> {
+        let parents: HashSet<_> = self.edges.iter().map(|(from, _)| *from).collect();
+        self.nodes
+            .keys()
+            .filter(|addr| !parents.contains(addr))
+            .copied()
+            .collect()
+    }
+
+    /// Export the DAG to the Graphviz DOT format
+    /// Forked entries are drawn in red, missing ones dashed and the edges closing cycles in red
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph graph_entries {\n");
+        for (addr, node) in &self.nodes {
+            let hex = addr.to_hex();
+            let mut attrs = format!(
+                "label=\"{}\\ndepth {}\\ncontent {}\"",
+                short_hex(&hex),
+                node.depth,
+                short_hex(&hex::encode(node.entry().content))
+            );
+            if *addr == self.root {
+                attrs.push_str(", shape=doublecircle");
+            }
+            if node.is_fork() {
+                let _ = write!(
+                    attrs,
+                    ", color=red, xlabel=\"fork x{}\"",
+                    node.entries.len()
+                );
+            }
+            let _ = writeln!(dot, "  \"{hex}\" [{attrs}];");
+        }
+        for addr in &self.missing {
+            let hex = addr.to_hex();
+            let _ = writeln!(
+                dot,
+                "  \"{hex}\" [label=\"{} (missing)\", style=dashed];",
+                short_hex(&hex)
+            );
+        }
+        for edge in &self.edges {
+            let (from, to) = edge;
+            let attrs = if self.cycles.contains(edge) {
+                " [color=red]"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                dot,
+                "  \"{}\" -> \"{}\"{attrs};",
+                from.to_hex(),
+                to.to_hex()
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Export the DAG to JSON, keys and contents are hex encoded
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let nodes = self
+            .order
+            .iter()
+            .filter_map(|addr| self.nodes.get(addr).map(|node| (addr, node)))
+            .map(|(addr, node)| JsonNode {
+                address: addr.to_hex(),
+                depth: node.depth,
+                entries: node.entries.iter().map(JsonEntry::from).collect(),
+            })
+            .collect();
+        let json = JsonDag {
+            root: self.root.to_hex(),
+            nodes,
+            edges: self.edges.iter().map(json_edge).collect(),
+            missing: self.missing.iter().map(|addr| addr.to_hex()).collect(),
+            cycles: self.cycles.iter().map(json_edge).collect(),
+            truncated: self.truncated,
+        };
+        serde_json::to_string_pretty(&json)
+    }
+
+    /// Add a visited node along with its edges
+    fn insert(&mut self, address: GraphEntryAddress, node: GraphDagNode) {
+        for entry in &node.entries {
+            for parent in &entry.parents {
+                self.edges
+                    .insert((GraphEntryAddress::from_owner(*parent), address));
+            }
+            for (descendant, _) in &entry.descendants {
+                self.edges
+                    .insert((address, GraphEntryAddress::from_owner(*descendant)));
+            }
+        }
+        self.order.push(address);
+        self.nodes.insert(address, node);
+    }
+
+    /// Keep only the edges between known entries and find the ones closing cycles
+    fn finish(&mut self) {
+        let known =
+            |addr: &GraphEntryAddress| self.nodes.contains_key(addr) || self.missing.contains(addr);
+        let edges = std::mem::take(&mut self.edges);
+        self.edges = edges
+            .into_iter()
+            .filter(|(from, to)| known(from) && known(to))
+            .collect();
+        self.cycles = self.find_back_edges();
+    }
+
+    /// Depth first search over the known edges, any edge leading back to an entry on the current path closes a cycle
+    fn find_back_edges(&self) -> BTreeSet<(GraphEntryAddress, GraphEntryAddress)> {
+        let mut children: BTreeMap<GraphEntryAddress, Vec<GraphEntryAddress>> = BTreeMap::new();
+        for (from, to) in &self.edges {
+            children.entry(*from).or_default().push(*to);
+        }
+
+        let mut back_edges = BTreeSet::new();
+        let mut done = HashSet::new();
+        let mut on_path = HashSet::new();
+        for start in self.order.iter() {
+            if done.contains(start) {
+                continue;
+            }
+            // stack of (address, index of the next child to visit)
+            let mut stack = vec![(*start, 0)];
+            on_path.insert(*start);
+            while let Some((addr, next)) = stack.last_mut() {
+                let addr = *addr;
+                match children.get(&addr).and_then(|c| c.get(*next)) {
+                    Some(child) => {
+                        *next += 1;
+                        if on_path.contains(child) {
+                            back_edges.insert((addr, *child));
+                        } else if !done.contains(child) {
+                            on_path.insert(*child);
+                            stack.push((*child, 0));
+                        }
+                    }
+                    None => {
+                        stack.pop();
+                        on_path.remove(&addr);
+                        done.insert(addr);
+                    }
+                }
+            }
+        }
+        back_edges
+    }
+}
+
+fn short_hex(hex: &str) -> &str {
+    &hex[..hex.len().min(8)]
+}
+
+fn json_edge(edge: &(GraphEntryAddress, GraphEntryAddress)) -> [String; 2] {
+    [edge.0.to_hex(), edge.1.to_hex()]
+}
+
+#[derive(Serialize)]
+struct JsonDag {
+    root: String,
+    nodes: Vec<JsonNode>,
+    edges: Vec<[String; 2]>,
+    missing: Vec<String>,
+    cycles: Vec<[String; 2]>,
+    truncated: bool,
+}
+
+#[derive(Serialize)]
+struct JsonNode {
+    address: String,
+    depth: usize,
+    entries: Vec<JsonEntry>,
+}
+
+#[derive(Serialize)]
+struct JsonEntry {
+    owner: String,
+    parents: Vec<String>,
+    content: String,
+    descendants: Vec<[String; 2]>,
+}
+
+impl From<&GraphEntry> for JsonEntry {
+    fn from(entry: &GraphEntry) -> Self {
+        Self {
+            owner: entry.owner.to_hex(),
+            parents: entry.parents.iter().map(|pk| pk.to_hex()).collect(),
+            content: hex::encode(entry.content),
+            descendants: entry
+                .descendants
+                .iter()
+                .map(|(pk, content)| [pk.to_hex(), hex::encode(content)])
+                .collect(),
+        }
+    }
+}
+
+/// The keys linked from the entries, in the given direction, without duplicates
+fn linked_keys(entries: &[GraphEntry], direction: TraversalDirection) -> Vec<PublicKey> {
+    let mut seen = HashSet::new();
+    let keys = entries.iter().flat_map(|entry| -> Vec<PublicKey> {
+        match direction {
+            TraversalDirection::Descendants => {
+                entry.descendants.iter().map(|(pk, _)| *pk).collect()
+            }
+            TraversalDirection::Ancestors => entry.parents.clone(),
+        }
+    });
+    keys.filter(|pk| seen.insert(*pk)).collect()
+}
+
+/// Traverse a graph from the root, fetching entries with `fetch`
+/// `fetch` returns the entries found at an address, or `None` if there is none
+async fn traverse<F, Fut>(
+    root: GraphEntryAddress,
+    options: &GraphTraversal,
+    fetch: F,
+) -> Result<GraphDag, GraphError>
+where
+    F: Fn(GraphEntryAddress) -> Fut,
+    Fut: Future<Output = Result<Option<Vec<GraphEntry>>, GraphError>>,
+{
+    let mut dag = GraphDag::new(root, options.direction);
+    let mut frontier = VecDeque::from([(root, 0)]);
+    let mut discovered = HashSet::from([root]);
+    let mut fetched: HashMap<GraphEntryAddress, Option<Vec<GraphEntry>>> = HashMap::new();
+    let concurrency = options.concurrency.max(1);
+
+    loop {
+        if options
+            .max_entries
+            .is_some_and(|max| dag.nodes.len() >= max)
+        {
+            dag.truncated = !frontier.is_empty();
+            break;
+        }
+
+        // prefetch the next entries to be visited
+        let upcoming: Vec<_> = match options.order {
+            TraversalOrder::BreadthFirst => frontier.iter().collect(),
+            TraversalOrder::DepthFirst => frontier.iter().rev().collect(),
+        };
+        let to_fetch: Vec<_> = upcoming
+            .into_iter()
+            .map(|(addr, _)| *addr)
+            .filter(|addr| !fetched.contains_key(addr))
+            .take(concurrency)
+            .collect();
+        let results = futures::future::join_all(to_fetch.iter().map(|addr| fetch(*addr))).await;
+        for (addr, res) in to_fetch.into_iter().zip(results) {
+            fetched.insert(addr, res?);
+        }
+
+        let next = match options.order {
+            TraversalOrder::BreadthFirst => frontier.pop_front(),
+            TraversalOrder::DepthFirst => frontier.pop_back(),
+        };
+        let Some((addr, depth)) = next else {
+            break;
+        };
+
+        let entries = match fetched.remove(&addr).flatten() {
+            Some(entries) if !entries.is_empty() => entries,
+            _ => {
+                debug!("Graph entry at {addr:?} is missing");
+                dag.missing.insert(addr);
+                continue;
+            }
+        };
+
+        let mut linked: Vec<_> = linked_keys(&entries, options.direction)
+            .into_iter()
+            .map(GraphEntryAddress::from_owner)
+            .filter(|linked| !discovered.contains(linked))
+            .collect();
+        if options.max_depth.is_some_and(|max| depth >= max) {
+            dag.truncated |= !linked.is_empty();
+            linked.clear();
+        }
+        // depth first pops from the back, push in reverse to visit the links in order
+        if options.order == TraversalOrder::DepthFirst {
+            linked.reverse();
+        }
+        for linked in linked {
+            discovered.insert(linked);
+            frontier.push_back((linked, depth + 1));
+        }
+
+        dag.insert(addr, GraphDagNode { depth, entries });
+    }
+
+    dag.finish();
+    Ok(dag)
+}
+
+impl Client {
+    /// Traverse a graph from the given root entry and return the visited part of the graph.
+    ///
+    /// Entries are fetched concurrently ahead of the traversal, each entry is visited once even if
+    /// the graph has cycles. Forks and missing entries are reported in the returned [`GraphDag`]
+    /// instead of failing the traversal, forked entries have all their versions followed.
+    pub async fn graph_traverse(
+        &self,
+        root: &GraphEntryAddress,
+        options: GraphTraversal,
+    ) -> Result<GraphDag, GraphError> {
+        debug!("Traversing graph from {root:?} with {options:?}");
+        let dag = traverse(*root, &options, |addr| self.graph_entry_get_all(addr)).await?;
+        debug!(
+            "Traversed {} graph entries from {root:?}, {} missing, truncated: {}",
+            dag.nodes.len(),
+            dag.missing.len(),
+            dag.truncated
+        );
+        Ok(dag)
+    }
+
+    /// Walk the parents of a graph entry, up to `max_depth` links away if set
+    pub async fn graph_ancestors(
+        &self,
+        address: &GraphEntryAddress,
+        max_depth: Option<usize>,
+    ) -> Result<GraphDag, GraphError> {
+        let mut options = GraphTraversal::ancestors();
+        options.max_depth = max_depth;
+        self.graph_traverse(address, options).await
+    }
+
+    /// Fetch all the entries at an address, more than one in case of a fork, or `None` if there is none
+    async fn graph_entry_get_all(
+        &self,
+        address: GraphEntryAddress,
+    ) -> Result<Option<Vec<GraphEntry>>, GraphError> {
+        match self.graph_entry_get(&address).await {
+            Ok(entry) => Ok(Some(vec![entry])),
+            Err(GraphError::Fork(entries)) => Ok(Some(entries)),
+            Err(GraphError::Network(NetworkError::GetRecordError(
+                GetRecordError::RecordNotFound,
+            ))) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls::SecretKey;
+    use std::cell::RefCell;
+
+    struct TestGraph {
+        keys: Vec<SecretKey>,
+        entries: HashMap<GraphEntryAddress, Vec<GraphEntry>>,
+        fetches: RefCell<Vec<GraphEntryAddress>>,
+    }
+
+    impl TestGraph {
+        /// Create a graph of `n` entries with the given links between them
+        fn new(n: usize, links: &[(usize, usize)], missing: &[usize]) -> Self {
+            let keys: Vec<_> = (0..n).map(|_| SecretKey::random()).collect();
+            let mut entries = HashMap::new();
+            for (i, key) in keys.iter().enumerate() {
+                if missing.contains(&i) {
+                    continue;
+                }
+                let parents = links
+                    .iter()
+                    .filter(|(_, to)| *to == i)
+                    .map(|(from, _)| keys[*from].public_key())
+                    .collect();
+                let descendants = links
+                    .iter()
+                    .filter(|(from, _)| *from == i)
+                    .map(|(_, to)| (keys[*to].public_key(), [0; 32]))
+                    .collect();
+                let entry = GraphEntry::new(key, parents, [i as u8; 32], descendants);
+                entries.insert(entry.address(), vec![entry]);
+            }
+            Self {
+                keys,
+                entries,
+                fetches: RefCell::new(vec![]),
+            }
+        }
+
+        fn addr(&self, i: usize) -> GraphEntryAddress {
+            GraphEntryAddress::from_owner(self.keys[i].public_key())
+        }
+
+        fn traverse(&self, root: usize, options: GraphTraversal) -> GraphDag {
+            let fetch = |addr| {
+                self.fetches.borrow_mut().push(addr);
+                let res = self.entries.get(&addr).cloned();
+                async move { Ok(res) }
+            };
+            futures::executor::block_on(traverse(self.addr(root), &options, fetch))
+                .expect("in memory traversal cannot fail")
+        }
+    }
+
+    #[test]
+    fn test_traversal_orders() {
+        //     0
+        //    / \
+        //   1   2
+        //   |   |
+        //   3   4
+        let graph = TestGraph::new(5, &[(0, 1), (0, 2), (1, 3), (2, 4)], &[]);
+        let addrs: Vec<_> = (0..5).map(|i| graph.addr(i)).collect();
+
+        let bfs = graph.traverse(0, GraphTraversal::descendants());
+        assert_eq!(
+            bfs.order,
+            vec![addrs[0], addrs[1], addrs[2], addrs[3], addrs[4]]
+        );
+        assert_eq!(bfs.get(&addrs[4]).map(|n| n.depth), Some(2));
+        assert_eq!(bfs.edges.len(), 4);
+        assert_eq!(bfs.heads(), vec![addrs[0]]);
+        assert!(!bfs.truncated);
+
+        let dfs = graph.traverse(
+            0,
+            GraphTraversal::descendants().with_order(TraversalOrder::DepthFirst),
+        );
+        assert_eq!(
+            dfs.order,
+            vec![addrs[0], addrs[1], addrs[3], addrs[2], addrs[4]]
+        );
+
+        let ancestors = graph.traverse(4, GraphTraversal::ancestors());
+        assert_eq!(ancestors.order, vec![addrs[4], addrs[2], addrs[0]]);
+        assert_eq!(ancestors.tails(), vec![addrs[4]]);
+    }
+
+    #[test]
+    fn test_traversal_limits() {
+        // a chain 0 -> 1 -> 2 -> 3
+        let graph = TestGraph::new(4, &[(0, 1), (1, 2), (2, 3)], &[]);
+
+        let dag = graph.traverse(0, GraphTraversal::descendants().with_max_depth(1));
+        assert_eq!(dag.nodes.len(), 2);
+        assert!(dag.truncated);
+
+        let dag = graph.traverse(0, GraphTraversal::descendants().with_max_entries(3));
+        assert_eq!(dag.nodes.len(), 3);
+        assert!(dag.truncated);
+
+        let dag = graph.traverse(0, GraphTraversal::descendants().with_max_entries(4));
+        assert_eq!(dag.nodes.len(), 4);
+        assert!(!dag.truncated);
+    }
+
+    #[test]
+    fn test_traversal_cycles_and_missing() {
+        // 0 -> 1 -> 2 -> 0, and 1 -> 3 which is missing
+        let graph = TestGraph::new(4, &[(0, 1), (1, 2), (2, 0), (1, 3)], &[3]);
+        let dag = graph.traverse(0, GraphTraversal::descendants());
+
+        assert_eq!(dag.nodes.len(), 3);
+        assert_eq!(
+            dag.missing.iter().copied().collect::<Vec<_>>(),
+            vec![graph.addr(3)]
+        );
+        assert_eq!(
+            dag.cycles.iter().copied().collect::<Vec<_>>(),
+            vec![(graph.addr(2), graph.addr(0))]
+        );
+        // each entry is fetched once
+        assert_eq!(graph.fetches.borrow().len(), 4);
+
+        let dot = dag.to_dot();
+        assert!(dot.starts_with("digraph"));
+        assert!(dot.contains("style=dashed"));
+        assert!(dot.contains("[color=red]"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&dag.to_json().expect("json export")).expect("valid json");
+        assert_eq!(json["nodes"].as_array().map(|n| n.len()), Some(3));
+        assert_eq!(json["cycles"].as_array().map(|c| c.len()), Some(1));
+    }
+
+    #[test]
+    fn test_traversal_forks() {
+        let mut graph = TestGraph::new(3, &[(0, 1)], &[]);
+        // a second version of 1 linking to 2
+        let fork = GraphEntry::new(
+            &graph.keys[1],
+            vec![graph.keys[0].public_key()],
+            [9; 32],
+            vec![(graph.keys[2].public_key(), [0; 32])],
+        );
+        if let Some(versions) = graph.entries.get_mut(&graph.addr(1)) {
+            versions.push(fork);
+        }
+
+        let dag = graph.traverse(0, GraphTraversal::descendants());
+        let forks: Vec<_> = dag
+            .forks()
+            .map(|(addr, entries)| (*addr, entries.len()))
+            .collect();
+        assert_eq!(forks, vec![(graph.addr(1), 2)]);
+        // the descendants of every version are followed
+        assert!(dag.get(&graph.addr(2)).is_some());
+    }
+}
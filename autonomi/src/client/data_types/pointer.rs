@@ -7,11 +7,13 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::client::{
+    data_types::chunk::CHUNK_UPLOAD_BATCH_SIZE,
     payment::{PayError, PaymentOption},
     quote::CostError,
-    Client,
+    utils::process_ordered_tasks_with_max_concurrency,
+    Client, QuorumFailure,
 };
-use ant_evm::{Amount, AttoTokens, EvmWalletError};
+use ant_evm::{Amount, AttoTokens, EvmWalletError, ProofOfPayment};
 use ant_networking::{GetRecordError, NetworkError};
 use ant_protocol::{
     storage::{try_deserialize_record, try_serialize_record, DataTypes, RecordHeader, RecordKind},
@@ -19,6 +21,7 @@ use ant_protocol::{
 };
 use bls::{PublicKey, SecretKey};
 use libp2p::kad::Record;
+use std::collections::HashSet;
 use tracing::{debug, error, trace};
 
 pub use ant_protocol::storage::{Pointer, PointerAddress, PointerTarget};
@@ -27,7 +30,9 @@ pub use ant_protocol::storage::{Pointer, PointerAddress, PointerTarget};
 #[derive(Debug, thiserror::Error)]
 pub enum PointerError {
     #[error("Network error")]
-    Network(#[from] NetworkError),
+    Network(NetworkError),
+    #[error(transparent)]
+    QuorumFailed(#[from] QuorumFailure),
     #[error("Serialization error")]
     Serialization,
     #[error("Pointer record corrupt: {0}")]
@@ -44,6 +49,28 @@ pub enum PointerError {
     PointerAlreadyExists(PointerAddress),
     #[error("Pointer cannot be updated as it does not exist, please create it first or wait for it to be created")]
     CannotUpdateNewPointer,
+    #[error("Pointer chain contains a cycle: {0:?}")]
+    Cycle(Vec<PointerAddress>),
+    #[error("Pointer chain is longer than the maximum depth of {0}")]
+    MaxDepthExceeded(usize),
+}
+
+impl From<NetworkError> for PointerError {
+    fn from(err: NetworkError) -> Self {
+        match QuorumFailure::try_from(err) {
+            Ok(failure) => PointerError::QuorumFailed(failure),
+            Err(err) => PointerError::Network(err),
+        }
+    }
+}
+
+/// The result of following a chain of pointers with [`Client::pointer_resolve`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointerResolution {
+    /// The pointers that were followed, starting with the one at the requested address
+    pub path: Vec<Pointer>,
+    /// The final target of the chain, this is never a [`PointerTarget::PointerAddress`]
+    pub target: PointerTarget,
 }
 
 impl Client {
@@ -82,6 +109,46 @@ impl Client {
         Ok(pointer)
     }
 
+    /// Follow a chain of pointers from the given address until a target that is not a pointer is found.
+    ///
+    /// At most `max_depth` pointers are fetched. Returns an error if the chain loops back on itself
+    /// or if it is longer than `max_depth`.
+    pub async fn pointer_resolve(
+        &self,
+        address: &PointerAddress,
+        max_depth: usize,
+    ) -> Result<PointerResolution, PointerError> {
+        let mut path: Vec<Pointer> = Vec::new();
+        let mut visited = HashSet::new();
+        let mut current = *address;
+
+        loop {
+            if !visited.insert(current) {
+                let mut cycle: Vec<PointerAddress> = path.iter().map(|p| p.address()).collect();
+                cycle.push(current);
+                error!("Pointer cycle detected while resolving {address:?}: {cycle:?}");
+                return Err(PointerError::Cycle(cycle));
+            }
+            if path.len() >= max_depth {
+                error!("Pointer chain from {address:?} exceeds max depth of {max_depth}");
+                return Err(PointerError::MaxDepthExceeded(max_depth));
+            }
+
+            debug!(
+                "Resolving pointer chain from {address:?}, hop {}: {current:?}",
+                path.len()
+            );
+            let pointer = self.pointer_get(&current).await?;
+            let target = pointer.target().clone();
+            path.push(pointer);
+
+            match target {
+                PointerTarget::PointerAddress(next) => current = next,
+                target => return Ok(PointerResolution { path, target }),
+            }
+        }
+    }
+
     /// Check if a pointer exists on the network
     pub async fn pointer_check_existance(
         &self,
@@ -143,12 +210,23 @@ impl Client {
         };
         let total_cost = *price;
 
+        self.pointer_store(&pointer, proof).await?;
+        Ok((total_cost, address))
+    }
+
+    /// Store a pointer on the network, along with its proof of payment if it is new
+    async fn pointer_store(
+        &self,
+        pointer: &Pointer,
+        proof: Option<&ProofOfPayment>,
+    ) -> Result<(), PointerError> {
+        let address = pointer.address();
         let (record, payees) = if let Some(proof) = proof {
             let payees = Some(proof.payees());
             let record = Record {
                 key: NetworkAddress::from_pointer_address(address).to_record_key(),
                 value: try_serialize_record(
-                    &(proof, &pointer),
+                    &(proof, pointer),
                     RecordKind::DataWithPayment(DataTypes::Pointer),
                 )
                 .map_err(|_| PointerError::Serialization)?
@@ -160,7 +238,7 @@ impl Client {
         } else {
             let record = Record {
                 key: NetworkAddress::from_pointer_address(address).to_record_key(),
-                value: try_serialize_record(&pointer, RecordKind::DataOnly(DataTypes::Pointer))
+                value: try_serialize_record(pointer, RecordKind::DataOnly(DataTypes::Pointer))
                     .map_err(|_| PointerError::Serialization)?
                     .to_vec(),
                 publisher: None,
@@ -178,8 +256,7 @@ impl Client {
             .inspect_err(|err| {
                 error!("Failed to put record - pointer {address:?} to the network: {err}")
             })?;
-
-        Ok((total_cost, address))
+        Ok(())
     }
 
     /// Create a new pointer on the network.
@@ -201,6 +278,84 @@ impl Client {
         self.pointer_put(pointer, payment_option).await
     }
 
+    /// Create many pointers on the network at once.
+    ///
+    /// The pointers are quoted concurrently and paid for at once, in as few transactions as possible,
+    /// then uploaded in parallel. This is much cheaper in gas and faster than calling [`Client::pointer_create`] for each pointer.
+    /// Returns the result of each pointer creation, in the order of `pointers`. Pointers that already exist
+    /// fail with [`PointerError::PointerAlreadyExists`] and the ones left out of the payment with
+    /// [`PayError::MissingPayment`], the whole batch fails if the payment fails.
+    pub async fn pointer_create_batch(
+        &self,
+        pointers: Vec<(SecretKey, PointerTarget)>,
+        payment_option: PaymentOption,
+    ) -> Result<Vec<Result<(AttoTokens, PointerAddress), PointerError>>, PointerError> {
+        let pointers: Vec<Pointer> = pointers
+            .into_iter()
+            .map(|(owner, target)| Pointer::new(&owner, 0, target))
+            .collect();
+        debug!("Creating a batch of {} pointers", pointers.len());
+
+        let existence = process_ordered_tasks_with_max_concurrency(
+            pointers.iter().map(|pointer| {
+                let address = pointer.address();
+                async move { self.pointer_check_existance(&address).await }
+            }),
+            *CHUNK_UPLOAD_BATCH_SIZE,
+        )
+        .await;
+
+        // only pay for the new pointers, once each
+        let mut results: Vec<Option<Result<(AttoTokens, PointerAddress), PointerError>>> =
+            pointers.iter().map(|_| None).collect();
+        let mut seen = HashSet::new();
+        let mut to_pay = vec![];
+        for (i, (pointer, exists)) in pointers.iter().zip(existence).enumerate() {
+            let address = pointer.address();
+            match exists {
+                Ok(false) if seen.insert(address) => to_pay.push(i),
+                Ok(_) => results[i] = Some(Err(PointerError::PointerAlreadyExists(address))),
+                Err(err) => results[i] = Some(Err(err)),
+            }
+        }
+
+        if !to_pay.is_empty() {
+            let (receipt, _skipped_payments) = self
+                .pay_for_content_addrs(
+                    DataTypes::Pointer,
+                    to_pay
+                        .iter()
+                        .map(|i| (*pointers[*i].address().xorname(), Pointer::size())),
+                    payment_option,
+                )
+                .await
+                .inspect_err(|err| error!("Failed to pay for batch of pointers: {err}"))?;
+
+            let uploads = process_ordered_tasks_with_max_concurrency(
+                to_pay.iter().map(|i| {
+                    let pointer = &pointers[*i];
+                    let paid = receipt.get(pointer.address().xorname());
+                    async move {
+                        let address = pointer.address();
+                        let Some((proof, price)) = paid else {
+                            error!("Pointer at address: {address:?} is missing from the payment receipt");
+                            return Err(PayError::MissingPayment(*address.xorname()).into());
+                        };
+                        self.pointer_store(pointer, Some(proof)).await?;
+                        Ok((*price, address))
+                    }
+                }),
+                *CHUNK_UPLOAD_BATCH_SIZE,
+            )
+            .await;
+            for (i, res) in to_pay.into_iter().zip(uploads) {
+                results[i] = Some(res);
+            }
+        }
+
+        Ok(results.into_iter().flatten().collect())
+    }
+
     /// Update an existing pointer to point to a new target on the network.
     ///
     /// The pointer needs to be created first with [`Client::pointer_put`].
@@ -0,0 +1,753 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::data_types::chunk::CHUNK_UPLOAD_BATCH_SIZE;
+use crate::client::payment::{PayError, PaymentOption};
+use crate::client::utils::process_ordered_tasks_with_max_concurrency;
+use crate::{
+    client::{quote::CostError, QuorumFailure},
+    Client,
+};
+use crate::{Amount, AttoTokens};
+use ant_evm::ProofOfPayment;
+use ant_networking::{GetRecordError, NetworkError, PutRecordOutcome};
+use ant_protocol::messages::PutRecordRejection;
+use ant_protocol::storage::{try_serialize_record, RecordKind};
+use ant_protocol::{
+    storage::{try_deserialize_record, DataTypes},
+    NetworkAddress,
+};
+use libp2p::kad::Record;
+use std::collections::HashSet;
+use xor_name::XorName;
+
+mod shared;
+
+pub use shared::SharedScratchpad;
+
+pub use crate::Bytes;
+pub use ant_protocol::storage::{Scratchpad, ScratchpadAddress};
+pub use bls::{PublicKey, SecretKey, Signature};
+
+const SCRATCHPAD_MAX_SIZE: usize = Scratchpad::MAX_SIZE;
+
+/// Errors that can occur when dealing with Scratchpads
+#[derive(Debug, thiserror::Error)]
+pub enum ScratchpadError {
+    #[error("Payment failure occurred during scratchpad creation.")]
+    Pay(Box<PayError>),
+    #[error("Scratchpad found at {0:?} was not a valid record.")]
+    CouldNotDeserializeScratchPad(ScratchpadAddress),
+    #[error("Network: {0}")]
+    Network(Box<NetworkError>),
+    #[error(transparent)]
+    QuorumFailed(Box<QuorumFailure>),
+    #[error("Scratchpad not found")]
+    Missing,
+    #[error("Serialization error")]
+    Serialization,
+    #[error("Scratchpad already exists at this address: {0:?}")]
+    ScratchpadAlreadyExists(ScratchpadAddress),
+    #[error("Scratchpad cannot be updated as it does not exist, please create it first or wait for it to be created")]
+    CannotUpdateNewScratchpad,
+    #[error("Scratchpad size is too big: {0} > {SCRATCHPAD_MAX_SIZE}")]
+    ScratchpadTooBig(usize),
+    #[error("Scratchpad signature is not valid")]
+    BadSignature,
+    #[error("Scratchpad at {address:?} was updated concurrently, expected counter {expected} but found {found}")]
+    Conflict {
+        address: ScratchpadAddress,
+        expected: u64,
+        found: u64,
+    },
+    #[error("Failed to decrypt scratchpad: {0}")]
+    Decryption(ant_protocol::Error),
+    #[error("Scratchpad update gave up after {0} conflicting attempts")]
+    TooManyConflicts(usize),
+    #[error("Scratchpad at {0:?} is not a shared scratchpad")]
+    NotShared(ScratchpadAddress),
+    #[error("Key {0:?} is not a reader of this shared scratchpad")]
+    NotAReader(PublicKey),
+}
+
+impl ScratchpadError {
+    /// Whether the scratchpad was not found on the network
+    pub(crate) fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            ScratchpadError::Network(err)
+                if matches!(**err, NetworkError::GetRecordError(GetRecordError::RecordNotFound))
+        )
+    }
+}
+
+impl From<PayError> for ScratchpadError {
+    fn from(err: PayError) -> Self {
+        ScratchpadError::Pay(Box::new(err))
+    }
+}
+
+impl From<NetworkError> for ScratchpadError {
+    fn from(err: NetworkError) -> Self {
+        match QuorumFailure::try_from(err) {
+            Ok(failure) => ScratchpadError::QuorumFailed(Box::new(failure)),
+            Err(err) => ScratchpadError::Network(Box::new(err)),
+        }
+    }
+}
+
+impl From<QuorumFailure> for ScratchpadError {
+    fn from(failure: QuorumFailure) -> Self {
+        ScratchpadError::QuorumFailed(Box::new(failure))
+    }
+}
+
+impl Client {
+    /// Get Scratchpad from the Network.
+    /// A Scratchpad is stored at the owner's public key so we can derive the address from it.
+    pub async fn scratchpad_get_from_public_key(
+        &self,
+        public_key: &PublicKey,
+    ) -> Result<Scratchpad, ScratchpadError> {
+        let address = ScratchpadAddress::new(*public_key);
+        self.scratchpad_get(&address).await
+    }
+
+    /// Get Scratchpad from the Network
+    pub async fn scratchpad_get(
+        &self,
+        address: &ScratchpadAddress,
+    ) -> Result<Scratchpad, ScratchpadError> {
+        let latest_pads = self.scratchpad_get_latest_versions(address).await?;
+
+        // make sure we only have one of latest version
+        let pad = match &latest_pads[..] {
+            [one] => one,
+            [multi, ..] => {
+                error!("Got multiple conflicting scratchpads for {address:?} with the latest version, returning the first one");
+                multi
+            }
+            [] => {
+                error!("Got empty scratchpad vector for {address:?}");
+                return Err(ScratchpadError::Missing);
+            }
+        };
+
+        Self::scratchpad_verify(pad)?;
+        Ok(pad.to_owned())
+    }
+
+    /// Fetch all the copies of a Scratchpad with the highest counter found on the Network
+    /// There can be more than one if the Scratchpad was updated concurrently
+    async fn scratchpad_get_latest_versions(
+        &self,
+        address: &ScratchpadAddress,
+    ) -> Result<Vec<Scratchpad>, ScratchpadError> {
+        let network_address = NetworkAddress::from_scratchpad_address(*address);
+        info!("Fetching scratchpad from network at {network_address:?}",);
+        let scratch_key = network_address.to_record_key();
+        let get_cfg = self.config.scratchpad.get_cfg();
+        match self
+            .network
+            .get_record_from_network(scratch_key.clone(), &get_cfg)
+            .await
+        {
+            Ok(record) => {
+                debug!("Got scratchpad for {scratch_key:?}");
+                let pad = try_deserialize_record::<Scratchpad>(&record)
+                    .map_err(|_| ScratchpadError::CouldNotDeserializeScratchPad(*address))?;
+                Ok(vec![pad])
+            }
+            Err(NetworkError::GetRecordError(GetRecordError::SplitRecord { result_map })) => {
+                debug!("Got multiple scratchpads for {scratch_key:?}");
+                let mut pads = result_map
+                    .values()
+                    .map(|(record, _)| try_deserialize_record::<Scratchpad>(record))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| ScratchpadError::CouldNotDeserializeScratchPad(*address))?;
+
+                // take the latest versions
+                pads.sort_by_key(|s| s.counter());
+                let max_version = pads.last().map(|p| p.counter()).unwrap_or_else(|| {
+                    error!("Got empty scratchpad vector for {scratch_key:?}");
+                    u64::MAX
+                });
+                let latest_pads: Vec<_> = pads
+                    .into_iter()
+                    .filter(|s| s.counter() == max_version)
+                    .collect();
+                Ok(latest_pads)
+            }
+            Err(e) => {
+                warn!("Failed to fetch scratchpad {network_address:?} from network: {e}");
+                Err(e)?
+            }
+        }
+    }
+
+    /// Check if a scratchpad exists on the network
+    pub async fn scratchpad_check_existance(
+        &self,
+        address: &ScratchpadAddress,
+    ) -> Result<bool, ScratchpadError> {
+        let key = NetworkAddress::from_scratchpad_address(*address).to_record_key();
+        debug!("Checking scratchpad existance at: {key:?}");
+        let get_cfg = self.config.scratchpad.verification_cfg();
+        match self
+            .network
+            .get_record_from_network(key.clone(), &get_cfg)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(NetworkError::GetRecordError(GetRecordError::SplitRecord { .. })) => Ok(true),
+            Err(NetworkError::GetRecordError(GetRecordError::RecordNotFound)) => Ok(false),
+            Err(err) => Err(ScratchpadError::Network(Box::new(err)))
+                .inspect_err(|err| error!("Error checking scratchpad existance: {err:?}")),
+        }
+    }
+
+    /// Verify a scratchpad
+    pub fn scratchpad_verify(scratchpad: &Scratchpad) -> Result<(), ScratchpadError> {
+        if !scratchpad.verify_signature() {
+            return Err(ScratchpadError::BadSignature);
+        }
+        if scratchpad.is_too_big() {
+            return Err(ScratchpadError::ScratchpadTooBig(scratchpad.size()));
+        }
+        Ok(())
+    }
+
+    /// Manually store a scratchpad on the network
+    pub async fn scratchpad_put(
+        &self,
+        scratchpad: Scratchpad,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, ScratchpadAddress), ScratchpadError> {
+        let address = scratchpad.address();
+        Self::scratchpad_verify(&scratchpad)?;
+
+        // pay for the scratchpad
+        let xor_name = address.xorname();
+        debug!("Paying for scratchpad at address: {address:?}");
+        let (payment_proofs, _skipped_payments) = self
+            .pay_for_content_addrs(
+                DataTypes::Scratchpad,
+                std::iter::once((xor_name, scratchpad.size())),
+                payment_option,
+            )
+            .await
+            .inspect_err(|err| {
+                error!("Failed to pay for scratchpad at address: {address:?} : {err}")
+            })?;
+
+        // verify payment was successful
+        let (proof, price) = match payment_proofs.get(&xor_name) {
+            Some((proof, price)) => (Some(proof), price),
+            None => {
+                info!("Scratchpad at address: {address:?} was already paid for, update is free");
+                (None, &AttoTokens::zero())
+            }
+        };
+        let total_cost = *price;
+
+        self.scratchpad_store(&scratchpad, proof).await?;
+        Ok((total_cost, *address))
+    }
+
+    /// Store a scratchpad on the network, along with its proof of payment if it is new
+    async fn scratchpad_store(
+        &self,
+        scratchpad: &Scratchpad,
+        proof: Option<&ProofOfPayment>,
+    ) -> Result<(), ScratchpadError> {
+        let address = scratchpad.address();
+        let net_addr = NetworkAddress::from_scratchpad_address(*address);
+        let (record, payees) = if let Some(proof) = proof {
+            let payees = Some(proof.payees());
+            let record = Record {
+                key: net_addr.to_record_key(),
+                value: try_serialize_record(
+                    &(proof, scratchpad),
+                    RecordKind::DataWithPayment(DataTypes::Scratchpad),
+                )
+                .map_err(|_| ScratchpadError::Serialization)?
+                .to_vec(),
+                publisher: None,
+                expires: None,
+            };
+            (record, payees)
+        } else {
+            let record = Record {
+                key: net_addr.to_record_key(),
+                value: try_serialize_record(
+                    scratchpad,
+                    RecordKind::DataOnly(DataTypes::Scratchpad),
+                )
+                .map_err(|_| ScratchpadError::Serialization)?
+                .to_vec(),
+                publisher: None,
+                expires: None,
+            };
+            (record, None)
+        };
+
+        // store the scratchpad on the network
+        debug!("Storing scratchpad at address {address:?} to the network");
+        let put_cfg = self.config.scratchpad.put_cfg(payees);
+        self.network
+            .put_record(record, &put_cfg)
+            .await
+            .inspect_err(|err| {
+                error!("Failed to put record - scratchpad {address:?} to the network: {err}")
+            })?;
+        Ok(())
+    }
+
+    /// Create a new scratchpad to the network.
+    ///
+    /// Make sure that the owner key is not already used for another scratchpad as each key is associated with one scratchpad.
+    /// The data will be encrypted with the owner key before being stored on the network.
+    /// The content type is used to identify the type of data stored in the scratchpad, the choice is up to the caller.
+    ///
+    /// Returns the cost and the address of the scratchpad.
+    pub async fn scratchpad_create(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        initial_data: &Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, ScratchpadAddress), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let already_exists = self.scratchpad_check_existance(&address).await?;
+        if already_exists {
+            return Err(ScratchpadError::ScratchpadAlreadyExists(address));
+        }
+
+        let counter = 0;
+        let scratchpad = Scratchpad::new(owner, content_type, initial_data, counter);
+        self.scratchpad_put(scratchpad, payment_option).await
+    }
+
+    /// Create many scratchpads on the network at once, from their owner, content type and initial data.
+    ///
+    /// The scratchpads are quoted concurrently and paid for at once, in as few transactions as possible,
+    /// then uploaded in parallel. This is much cheaper in gas and faster than calling [`Client::scratchpad_create`] for each scratchpad.
+    /// Returns the result of each scratchpad creation, in the order of `scratchpads`. Scratchpads that already exist
+    /// fail with [`ScratchpadError::ScratchpadAlreadyExists`] and the ones left out of the payment with
+    /// [`PayError::MissingPayment`], the whole batch fails if the payment fails.
+    pub async fn scratchpad_create_batch(
+        &self,
+        scratchpads: Vec<(SecretKey, u64, Bytes)>,
+        payment_option: PaymentOption,
+    ) -> Result<Vec<Result<(AttoTokens, ScratchpadAddress), ScratchpadError>>, ScratchpadError>
+    {
+        let scratchpads: Vec<Scratchpad> = scratchpads
+            .into_iter()
+            .map(|(owner, content_type, data)| Scratchpad::new(&owner, content_type, &data, 0))
+            .collect();
+        debug!("Creating a batch of {} scratchpads", scratchpads.len());
+
+        let existence = process_ordered_tasks_with_max_concurrency(
+            scratchpads.iter().map(|scratchpad| {
+                let address = *scratchpad.address();
+                async move { self.scratchpad_check_existance(&address).await }
+            }),
+            *CHUNK_UPLOAD_BATCH_SIZE,
+        )
+        .await;
+
+        // only pay for the new valid scratchpads, once each
+        let mut results: Vec<Option<Result<(AttoTokens, ScratchpadAddress), ScratchpadError>>> =
+            scratchpads.iter().map(|_| None).collect();
+        let mut seen = HashSet::new();
+        let mut to_pay = vec![];
+        for (i, (scratchpad, exists)) in scratchpads.iter().zip(existence).enumerate() {
+            let address = *scratchpad.address();
+            let res = Self::scratchpad_verify(scratchpad).and(exists);
+            match res {
+                Ok(false) if seen.insert(address) => to_pay.push(i),
+                Ok(_) => results[i] = Some(Err(ScratchpadError::ScratchpadAlreadyExists(address))),
+                Err(err) => results[i] = Some(Err(err)),
+            }
+        }
+
+        if !to_pay.is_empty() {
+            let (receipt, _skipped_payments) = self
+                .pay_for_content_addrs(
+                    DataTypes::Scratchpad,
+                    to_pay.iter().map(|i| {
+                        let scratchpad = &scratchpads[*i];
+                        (scratchpad.address().xorname(), scratchpad.size())
+                    }),
+                    payment_option,
+                )
+                .await
+                .inspect_err(|err| error!("Failed to pay for batch of scratchpads: {err}"))?;
+
+            let uploads = process_ordered_tasks_with_max_concurrency(
+                to_pay.iter().map(|i| {
+                    let scratchpad = &scratchpads[*i];
+                    let paid = receipt.get(&scratchpad.address().xorname());
+                    async move {
+                        let address = *scratchpad.address();
+                        let Some((proof, price)) = paid else {
+                            error!("Scratchpad at address: {address:?} is missing from the payment receipt");
+                            return Err(PayError::MissingPayment(address.xorname()).into());
+                        };
+                        self.scratchpad_store(scratchpad, Some(proof)).await?;
+                        Ok((*price, address))
+                    }
+                }),
+                *CHUNK_UPLOAD_BATCH_SIZE,
+            )
+            .await;
+            for (i, res) in to_pay.into_iter().zip(uploads) {
+                results[i] = Some(res);
+            }
+        }
+
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    /// Update an existing scratchpad to the network.
+    /// The scratchpad needs to be created first with [`Client::scratchpad_create`].
+    /// This operation is free as the scratchpad was already paid for at creation.
+    /// Only the latest version of the scratchpad is kept on the Network, previous versions will be overwritten and unrecoverable.
+    ///
+    /// Concurrent updates from several writers can overwrite each other, use [`Client::scratchpad_update_if`] to detect them.
+    pub async fn scratchpad_update(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: &Bytes,
+    ) -> Result<(), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let current = self.scratchpad_get_current(&address).await?;
+
+        let scratchpad = if let Some(p) = current {
+            let version = p.counter() + 1;
+            Scratchpad::new(owner, content_type, data, version)
+        } else {
+            warn!("Scratchpad at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
+            return Err(ScratchpadError::CannotUpdateNewScratchpad);
+        };
+
+        self.scratchpad_put_update(scratchpad).await
+    }
+
+    /// Update an existing scratchpad only if its counter on the Network is still `expected_counter`.
+    ///
+    /// This is an optimistic compare-and-swap: the current counter is checked before the update, then the
+    /// signed acknowledgements and rejections of the nodes, along with a read back, make sure the update
+    /// was not beaten by a concurrent writer.
+    /// Returns [`ScratchpadError::Conflict`] if the scratchpad has moved on, in which case the caller
+    /// should re-read the scratchpad and retry. See [`Client::scratchpad_update_with`] for a helper doing that.
+    ///
+    /// Returns the new counter of the scratchpad.
+    pub async fn scratchpad_update_if(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: &Bytes,
+        expected_counter: u64,
+    ) -> Result<u64, ScratchpadError> {
+        let new_counter = expected_counter + 1;
+        let scratchpad = Scratchpad::new(owner, content_type, data, new_counter);
+        self.scratchpad_put_if(scratchpad).await
+    }
+
+    /// Store a signed scratchpad update if the counter on the Network is still the one right before it.
+    /// See [`Client::scratchpad_update_if`] for the semantics.
+    async fn scratchpad_put_if(&self, scratchpad: Scratchpad) -> Result<u64, ScratchpadError> {
+        let address = *scratchpad.address();
+        let new_counter = scratchpad.counter();
+        let expected_counter = new_counter.saturating_sub(1);
+        let current = match self.scratchpad_get_current(&address).await? {
+            Some(current) => current,
+            None => {
+                warn!("Scratchpad at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
+                return Err(ScratchpadError::CannotUpdateNewScratchpad);
+            }
+        };
+
+        if current.counter() != expected_counter {
+            warn!(
+                "Scratchpad at {address:?} has counter {} while {expected_counter} was expected, not updating",
+                current.counter()
+            );
+            return Err(ScratchpadError::Conflict {
+                address,
+                expected: expected_counter,
+                found: current.counter(),
+            });
+        }
+
+        let our_hash = scratchpad.encrypted_data_hash();
+        // Nodes only acknowledge the copy they stored and refuse any other one with the same counter, so a
+        // concurrent writer with the same counter shows up as a rejection on the nodes it reached first.
+        let is_outdated = |rejection: &PutRecordRejection| {
+            matches!(rejection, PutRecordRejection::OutdatedCounter)
+        };
+        let rejected_as_outdated =
+            match self.scratchpad_put_update_and_get_outcome(scratchpad).await {
+                Ok(outcome) => outcome.rejections.values().any(is_outdated),
+                Err(ScratchpadError::QuorumFailed(failure))
+                    if failure.rejections.values().any(is_outdated) =>
+                {
+                    true
+                }
+                Err(err) => return Err(err),
+            };
+
+        let latest = self.scratchpad_get_latest_versions(&address).await?;
+        let outcome = scratchpad_update_if_outcome(
+            address,
+            new_counter,
+            our_hash,
+            rejected_as_outdated,
+            &latest,
+        );
+        if outcome.is_ok() {
+            debug!(
+                "Scratchpad at {address:?} updated from counter {expected_counter} to {new_counter}"
+            );
+        }
+        outcome
+    }
+
+    /// Read-modify-write a scratchpad safely in the presence of concurrent writers.
+    ///
+    /// Reads and decrypts the current content, applies `update` to it and writes the result with
+    /// [`Client::scratchpad_update_if`]. If another writer got there first, the whole cycle is repeated
+    /// on the fresh content, up to `max_attempts` times.
+    ///
+    /// Returns the content that was written.
+    pub async fn scratchpad_update_with<F>(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        max_attempts: usize,
+        mut update: F,
+    ) -> Result<Bytes, ScratchpadError>
+    where
+        F: FnMut(&Bytes) -> Bytes,
+    {
+        let address = ScratchpadAddress::new(owner.public_key());
+        for attempt in 1..=max_attempts {
+            let current = match self.scratchpad_get_current(&address).await? {
+                Some(current) => current,
+                None => return Err(ScratchpadError::CannotUpdateNewScratchpad),
+            };
+            let data = current
+                .decrypt_data(owner)
+                .map_err(ScratchpadError::Decryption)?;
+            let new_data = update(&data);
+
+            match self
+                .scratchpad_update_if(owner, content_type, &new_data, current.counter())
+                .await
+            {
+                Ok(_) => return Ok(new_data),
+                Err(ScratchpadError::Conflict { found, .. }) => {
+                    info!("Conflicting update on scratchpad {address:?} (now at {found}), attempt {attempt}/{max_attempts}");
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        error!(
+            "Giving up updating scratchpad {address:?} after {max_attempts} conflicting attempts"
+        );
+        Err(ScratchpadError::TooManyConflicts(max_attempts))
+    }
+
+    /// Get the current version of a scratchpad to base an update on
+    /// Returns `None` if the scratchpad does not exist yet
+    async fn scratchpad_get_current(
+        &self,
+        address: &ScratchpadAddress,
+    ) -> Result<Option<Scratchpad>, ScratchpadError> {
+        match self.scratchpad_get(address).await {
+            Ok(scratchpad) => Ok(Some(scratchpad)),
+            Err(ScratchpadError::Network(err)) => match *err {
+                NetworkError::GetRecordError(GetRecordError::RecordNotFound) => Ok(None),
+                NetworkError::GetRecordError(GetRecordError::SplitRecord { result_map }) => {
+                    Ok(result_map
+                        .values()
+                        .filter_map(|(record, _)| try_deserialize_record::<Scratchpad>(record).ok())
+                        .max_by_key(|scratchpad: &Scratchpad| scratchpad.counter()))
+                }
+                err => Err(err.into()),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Store an already paid for scratchpad update on the network
+    async fn scratchpad_put_update(&self, scratchpad: Scratchpad) -> Result<(), ScratchpadError> {
+        self.scratchpad_put_update_and_get_outcome(scratchpad)
+            .await
+            .map(|_| ())
+    }
+
+    /// Store an already paid for scratchpad update on the network, returning how the nodes answered
+    async fn scratchpad_put_update_and_get_outcome(
+        &self,
+        scratchpad: Scratchpad,
+    ) -> Result<PutRecordOutcome, ScratchpadError> {
+        let address = *scratchpad.address();
+
+        // make sure the scratchpad is valid
+        Self::scratchpad_verify(&scratchpad)?;
+
+        // prepare the record to be stored
+        let record = Record {
+            key: NetworkAddress::from_scratchpad_address(address).to_record_key(),
+            value: try_serialize_record(&scratchpad, RecordKind::DataOnly(DataTypes::Scratchpad))
+                .map_err(|_| ScratchpadError::Serialization)?
+                .to_vec(),
+            publisher: None,
+            expires: None,
+        };
+
+        // store the scratchpad on the network
+        let put_cfg = self.config.scratchpad.put_cfg(None);
+        debug!("Updating scratchpad at address {address:?} to the network");
+        let outcome = self
+            .network
+            .put_record_and_get_outcome(record, &put_cfg)
+            .await
+            .inspect_err(|err| {
+                error!("Failed to update scratchpad at address {address:?} to the network: {err}")
+            })?;
+
+        Ok(outcome)
+    }
+
+    /// Get the cost of creating a new Scratchpad
+    pub async fn scratchpad_cost(&self, owner: &PublicKey) -> Result<AttoTokens, CostError> {
+        info!("Getting cost for scratchpad");
+        let scratch_xor = ScratchpadAddress::new(*owner).xorname();
+
+        let store_quote = self
+            .get_store_quotes(
+                DataTypes::Scratchpad,
+                std::iter::once((scratch_xor, SCRATCHPAD_MAX_SIZE)),
+            )
+            .await?;
+
+        let total_cost = AttoTokens::from_atto(
+            store_quote
+                .0
+                .values()
+                .map(|quote| quote.price())
+                .sum::<Amount>(),
+        );
+
+        Ok(total_cost)
+    }
+}
+
+/// Decide whether a compare-and-swap update to `new_counter` is the one the network kept.
+///
+/// A node refusing the update for holding the same or a higher counter means another writer may have won,
+/// so the update is then only confirmed if our copy is the latest one read back. Any other copy with our
+/// counter means we lost the race on some of the nodes.
+fn scratchpad_update_if_outcome(
+    address: ScratchpadAddress,
+    new_counter: u64,
+    our_hash: XorName,
+    rejected_as_outdated: bool,
+    latest: &[Scratchpad],
+) -> Result<u64, ScratchpadError> {
+    let found = latest.iter().map(|p| p.counter()).max().unwrap_or(0);
+    let is_ours = |p: &Scratchpad| p.encrypted_data_hash() == our_hash;
+    let beaten = latest
+        .iter()
+        .any(|p| p.counter() == new_counter && !is_ours(p));
+    let kept_ours = found == new_counter && latest.iter().any(is_ours);
+
+    if beaten || (rejected_as_outdated && !kept_ours) {
+        warn!("Scratchpad update at {address:?} to counter {new_counter} lost against a concurrent update");
+        return Err(ScratchpadError::Conflict {
+            address,
+            expected: new_counter.saturating_sub(1),
+            found: found.max(new_counter),
+        });
+    }
+    if found > new_counter {
+        // our update was acknowledged without competition, someone else updated it after us
+        warn!("Scratchpad at {address:?} was already updated to {found} after our update to {new_counter}");
+    }
+    Ok(new_counter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scratchpad_update_if_outcome() {
+        let owner = SecretKey::random();
+        let address = ScratchpadAddress::new(owner.public_key());
+        let ours = Scratchpad::new(&owner, 0, &Bytes::from("ours"), 1);
+        let theirs = Scratchpad::new(&owner, 0, &Bytes::from("theirs"), 1);
+        let their_next = Scratchpad::new(&owner, 0, &Bytes::from("theirs again"), 2);
+        let our_hash = ours.encrypted_data_hash();
+
+        // acknowledged without competition
+        let outcome =
+            scratchpad_update_if_outcome(address, 1, our_hash, false, std::slice::from_ref(&ours));
+        assert!(matches!(outcome, Ok(1)));
+
+        // acknowledged without competition, then superseded by a later update
+        let outcome = scratchpad_update_if_outcome(address, 1, our_hash, false, &[their_next]);
+        assert!(matches!(outcome, Ok(1)));
+
+        // a node refused our copy because it already held ours, e.g. after a lost acknowledgement
+        let outcome =
+            scratchpad_update_if_outcome(address, 1, our_hash, true, std::slice::from_ref(&ours));
+        assert!(matches!(outcome, Ok(1)));
+
+        // another copy with our counter was kept by some nodes
+        let outcome = scratchpad_update_if_outcome(address, 1, our_hash, false, &[ours, theirs]);
+        assert!(matches!(
+            outcome,
+            Err(ScratchpadError::Conflict {
+                expected: 0,
+                found: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_scratchpad_update_if_lost_race() {
+        let owner = SecretKey::random();
+        let address = ScratchpadAddress::new(owner.public_key());
+        let ours = Scratchpad::new(&owner, 0, &Bytes::from("ours"), 1);
+        let their_next = Scratchpad::new(&owner, 0, &Bytes::from("theirs again"), 2);
+
+        // another writer won counter 1 and then wrote counter 2, our copy was refused as outdated
+        let outcome = scratchpad_update_if_outcome(
+            address,
+            1,
+            ours.encrypted_data_hash(),
+            true,
+            &[their_next],
+        );
+        assert!(matches!(
+            outcome,
+            Err(ScratchpadError::Conflict {
+                expected: 0,
+                found: 2,
+                ..
+            })
+        ));
+    }
+}
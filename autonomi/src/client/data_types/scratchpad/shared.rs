@@ -0,0 +1,318 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::{Bytes, PublicKey, Scratchpad, ScratchpadAddress, ScratchpadError, SecretKey};
+use crate::client::payment::PaymentOption;
+use crate::{AttoTokens, Client};
+use bls::Ciphertext;
+use serde::{Deserialize, Serialize};
+
+/// The content of a shared Scratchpad, readable by a list of readers without giving them the owner's key.
+///
+/// Scratchpads created with [`Scratchpad::new`] are encrypted to the owner, so only the owner can read them.
+/// A shared Scratchpad instead encrypts its content with a separate read key, and stores that read key
+/// encrypted (wrapped) for the owner and for each reader's [`PublicKey`].
+/// Revoking a reader rotates the read key so that the revoked reader cannot read later versions.
+///
+/// The list of reader public keys is visible to anyone who can fetch the Scratchpad.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedScratchpad {
+    /// Public part of the read key, the content is encrypted to it
+    read_key: PublicKey,
+    /// The read key, encrypted to the owner and to each reader
+    wrapped_keys: Vec<(PublicKey, Bytes)>,
+    /// The content, encrypted to the read key
+    encrypted_content: Bytes,
+}
+
+impl SharedScratchpad {
+    /// Encrypt the content with the given read key and wrap the read key for the owner and readers
+    fn new(owner: &PublicKey, read_key: &SecretKey, readers: &[PublicKey], data: &Bytes) -> Self {
+        let mut holders = vec![*owner];
+        for reader in readers {
+            if !holders.contains(reader) {
+                holders.push(*reader);
+            }
+        }
+        let wrapped_keys = holders
+            .into_iter()
+            .map(|pk| {
+                let wrapped = pk.encrypt(read_key.to_bytes()).to_bytes();
+                (pk, Bytes::from(wrapped))
+            })
+            .collect();
+
+        Self {
+            read_key: read_key.public_key(),
+            wrapped_keys,
+            encrypted_content: Bytes::from(read_key.public_key().encrypt(data).to_bytes()),
+        }
+    }
+
+    /// Parse the content of a shared Scratchpad
+    pub fn from_scratchpad(scratchpad: &Scratchpad) -> Result<Self, ScratchpadError> {
+        rmp_serde::from_slice(scratchpad.encrypted_data())
+            .map_err(|_| ScratchpadError::NotShared(*scratchpad.address()))
+    }
+
+    /// The readers of this Scratchpad, not including the owner
+    pub fn readers(&self, owner: &PublicKey) -> Vec<PublicKey> {
+        self.wrapped_keys
+            .iter()
+            .map(|(pk, _)| *pk)
+            .filter(|pk| pk != owner)
+            .collect()
+    }
+
+    /// Decrypt the content with the key of a reader or of the owner
+    pub fn decrypt(&self, reader: &SecretKey) -> Result<Bytes, ScratchpadError> {
+        let read_key = self.unwrap_read_key(reader)?;
+        let cipher = Ciphertext::from_bytes(&self.encrypted_content).map_err(|_| {
+            ScratchpadError::Decryption(ant_protocol::Error::ScratchpadCipherTextFailed)
+        })?;
+        let data = read_key
+            .decrypt(&cipher)
+            .ok_or(ScratchpadError::Decryption(
+                ant_protocol::Error::ScratchpadCipherTextInvalid,
+            ))?;
+        Ok(Bytes::from(data))
+    }
+
+    /// Recover the read key using the key of a reader or of the owner
+    fn unwrap_read_key(&self, reader: &SecretKey) -> Result<SecretKey, ScratchpadError> {
+        let reader_pk = reader.public_key();
+        let (_, wrapped) = self
+            .wrapped_keys
+            .iter()
+            .find(|(pk, _)| *pk == reader_pk)
+            .ok_or(ScratchpadError::NotAReader(reader_pk))?;
+
+        let cipher = Ciphertext::from_bytes(wrapped).map_err(|_| {
+            ScratchpadError::Decryption(ant_protocol::Error::ScratchpadCipherTextFailed)
+        })?;
+        let invalid =
+            || ScratchpadError::Decryption(ant_protocol::Error::ScratchpadCipherTextInvalid);
+        let bytes: [u8; 32] = reader
+            .decrypt(&cipher)
+            .ok_or_else(invalid)?
+            .try_into()
+            .map_err(|_| invalid())?;
+        let read_key = SecretKey::from_bytes(bytes).map_err(|_| invalid())?;
+        if read_key.public_key() != self.read_key {
+            return Err(invalid());
+        }
+        Ok(read_key)
+    }
+
+    /// Sign this content into a Scratchpad
+    fn into_scratchpad(
+        self,
+        owner: &SecretKey,
+        content_type: u64,
+        counter: u64,
+    ) -> Result<Scratchpad, ScratchpadError> {
+        let encrypted_data =
+            Bytes::from(rmp_serde::to_vec(&self).map_err(|_| ScratchpadError::Serialization)?);
+        let address = ScratchpadAddress::new(owner.public_key());
+        let signature = owner.sign(Scratchpad::bytes_for_signature(
+            address,
+            content_type,
+            &encrypted_data,
+            counter,
+        ));
+        Ok(Scratchpad::new_with_signature(
+            owner.public_key(),
+            content_type,
+            encrypted_data,
+            counter,
+            signature,
+        ))
+    }
+}
+
+impl Client {
+    /// Create a new shared scratchpad, readable by the owner and by the given readers.
+    ///
+    /// See [`SharedScratchpad`] for how access is granted.
+    /// Returns the cost and the address of the scratchpad.
+    pub async fn scratchpad_create_shared(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        initial_data: &Bytes,
+        readers: &[PublicKey],
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, ScratchpadAddress), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        if self.scratchpad_check_existance(&address).await? {
+            return Err(ScratchpadError::ScratchpadAlreadyExists(address));
+        }
+
+        let read_key = SecretKey::random();
+        let shared = SharedScratchpad::new(&owner.public_key(), &read_key, readers, initial_data);
+        let scratchpad = shared.into_scratchpad(owner, content_type, 0)?;
+        debug!(
+            "Creating shared scratchpad at {address:?} with {} readers",
+            readers.len()
+        );
+        self.scratchpad_put(scratchpad, payment_option).await
+    }
+
+    /// Fetch a shared scratchpad and decrypt it with the key of one of its readers or of its owner.
+    pub async fn scratchpad_get_shared(
+        &self,
+        address: &ScratchpadAddress,
+        reader: &SecretKey,
+    ) -> Result<Bytes, ScratchpadError> {
+        let scratchpad = self.scratchpad_get(address).await?;
+        SharedScratchpad::from_scratchpad(&scratchpad)?.decrypt(reader)
+    }
+
+    /// Get the public keys of the readers of a shared scratchpad, not including its owner.
+    pub async fn scratchpad_readers(
+        &self,
+        address: &ScratchpadAddress,
+    ) -> Result<Vec<PublicKey>, ScratchpadError> {
+        let scratchpad = self.scratchpad_get(address).await?;
+        let shared = SharedScratchpad::from_scratchpad(&scratchpad)?;
+        Ok(shared.readers(scratchpad.owner()))
+    }
+
+    /// Update the content of a shared scratchpad, keeping its current readers.
+    ///
+    /// Fails with [`ScratchpadError::Conflict`] if the scratchpad was updated concurrently.
+    /// Returns the new counter of the scratchpad.
+    pub async fn scratchpad_update_shared(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: &Bytes,
+    ) -> Result<u64, ScratchpadError> {
+        let (current, shared) = self.scratchpad_get_current_shared(owner).await?;
+        let read_key = shared.unwrap_read_key(owner)?;
+        let readers = shared.readers(&owner.public_key());
+
+        let updated = SharedScratchpad::new(&owner.public_key(), &read_key, &readers, data);
+        let scratchpad = updated.into_scratchpad(owner, content_type, current.counter() + 1)?;
+        self.scratchpad_put_if(scratchpad).await
+    }
+
+    /// Grant read access to a shared scratchpad.
+    ///
+    /// The new reader can also read the current version, which is re-published with the read key wrapped for them.
+    /// Returns the new counter of the scratchpad.
+    pub async fn scratchpad_add_reader(
+        &self,
+        owner: &SecretKey,
+        reader: PublicKey,
+    ) -> Result<u64, ScratchpadError> {
+        let (current, shared) = self.scratchpad_get_current_shared(owner).await?;
+        let read_key = shared.unwrap_read_key(owner)?;
+        let data = shared.decrypt(owner)?;
+        let mut readers = shared.readers(&owner.public_key());
+        if readers.contains(&reader) {
+            info!(
+                "{reader:?} is already a reader of scratchpad {:?}",
+                current.address()
+            );
+            return Ok(current.counter());
+        }
+        readers.push(reader);
+
+        let updated = SharedScratchpad::new(&owner.public_key(), &read_key, &readers, &data);
+        let scratchpad =
+            updated.into_scratchpad(owner, current.data_encoding(), current.counter() + 1)?;
+        self.scratchpad_put_if(scratchpad).await
+    }
+
+    /// Revoke read access to a shared scratchpad.
+    ///
+    /// The read key is rotated and the content re-encrypted, so the revoked reader cannot read this or any later version.
+    /// Versions they have already fetched remain readable to them.
+    /// Returns the new counter of the scratchpad.
+    pub async fn scratchpad_revoke_reader(
+        &self,
+        owner: &SecretKey,
+        reader: &PublicKey,
+    ) -> Result<u64, ScratchpadError> {
+        let (current, shared) = self.scratchpad_get_current_shared(owner).await?;
+        let data = shared.decrypt(owner)?;
+        let readers: Vec<_> = shared
+            .readers(&owner.public_key())
+            .into_iter()
+            .filter(|pk| pk != reader)
+            .collect();
+
+        let new_read_key = SecretKey::random();
+        let updated = SharedScratchpad::new(&owner.public_key(), &new_read_key, &readers, &data);
+        let scratchpad =
+            updated.into_scratchpad(owner, current.data_encoding(), current.counter() + 1)?;
+        debug!(
+            "Revoking {reader:?} from scratchpad {:?}, rotating its read key",
+            current.address()
+        );
+        self.scratchpad_put_if(scratchpad).await
+    }
+
+    /// Get the current version of a shared scratchpad to base an update on
+    async fn scratchpad_get_current_shared(
+        &self,
+        owner: &SecretKey,
+    ) -> Result<(Scratchpad, SharedScratchpad), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let current = match self.scratchpad_get_current(&address).await? {
+            Some(current) => current,
+            None => return Err(ScratchpadError::CannotUpdateNewScratchpad),
+        };
+        let shared = SharedScratchpad::from_scratchpad(&current)?;
+        Ok((current, shared))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_scratchpad_access() -> Result<(), ScratchpadError> {
+        let owner = SecretKey::random();
+        let reader = SecretKey::random();
+        let stranger = SecretKey::random();
+        let data = Bytes::from("what's the meaning of life the universe and everything?");
+
+        let read_key = SecretKey::random();
+        let shared = SharedScratchpad::new(
+            &owner.public_key(),
+            &read_key,
+            &[reader.public_key()],
+            &data,
+        );
+        let scratchpad = shared.into_scratchpad(&owner, 42, 0)?;
+        assert!(scratchpad.verify_signature());
+
+        let shared = SharedScratchpad::from_scratchpad(&scratchpad)?;
+        assert_eq!(
+            shared.readers(&owner.public_key()),
+            vec![reader.public_key()]
+        );
+        assert_eq!(shared.decrypt(&owner)?, data);
+        assert_eq!(shared.decrypt(&reader)?, data);
+        assert!(matches!(
+            shared.decrypt(&stranger),
+            Err(ScratchpadError::NotAReader(pk)) if pk == stranger.public_key()
+        ));
+
+        // regular scratchpads are not shared
+        let private = Scratchpad::new(&owner, 42, &data, 0);
+        assert!(matches!(
+            SharedScratchpad::from_scratchpad(&private),
+            Err(ScratchpadError::NotShared(_))
+        ));
+        Ok(())
+    }
+}
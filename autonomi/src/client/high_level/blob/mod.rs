@@ -0,0 +1,808 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::data_types::scratchpad::{Scratchpad, ScratchpadAddress, ScratchpadError};
+use crate::client::high_level::files::FILE_UPLOAD_BATCH_SIZE;
+use crate::client::key_derivation::{DerivationIndex, MainPubkey, MainSecretKey};
+use crate::client::payment::PaymentOption;
+use crate::client::quote::CostError;
+use crate::client::utils::process_tasks_with_max_concurrency;
+use crate::client::Client;
+use crate::{AttoTokens, Bytes, PublicKey, SecretKey};
+use ant_evm::U256;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use xor_name::XorName;
+
+/// Max size of content written into each Scratchpad, leaving room for the Scratchpad metadata
+pub(crate) const MAX_CONTENT_PER_SCRATCHPAD: usize = Scratchpad::MAX_SIZE - 1024;
+
+/// Max number of shards in a blob, so that the manifest always fits in a single Scratchpad
+/// This allows blobs of roughly 40GB
+const MAX_SHARDS_PER_BLOB: usize = 10_000;
+
+/// Derivation name for the Scratchpad holding the blob manifest
+const BLOB_MANIFEST_DERIVATION_NAME: &str = "blob/manifest";
+
+/// Derivation name prefix for the Scratchpads holding the blob content
+const BLOB_SHARD_DERIVATION_PREFIX: &str = "blob/shard";
+
+/// An update reserved for longer than this is considered abandoned (the writer crashed or lost its
+/// connection), and can be taken over by another writer
+const BLOB_UPDATE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// A Blob is a large piece of mutable encrypted data, split across as many [`Scratchpad`]s as needed.
+/// It is addressed at a [`BlobAddress`] derived from the owner's [`PublicKey`], so there can only be one blob per key.
+///
+/// A manifest Scratchpad keeps track of the blob's version and of the hash of each shard.
+/// Shards are written in two alternating generations: an update writes the generation that is not
+/// currently in use, then flips the manifest. Readers thus keep seeing the previous version until
+/// the update completes, and a write interrupted half way leaves the previous version intact.
+///
+/// Before writing any shard, an update reserves the next version by marking the manifest through a
+/// compare-and-swap. A concurrent writer starting from the same version fails at this point, and
+/// one starting later sees the reservation and backs off, so two writers never write the same
+/// generation at once. A reservation older than ten minutes is considered abandoned and
+/// can be taken over; a writer stalled for longer than that could still overwrite the shards of
+/// the writer taking over, in which case the shards no longer match the manifest.
+/// Shards are checked against the manifest when read, so a blob is never returned half updated:
+/// reading it fails with [`BlobError::InconsistentShard`] instead.
+///
+/// ```ignore
+/// manifest (version n) --> generation n % 2: [shard 0] [shard 1] ... [shard k]
+///                          generation (n + 1) % 2: shards of the previous version, overwritten by the next update
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BlobAddress {
+    pub owner: PublicKey,
+}
+
+impl BlobAddress {
+    /// Create a new blob address
+    pub fn new(owner: PublicKey) -> Self {
+        Self { owner }
+    }
+
+    /// Get the owner of the blob
+    pub fn owner(&self) -> PublicKey {
+        self.owner
+    }
+
+    /// Address of the underlying Scratchpad holding the blob manifest
+    pub fn manifest_address(&self) -> ScratchpadAddress {
+        let manifest_key = MainPubkey::new(self.owner)
+            .derive_key(&derivation_index(BLOB_MANIFEST_DERIVATION_NAME));
+        ScratchpadAddress::new(manifest_key.into())
+    }
+
+    /// Address of the underlying Scratchpad holding a shard of the blob content
+    fn shard_address(&self, generation: u64, index: usize) -> ScratchpadAddress {
+        let shard_key =
+            MainPubkey::new(self.owner).derive_key(&shard_derivation_index(generation, index));
+        ScratchpadAddress::new(shard_key.into())
+    }
+
+    /// Convert a blob address to a hex string
+    pub fn to_hex(&self) -> String {
+        self.owner.to_hex()
+    }
+
+    /// Convert a hex string to a blob address
+    pub fn from_hex(hex: &str) -> Result<Self, bls::Error> {
+        let owner = PublicKey::from_hex(hex)?;
+        Ok(Self { owner })
+    }
+}
+
+impl std::fmt::Display for BlobAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+/// Capacity and usage of a blob, see [`Client::blob_info`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlobInfo {
+    /// The content type given by the writer
+    pub content_type: u64,
+    /// The version of the blob, starting at 0 and incremented on each update
+    pub version: u64,
+    /// Size of the current content in bytes
+    pub size: u64,
+    /// Number of shards holding the current content
+    pub shards_used: usize,
+    /// Number of shards created so far, in both generations
+    pub shards_created: usize,
+    /// Size of the content the next update can hold without creating (and paying for) new shards
+    pub free_capacity: u64,
+    /// Stale content left in the created shards that don't hold the current content, in bytes.
+    /// This includes the previous version, see [`Client::blob_update_and_zero_fill`] to clear it.
+    pub garbage_bytes: u64,
+}
+
+/// The content of a blob, as returned by [`Client::blob_get`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobContent {
+    /// The decrypted data
+    pub data: Bytes,
+    /// The content type given by the writer
+    pub content_type: u64,
+    /// The version of the blob, starting at 0 and incremented on each update
+    pub version: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlobError {
+    #[error("Blob Scratchpad related error: {0}")]
+    Scratchpad(#[from] ScratchpadError),
+    #[error("Blob Cost related error: {0}")]
+    Cost(Box<CostError>),
+    #[error("Protocol: {0}")]
+    Protocol(#[from] ant_protocol::Error),
+    #[error("Corrupt blob manifest: {0}")]
+    Corrupt(String),
+    #[error("Blob already exists at this address: {0}")]
+    AlreadyExists(BlobAddress),
+    #[error("Blob cannot be updated as it does not exist, please create it first or wait for it to be created")]
+    CannotUpdateNewBlob,
+    #[error("Blob {0} is being updated by another writer, please try again later")]
+    UpdateInProgress(BlobAddress),
+    #[error("Blob shard {0} does not match the manifest, the blob is probably being updated, please try again")]
+    InconsistentShard(usize),
+    #[error("Blob content is too big: {0} bytes, the maximum is {max} bytes", max = MAX_SHARDS_PER_BLOB * MAX_CONTENT_PER_SCRATCHPAD)]
+    TooBig(usize),
+}
+
+impl From<CostError> for BlobError {
+    fn from(err: CostError) -> Self {
+        BlobError::Cost(Box::new(err))
+    }
+}
+
+/// The manifest of a blob, stored encrypted in its own Scratchpad
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BlobManifest {
+    /// Incremented on each update, its parity is the generation of the shards in use
+    version: u64,
+    /// The content type given by the writer
+    content_type: u64,
+    /// Total size of the content in bytes
+    size: u64,
+    /// Hashes of the content of each shard, in order
+    shards: Vec<XorName>,
+    /// Number of shards already created (and paid for) in each generation
+    allocated: [u64; 2],
+    /// Set while an update is writing the next version, to the time it started in seconds since
+    /// the UNIX epoch
+    #[serde(default)]
+    update_started_at: Option<u64>,
+}
+
+impl BlobManifest {
+    fn generation(&self) -> u64 {
+        self.version % 2
+    }
+
+    /// The created shards that don't hold the current content, as (generation, index): the ones
+    /// past the content in the current generation, and all the ones of the other generation.
+    fn unused_shards(&self) -> Vec<(u64, usize)> {
+        let current = self.generation();
+        let other = 1 - current;
+        (self.shards.len()..self.allocated[current as usize] as usize)
+            .map(|i| (current, i))
+            .chain((0..self.allocated[other as usize] as usize).map(|i| (other, i)))
+            .collect()
+    }
+}
+
+impl Client {
+    /// Fetch and decrypt a blob.
+    pub async fn blob_get(&self, owner: &SecretKey) -> Result<BlobContent, BlobError> {
+        let address = BlobAddress::new(owner.public_key());
+        let (manifest, _) = self.blob_get_manifest(owner).await?;
+        let generation = manifest.generation();
+        debug!(
+            "Fetching blob {address} version {} with {} shards",
+            manifest.version,
+            manifest.shards.len()
+        );
+
+        let fetch_futures: Vec<_> = manifest
+            .shards
+            .iter()
+            .enumerate()
+            .map(|(i, expected_hash)| {
+                let shard_key = shard_key(owner, generation, i);
+                let client = self.clone();
+                async move {
+                    let shard_addr = ScratchpadAddress::new(shard_key.public_key());
+                    let shard = client.scratchpad_get(&shard_addr).await?;
+                    let data = shard.decrypt_data(&shard_key)?;
+                    if XorName::from_content(&data) != *expected_hash {
+                        warn!("Blob {address} shard {i} at {shard_addr:?} does not match its manifest");
+                        return Err(BlobError::InconsistentShard(i));
+                    }
+                    Ok((i, data))
+                }
+            })
+            .collect();
+
+        let mut shards = process_tasks_with_max_concurrency(fetch_futures, *FILE_UPLOAD_BATCH_SIZE)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        shards.sort_by_key(|(i, _)| *i);
+
+        let data: Vec<u8> = shards
+            .into_iter()
+            .flat_map(|(_, data)| data.to_vec())
+            .collect();
+        if data.len() as u64 != manifest.size {
+            return Err(BlobError::Corrupt(format!(
+                "Blob {address} has {} bytes while its manifest expects {}",
+                data.len(),
+                manifest.size
+            )));
+        }
+
+        Ok(BlobContent {
+            data: Bytes::from(data),
+            content_type: manifest.content_type,
+            version: manifest.version,
+        })
+    }
+
+    /// Create a new blob holding the given data.
+    ///
+    /// Each key can only own one blob, see [`BlobAddress`].
+    /// Returns the cost and the address of the blob.
+    pub async fn blob_create(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, BlobAddress), BlobError> {
+        let address = BlobAddress::new(owner.public_key());
+        if self
+            .scratchpad_check_existance(&address.manifest_address())
+            .await?
+        {
+            return Err(BlobError::AlreadyExists(address));
+        }
+
+        let contents = split_bytes(data.clone());
+        if contents.len() > MAX_SHARDS_PER_BLOB {
+            return Err(BlobError::TooBig(data.len()));
+        }
+        info!(
+            "Creating blob {address} of {} bytes in {} shards",
+            data.len(),
+            contents.len()
+        );
+
+        let mut manifest = BlobManifest {
+            version: 0,
+            content_type,
+            size: data.len() as u64,
+            shards: contents.iter().map(|c| XorName::from_content(c)).collect(),
+            allocated: [0, 0],
+            update_started_at: None,
+        };
+        let shards_cost = self
+            .blob_write_shards(owner, &mut manifest, contents, payment_option.clone())
+            .await?;
+
+        let manifest_key = manifest_key(owner);
+        let manifest_bytes = serialize_manifest(&manifest)
+            .map_err(|e| BlobError::Corrupt(format!("Failed to serialize manifest: {e}")))?;
+        let (manifest_cost, _) = self
+            .scratchpad_create(&manifest_key, content_type, &manifest_bytes, payment_option)
+            .await?;
+
+        let total_cost = AttoTokens::from_atto(shards_cost.as_atto() + manifest_cost.as_atto());
+        debug!("Created blob {address} for {total_cost}");
+        Ok((total_cost, address))
+    }
+
+    /// Replace the content of an existing blob.
+    ///
+    /// Updates are free unless the new content needs more shards than were ever used before.
+    /// If another writer updated the blob concurrently, this fails with [`ScratchpadError::Conflict`],
+    /// or with [`BlobError::UpdateInProgress`] if its update is still running.
+    /// Returns the cost of the update.
+    pub async fn blob_update(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, BlobError> {
+        self.blob_update_inner(owner, content_type, data, payment_option, false)
+            .await
+    }
+
+    /// Replace the content of an existing blob, like [`Client::blob_update`], then overwrite the
+    /// shards that don't hold the new content with empty content.
+    ///
+    /// Use this when the content shrinks or must not be kept around, so that no stale data from
+    /// previous versions is left on the Network. Overwriting existing shards is free, the capacity
+    /// paid for is kept for later updates.
+    pub async fn blob_update_and_zero_fill(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, BlobError> {
+        self.blob_update_inner(owner, content_type, data, payment_option, true)
+            .await
+    }
+
+    async fn blob_update_inner(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: Bytes,
+        payment_option: PaymentOption,
+        zero_fill: bool,
+    ) -> Result<AttoTokens, BlobError> {
+        let address = BlobAddress::new(owner.public_key());
+        let (previous, manifest_counter) = self.blob_get_manifest(owner).await?;
+        if let Some(started_at) = previous.update_started_at {
+            let age = now().saturating_sub(started_at);
+            if age < BLOB_UPDATE_TIMEOUT.as_secs() {
+                warn!("Blob {address} is being updated since {age}s, not updating");
+                return Err(BlobError::UpdateInProgress(address));
+            }
+            warn!("Blob {address} has an update started {age}s ago which was abandoned, taking it over");
+        }
+
+        let contents = split_bytes(data.clone());
+        if contents.len() > MAX_SHARDS_PER_BLOB {
+            return Err(BlobError::TooBig(data.len()));
+        }
+        info!(
+            "Updating blob {address} to version {} with {} bytes in {} shards",
+            previous.version + 1,
+            data.len(),
+            contents.len()
+        );
+
+        // reserve the next version before writing any shard, failing if someone else did it first
+        let manifest_key = manifest_key(owner);
+        let mut reserved = previous.clone();
+        reserved.update_started_at = Some(now());
+        let mut counter = self
+            .blob_put_manifest(&manifest_key, &reserved, manifest_counter)
+            .await?;
+
+        let mut manifest = BlobManifest {
+            version: previous.version + 1,
+            content_type,
+            size: data.len() as u64,
+            shards: contents.iter().map(|c| XorName::from_content(c)).collect(),
+            allocated: previous.allocated,
+            // still reserved while the unused shards are zero-filled
+            update_started_at: zero_fill.then(now),
+        };
+        let total_cost = match self
+            .blob_write_shards(owner, &mut manifest, contents, payment_option)
+            .await
+        {
+            Ok(cost) => cost,
+            Err(err) => {
+                // release the reservation, so that the blob can be updated again right away
+                if let Err(release_err) = self
+                    .blob_put_manifest(&manifest_key, &previous, counter)
+                    .await
+                {
+                    warn!(
+                        "Failed to release the update reservation of blob {address}: {release_err}"
+                    );
+                }
+                return Err(err);
+            }
+        };
+
+        // flip the manifest to the new generation
+        counter = self
+            .blob_put_manifest(&manifest_key, &manifest, counter)
+            .await?;
+
+        if zero_fill {
+            let zero_fill_result = self.blob_zero_fill_unused(owner, &manifest).await;
+            manifest.update_started_at = None;
+            let _ = self
+                .blob_put_manifest(&manifest_key, &manifest, counter)
+                .await?;
+            zero_fill_result?;
+        }
+
+        debug!(
+            "Updated blob {address} to version {} for {total_cost}",
+            manifest.version
+        );
+        Ok(total_cost)
+    }
+
+    /// Get the capacity and usage of a blob.
+    ///
+    /// The shards that don't hold the current content are fetched to measure the stale data they
+    /// hold, so this is about as costly as fetching the blob.
+    pub async fn blob_info(&self, owner: &SecretKey) -> Result<BlobInfo, BlobError> {
+        let address = BlobAddress::new(owner.public_key());
+        let (manifest, _) = self.blob_get_manifest(owner).await?;
+        debug!(
+            "Getting info of blob {address} version {}",
+            manifest.version
+        );
+
+        let fetch_futures: Vec<_> = manifest
+            .unused_shards()
+            .into_iter()
+            .map(|(generation, i)| {
+                let shard_key = shard_key(owner, generation, i);
+                let client = self.clone();
+                async move {
+                    let shard_addr = ScratchpadAddress::new(shard_key.public_key());
+                    match client.scratchpad_get(&shard_addr).await {
+                        Ok(shard) => Ok(shard.decrypt_data(&shard_key)?.len() as u64),
+                        // left missing by an interrupted write
+                        Err(err) if err.is_not_found() => Ok(0),
+                        Err(err) => Err(BlobError::from(err)),
+                    }
+                }
+            })
+            .collect();
+        let mut garbage_bytes = 0;
+        for result in
+            process_tasks_with_max_concurrency(fetch_futures, *FILE_UPLOAD_BATCH_SIZE).await
+        {
+            garbage_bytes += result?;
+        }
+
+        let next_generation = 1 - manifest.generation() as usize;
+        Ok(BlobInfo {
+            content_type: manifest.content_type,
+            version: manifest.version,
+            size: manifest.size,
+            shards_used: manifest.shards.len(),
+            shards_created: (manifest.allocated[0] + manifest.allocated[1]) as usize,
+            free_capacity: manifest.allocated[next_generation] * MAX_CONTENT_PER_SCRATCHPAD as u64,
+            garbage_bytes,
+        })
+    }
+
+    /// Get the cost of a blob that can hold up to `max_size` bytes.
+    ///
+    /// This accounts for both shard generations, so that the blob can then be updated for free with content up to `max_size`.
+    /// For an existing blob, this is the cost of the shards still missing to do so, i.e. zero unless the blob has to grow.
+    pub async fn blob_cost(
+        &self,
+        owner: &PublicKey,
+        max_size: u64,
+    ) -> Result<AttoTokens, BlobError> {
+        let address = BlobAddress::new(*owner);
+        info!("Getting cost for blob {address} of up to {max_size} bytes");
+        let num_of_shards = max_size.div_ceil(MAX_CONTENT_PER_SCRATCHPAD as u64) as usize;
+
+        let manifest_cost = self
+            .scratchpad_cost(address.manifest_address().owner())
+            .await?;
+        if !manifest_cost.is_zero() {
+            let total_cost =
+                manifest_cost.as_atto() + U256::from(2 * num_of_shards) * manifest_cost.as_atto();
+            return Ok(AttoTokens::from_atto(total_cost));
+        }
+
+        // Has been created, only the shards missing to hold `max_size` bytes are paid for
+        let mut missing = vec![];
+        for generation in 0..2 {
+            let created = self
+                .blob_created_shards(&address, generation, num_of_shards)
+                .await?;
+            missing.extend((created..num_of_shards).map(|i| address.shard_address(generation, i)));
+        }
+        let Some(first_missing) = missing.first() else {
+            return Ok(AttoTokens::zero());
+        };
+        let shard_cost = self.scratchpad_cost(first_missing.owner()).await?;
+        let total_cost = U256::from(missing.len()) * shard_cost.as_atto();
+        Ok(AttoTokens::from_atto(total_cost))
+    }
+
+    /// Number of shards created in the generation, among the first `max` ones.
+    /// Shards are created in order, so the created ones are found by a binary search.
+    async fn blob_created_shards(
+        &self,
+        address: &BlobAddress,
+        generation: u64,
+        max: usize,
+    ) -> Result<usize, BlobError> {
+        // the shards before `low` exist, the ones from `high` on don't
+        let (mut low, mut high) = (0, max);
+        while low < high {
+            let mid = (low + high) / 2;
+            if self
+                .scratchpad_check_existance(&address.shard_address(generation, mid))
+                .await?
+            {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+
+    /// Overwrite the unused shards of the manifest with empty content, skipping the ones already empty
+    async fn blob_zero_fill_unused(
+        &self,
+        owner: &SecretKey,
+        manifest: &BlobManifest,
+    ) -> Result<(), BlobError> {
+        let content_type = manifest.content_type;
+        let zero_fill_futures: Vec<_> = manifest
+            .unused_shards()
+            .into_iter()
+            .map(|(generation, i)| {
+                let shard_key = shard_key(owner, generation, i);
+                let client = self.clone();
+                async move {
+                    let shard_addr = ScratchpadAddress::new(shard_key.public_key());
+                    let shard = match client.scratchpad_get(&shard_addr).await {
+                        Ok(shard) => shard,
+                        Err(err) if err.is_not_found() => return Ok(false),
+                        Err(err) => return Err(BlobError::from(err)),
+                    };
+                    if shard.decrypt_data(&shard_key)?.is_empty() {
+                        return Ok(false);
+                    }
+                    client
+                        .scratchpad_update(&shard_key, content_type, &Bytes::new())
+                        .await?;
+                    Ok(true)
+                }
+            })
+            .collect();
+
+        let mut num_of_cleared = 0;
+        for result in
+            process_tasks_with_max_concurrency(zero_fill_futures, *FILE_UPLOAD_BATCH_SIZE).await
+        {
+            if result? {
+                num_of_cleared += 1;
+            }
+        }
+        info!("Zero-filled {num_of_cleared} unused blob shards");
+        Ok(())
+    }
+
+    /// Store the manifest if the counter of its Scratchpad is still `expected_counter`, returning the new counter
+    async fn blob_put_manifest(
+        &self,
+        manifest_key: &SecretKey,
+        manifest: &BlobManifest,
+        expected_counter: u64,
+    ) -> Result<u64, BlobError> {
+        let manifest_bytes = serialize_manifest(manifest)
+            .map_err(|e| BlobError::Corrupt(format!("Failed to serialize manifest: {e}")))?;
+        let counter = self
+            .scratchpad_update_if(
+                manifest_key,
+                manifest.content_type,
+                &manifest_bytes,
+                expected_counter,
+            )
+            .await?;
+        Ok(counter)
+    }
+
+    /// Fetch and decrypt the manifest of a blob, along with the counter of its Scratchpad
+    async fn blob_get_manifest(&self, owner: &SecretKey) -> Result<(BlobManifest, u64), BlobError> {
+        let manifest_key = manifest_key(owner);
+        let manifest_addr = ScratchpadAddress::new(manifest_key.public_key());
+        let pad = match self.scratchpad_get(&manifest_addr).await {
+            Ok(pad) => pad,
+            Err(err) if err.is_not_found() => return Err(BlobError::CannotUpdateNewBlob),
+            Err(err) => return Err(err.into()),
+        };
+        let bytes = pad.decrypt_data(&manifest_key)?;
+        let manifest: BlobManifest = rmp_serde::from_slice(&bytes)
+            .map_err(|e| BlobError::Corrupt(format!("Failed to deserialize manifest: {e}")))?;
+        Ok((manifest, pad.counter()))
+    }
+
+    /// Write the shards of the manifest's generation, creating the missing ones.
+    /// Updates the manifest's allocated shards and returns the cost of the created shards.
+    async fn blob_write_shards(
+        &self,
+        owner: &SecretKey,
+        manifest: &mut BlobManifest,
+        contents: Vec<Bytes>,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, BlobError> {
+        let generation = manifest.generation();
+        let allocated = manifest.allocated[generation as usize] as usize;
+        let content_type = manifest.content_type;
+        let num_of_shards = contents.len();
+
+        let write_futures: Vec<_> = contents
+            .into_iter()
+            .enumerate()
+            .map(|(i, content)| {
+                let shard_key = shard_key(owner, generation, i);
+                let client = self.clone();
+                let payment_option = payment_option.clone();
+                async move {
+                    if i < allocated {
+                        client
+                            .scratchpad_update(&shard_key, content_type, &content)
+                            .await?;
+                        return Ok(AttoTokens::zero());
+                    }
+                    match client
+                        .scratchpad_create(&shard_key, content_type, &content, payment_option)
+                        .await
+                    {
+                        Ok((price, _)) => Ok(price),
+                        Err(ScratchpadError::ScratchpadAlreadyExists(addr)) => {
+                            // left over from an interrupted write
+                            info!("Blob shard {i} already exists at {addr:?}, updating it");
+                            client
+                                .scratchpad_update(&shard_key, content_type, &content)
+                                .await?;
+                            Ok(AttoTokens::zero())
+                        }
+                        Err(err) => Err(BlobError::from(err)),
+                    }
+                }
+            })
+            .collect();
+
+        let results =
+            process_tasks_with_max_concurrency(write_futures, *FILE_UPLOAD_BATCH_SIZE).await;
+
+        let mut total_cost = AttoTokens::zero();
+        for result in results {
+            total_cost = AttoTokens::from_atto(total_cost.as_atto() + result?.as_atto());
+        }
+
+        let allocated = &mut manifest.allocated[generation as usize];
+        *allocated = (*allocated).max(num_of_shards as u64);
+        Ok(total_cost)
+    }
+}
+
+fn derivation_index(name: &str) -> DerivationIndex {
+    DerivationIndex::from_bytes(XorName::from_content(name.as_bytes()).0)
+}
+
+fn manifest_key(owner: &SecretKey) -> SecretKey {
+    MainSecretKey::new(owner.clone())
+        .derive_key(&derivation_index(BLOB_MANIFEST_DERIVATION_NAME))
+        .into()
+}
+
+fn shard_derivation_index(generation: u64, index: usize) -> DerivationIndex {
+    derivation_index(&format!(
+        "{BLOB_SHARD_DERIVATION_PREFIX}/{generation}/{index}"
+    ))
+}
+
+fn shard_key(owner: &SecretKey, generation: u64, index: usize) -> SecretKey {
+    MainSecretKey::new(owner.clone())
+        .derive_key(&shard_derivation_index(generation, index))
+        .into()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn serialize_manifest(manifest: &BlobManifest) -> Result<Bytes, rmp_serde::encode::Error> {
+    Ok(Bytes::from(rmp_serde::to_vec(manifest)?))
+}
+
+/// Split bytes into pieces that each fit in a Scratchpad
+pub(crate) fn split_bytes(input: Bytes) -> Vec<Bytes> {
+    let mut contents = Vec::new();
+    let mut offset = 0;
+
+    while offset < input.len() {
+        let end = (offset + MAX_CONTENT_PER_SCRATCHPAD).min(input.len());
+        contents.push(input.slice(offset..end));
+        offset = end;
+    }
+
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_bytes() {
+        assert!(split_bytes(Bytes::new()).is_empty());
+
+        let data = Bytes::from(vec![7u8; 2 * MAX_CONTENT_PER_SCRATCHPAD + 10]);
+        let shards = split_bytes(data.clone());
+        assert_eq!(shards.len(), 3);
+        assert_eq!(shards[0].len(), MAX_CONTENT_PER_SCRATCHPAD);
+        assert_eq!(shards[2].len(), 10);
+        assert_eq!(shards.concat(), data.to_vec());
+    }
+
+    #[test]
+    fn test_blob_keys_are_distinct() {
+        let owner = SecretKey::random();
+        let address = BlobAddress::new(owner.public_key());
+
+        let manifest = manifest_key(&owner).public_key();
+        assert_eq!(address.manifest_address(), ScratchpadAddress::new(manifest));
+        assert_ne!(manifest, owner.public_key());
+
+        let gen0 = shard_key(&owner, 0, 0).public_key();
+        let gen1 = shard_key(&owner, 1, 0).public_key();
+        let next = shard_key(&owner, 0, 1).public_key();
+        assert_ne!(gen0, gen1);
+        assert_ne!(gen0, next);
+        assert_ne!(gen0, manifest);
+        assert_eq!(address.shard_address(1, 0), ScratchpadAddress::new(gen1));
+    }
+
+    #[test]
+    fn test_unused_shards() {
+        let mut manifest = BlobManifest {
+            version: 3,
+            content_type: 0,
+            size: 10,
+            shards: vec![XorName::default()],
+            allocated: [2, 3],
+            update_started_at: None,
+        };
+        // version 3 is in generation 1, its 2 last shards and the ones of generation 0 are unused
+        assert_eq!(
+            manifest.unused_shards(),
+            vec![(1, 1), (1, 2), (0, 0), (0, 1)]
+        );
+
+        manifest.version = 4;
+        manifest.shards = vec![XorName::default(); 2];
+        assert_eq!(manifest.unused_shards(), vec![(1, 0), (1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_manifest_without_update_reservation() -> eyre::Result<()> {
+        // manifests written before the update reservation was introduced
+        #[derive(Serialize)]
+        struct PreviousManifest {
+            version: u64,
+            content_type: u64,
+            size: u64,
+            shards: Vec<XorName>,
+            allocated: [u64; 2],
+        }
+        let bytes = rmp_serde::to_vec(&PreviousManifest {
+            version: 1,
+            content_type: 7,
+            size: 10,
+            shards: vec![XorName::default()],
+            allocated: [1, 1],
+        })?;
+        let manifest: BlobManifest = rmp_serde::from_slice(&bytes)?;
+        assert_eq!(manifest.version, 1);
+        assert_eq!(manifest.update_started_at, None);
+        Ok(())
+    }
+}
@@ -43,6 +43,27 @@ impl Client {
         Ok(data)
     }
 
+    /// Fetch `len` bytes of a blob of (private) data from the network, starting at `position`.
+    /// Only the chunks covering the range are fetched. The result is shorter than `len` if the
+    /// range goes past the end of the data, and empty if it starts after it.
+    pub async fn data_get_range(
+        &self,
+        data_map: &DataMapChunk,
+        position: u64,
+        len: u64,
+    ) -> Result<Bytes, GetError> {
+        info!(
+            "Fetching {len} bytes at {position} of private data from Data Map {:?}",
+            data_map.0.address()
+        );
+        let data = self
+            .fetch_range_from_data_map_chunk(data_map.0.value(), position, len)
+            .await?;
+
+        debug!("Successfully fetched a range of a blob of private data from the network");
+        Ok(data)
+    }
+
     /// Upload a piece of private data to the network. This data will be self-encrypted.
     /// The [`DataMapChunk`] is not uploaded to the network, keeping the data private.
     ///
@@ -30,6 +30,25 @@ impl Client {
         Ok(data)
     }
 
+    /// Fetch `len` bytes of a blob of data from the network, starting at `position`.
+    /// Only the chunks covering the range are fetched. The result is shorter than `len` if the
+    /// range goes past the end of the data, and empty if it starts after it.
+    pub async fn data_get_public_range(
+        &self,
+        addr: &DataAddr,
+        position: u64,
+        len: u64,
+    ) -> Result<Bytes, GetError> {
+        info!("Fetching {len} bytes at {position} from Data Address: {addr:?}");
+        let data_map_chunk = self.chunk_get(&ChunkAddress::new(*addr)).await?;
+        let data = self
+            .fetch_range_from_data_map_chunk(data_map_chunk.value(), position, len)
+            .await?;
+
+        debug!("Successfully fetched a range of a blob of data from the network");
+        Ok(data)
+    }
+
     /// Upload a piece of data to the network. This data is publicly accessible.
     ///
     /// Returns the Data Address at which the data was stored.
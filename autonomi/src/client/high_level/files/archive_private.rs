@@ -38,11 +38,15 @@ pub struct PrivateArchive {
 }
 
 /// This type essentially wraps archive in version marker. E.g. in JSON format:
-/// `{ "V0": { "map": <xxx> } }`
+/// `{ "V1": { "map": <xxx> } }`
+///
+/// V1 archives have the MIME type, hash and tags fields in their [`Metadata`], which are left empty
+/// when reading V0 archives. Clients that only know V0 can't read V1 archives.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum PrivateArchiveVersioned {
     V0(PrivateArchive),
+    V1(PrivateArchive),
 }
 
 impl PrivateArchive {
@@ -110,15 +114,17 @@ impl PrivateArchive {
     /// Deserialize from bytes.
     pub fn from_bytes(data: Bytes) -> Result<PrivateArchive, rmp_serde::decode::Error> {
         let root: PrivateArchiveVersioned = rmp_serde::from_slice(&data[..])?;
-        // Currently we have only `V0`. If we add `V1`, then we need an upgrade/migration path here.
-        let PrivateArchiveVersioned::V0(root) = root;
+        // `V1` only added fields to the `Metadata`, which default to empty when reading `V0`.
+        let root = match root {
+            PrivateArchiveVersioned::V0(root) | PrivateArchiveVersioned::V1(root) => root,
+        };
 
         Ok(root)
     }
 
     /// Serialize to bytes.
     pub fn to_bytes(&self) -> Result<Bytes, rmp_serde::encode::Error> {
-        let versioned = PrivateArchiveVersioned::V0(self.clone());
+        let versioned = PrivateArchiveVersioned::V1(self.clone());
         let root_serialized = rmp_serde::to_vec_named(&versioned)?;
         let root_serialized = Bytes::from(root_serialized);
 
@@ -40,11 +40,15 @@ pub struct PublicArchive {
 }
 
 /// This type essentially wraps archive in version marker. E.g. in JSON format:
-/// `{ "V0": { "map": <xxx> } }`
+/// `{ "V1": { "map": <xxx> } }`
+///
+/// V1 archives have the MIME type, hash and tags fields in their [`Metadata`], which are left empty
+/// when reading V0 archives. Clients that only know V0 can't read V1 archives.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum PublicArchiveVersioned {
     V0(PublicArchive),
+    V1(PublicArchive),
 }
 
 impl PublicArchive {
@@ -109,15 +113,17 @@ impl PublicArchive {
     /// Deserialize from bytes.
     pub fn from_bytes(data: Bytes) -> Result<PublicArchive, rmp_serde::decode::Error> {
         let root: PublicArchiveVersioned = rmp_serde::from_slice(&data[..])?;
-        // Currently we have only `V0`. If we add `V1`, then we need an upgrade/migration path here.
-        let PublicArchiveVersioned::V0(root) = root;
+        // `V1` only added fields to the `Metadata`, which default to empty when reading `V0`.
+        let root = match root {
+            PublicArchiveVersioned::V0(root) | PublicArchiveVersioned::V1(root) => root,
+        };
 
         Ok(root)
     }
 
     /// Serialize to bytes.
     pub fn to_bytes(&self) -> Result<Bytes, rmp_serde::encode::Error> {
-        let versioned = PublicArchiveVersioned::V0(self.clone());
+        let versioned = PublicArchiveVersioned::V1(self.clone());
         let root_serialized = rmp_serde::to_vec_named(&versioned)?;
         let root_serialized = Bytes::from(root_serialized);
 
@@ -208,6 +214,7 @@ mod test {
         pub enum FuturePublicArchiveVersioned {
             V0(PublicArchive),
             V1(PublicArchive),
+            V2(PublicArchive),
             #[serde(other)]
             Unsupported,
         }
@@ -220,8 +227,8 @@ mod test {
         );
         let arch_serialized = arch.to_bytes().unwrap();
 
-        // Create archive, forward compatible (still the same V0 version).
-        let future_arch = FuturePublicArchiveVersioned::V0(arch.clone());
+        // Create archive, forward compatible (still the same V1 version).
+        let future_arch = FuturePublicArchiveVersioned::V1(arch.clone());
         let future_arch_serialized = rmp_serde::to_vec_named(&future_arch).unwrap();
 
         // Let's see if we can deserialize a (forward compatible) archive arriving to us from the future
@@ -231,13 +238,13 @@ mod test {
         let _: FuturePublicArchiveVersioned = rmp_serde::from_slice(&arch_serialized[..]).unwrap();
 
         // Now we break forward compatibility by introducing a new version not supported by the old code.
-        let future_arch = FuturePublicArchiveVersioned::V1(arch.clone());
+        let future_arch = FuturePublicArchiveVersioned::V2(arch.clone());
         let future_arch_serialized = rmp_serde::to_vec_named(&future_arch).unwrap();
         // The old archive will not be able to decode this.
         assert!(PublicArchive::from_bytes(Bytes::from(future_arch_serialized)).is_err());
 
-        // Now we prove backwards compatibility. Our old V0 archive will still be decoded by our new archive wrapper as V0.
-        let versioned_arch = PublicArchiveVersioned::V0(arch.clone()); // 'Old' archive wrapper
+        // Now we prove backwards compatibility. Our old V1 archive will still be decoded by our new archive wrapper as V1.
+        let versioned_arch = PublicArchiveVersioned::V1(arch.clone()); // 'Old' archive wrapper
         let versioned_arch_serialized = rmp_serde::to_vec_named(&versioned_arch).unwrap();
         let _: FuturePublicArchiveVersioned = // Into 'new' wrapper
             rmp_serde::from_slice(&versioned_arch_serialized[..]).unwrap();
@@ -282,4 +289,63 @@ mod test {
         // Our old data structure should be forward compatible with the new one.
         assert!(PublicArchive::from_bytes(Bytes::from(arch_p1_ser)).is_ok());
     }
+
+    #[test]
+    fn backward_compatibility_metadata() {
+        // The `Metadata` of archives created before MIME types, hashes and tags were added.
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct MetadataV0 {
+            created: u64,
+            modified: u64,
+            size: u64,
+            extra: Option<String>,
+        }
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct PublicArchiveV0 {
+            map: BTreeMap<PathBuf, (DataAddr, MetadataV0)>,
+        }
+        #[derive(Debug, Serialize, Deserialize)]
+        pub enum PublicArchiveVersionedV0 {
+            V0(PublicArchiveV0),
+        }
+
+        let mut arch_v0 = PublicArchiveV0::default();
+        arch_v0.map.insert(
+            PathBuf::from_str("hello_world").unwrap(),
+            (
+                DataAddr::random(&mut rand::thread_rng()),
+                MetadataV0 {
+                    size: 11,
+                    ..Default::default()
+                },
+            ),
+        );
+        let arch_v0_ser = rmp_serde::to_vec_named(&PublicArchiveVersionedV0::V0(arch_v0)).unwrap();
+
+        // Old archives are still readable, the new fields are left empty.
+        let arch = PublicArchive::from_bytes(Bytes::from(arch_v0_ser)).unwrap();
+        let (_, meta) = arch.map().values().next().unwrap();
+        assert_eq!(meta.size, 11);
+        assert_eq!(meta.mime_type, None);
+        assert_eq!(meta.sha256, None);
+        assert!(meta.tags.is_empty());
+        assert!(meta.verify_content(b"anything"));
+
+        // New archives are V1, which clients that only know V0 can't read.
+        let mut arch = PublicArchive::new();
+        arch.add_file(
+            PathBuf::from_str("index.html").unwrap(),
+            DataAddr::random(&mut rand::thread_rng()),
+            Metadata::new_with_size(4)
+                .with_content(Path::new("index.html"), b"<p/>")
+                .with_tag("lang", "en"),
+        );
+        let arch_ser = arch.to_bytes().unwrap();
+        assert!(rmp_serde::from_slice::<PublicArchiveVersionedV0>(&arch_ser[..]).is_err());
+        let new = PublicArchive::from_bytes(arch_ser).unwrap();
+        let (_, meta) = new.map().values().next().unwrap();
+        assert_eq!(meta.mime_type.as_deref(), Some("text/html"));
+        assert_eq!(meta.tags.get("lang").map(String::as_str), Some("en"));
+        assert!(meta.verify_content(b"<p/>"));
+    }
 }
@@ -49,10 +49,45 @@ impl Client {
         &self,
         archive_access: &PrivateArchiveAccess,
         to_dest: PathBuf,
+    ) -> Result<(), DownloadError> {
+        self.dir_download_inner(archive_access, to_dest, false)
+            .await
+    }
+
+    /// Same as [`Client::dir_download`], but checks the content of each file against the SHA-256 hash stored in the archive.
+    /// Files without a hash in the archive are not checked. Fails with [`DownloadError::HashMismatch`] on the first
+    /// file that does not match, before writing it.
+    pub async fn dir_download_verified(
+        &self,
+        archive_access: &PrivateArchiveAccess,
+        to_dest: PathBuf,
+    ) -> Result<(), DownloadError> {
+        self.dir_download_inner(archive_access, to_dest, true).await
+    }
+
+    async fn dir_download_inner(
+        &self,
+        archive_access: &PrivateArchiveAccess,
+        to_dest: PathBuf,
+        verify: bool,
     ) -> Result<(), DownloadError> {
         let archive = self.archive_get(archive_access).await?;
-        for (path, addr, _meta) in archive.iter() {
-            self.file_download(addr, to_dest.join(path)).await?;
+        for (path, addr, meta) in archive.iter() {
+            if !verify {
+                self.file_download(addr, to_dest.join(path)).await?;
+                continue;
+            }
+            let data = self.data_get(addr).await?;
+            if !meta.verify_content(&data) {
+                error!("Content of {path:?} does not match its hash in the private archive");
+                return Err(DownloadError::HashMismatch(path.clone()));
+            }
+            let dest = to_dest.join(path);
+            if let Some(parent) = dest.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&dest, data).await?;
+            debug!("Downloaded and verified file {path:?} to {dest:?}");
         }
         debug!("Downloaded directory to {to_dest:?}");
         Ok(())
@@ -97,6 +132,9 @@ impl Client {
                     return Err(err_msg);
                 }
 
+                let metadata =
+                    super::fs_public::metadata_from_entry(&entry).with_content(&file_path, &data);
+
                 let now = ant_networking::time::Instant::now();
 
                 let (data_map_chunk, chunks) = encrypt(data).map_err(|err| err.to_string())?;
@@ -108,8 +146,6 @@ impl Client {
                     .map(|chunk| (*chunk.name(), chunk.size()))
                     .collect();
 
-                let metadata = super::fs_public::metadata_from_entry(&entry);
-
                 let relative_path =
                     get_relative_file_path_from_abs_file_and_folder_path(&file_path, &dir_path);
 
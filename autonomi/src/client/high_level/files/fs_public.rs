@@ -18,6 +18,7 @@ use crate::{Amount, AttoTokens, Wallet};
 use ant_networking::time::{Duration, SystemTime};
 use ant_protocol::storage::{Chunk, DataTypes};
 use bytes::Bytes;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use xor_name::XorName;
 
@@ -43,11 +44,47 @@ impl Client {
         &self,
         archive_addr: &ArchiveAddr,
         to_dest: PathBuf,
+    ) -> Result<(), DownloadError> {
+        self.dir_download_public_inner(archive_addr, to_dest, false)
+            .await
+    }
+
+    /// Same as [`Client::dir_download_public`], but checks the content of each file against the SHA-256 hash stored in the archive.
+    /// Files without a hash in the archive are not checked. Fails with [`DownloadError::HashMismatch`] on the first
+    /// file that does not match, before writing it.
+    pub async fn dir_download_public_verified(
+        &self,
+        archive_addr: &ArchiveAddr,
+        to_dest: PathBuf,
+    ) -> Result<(), DownloadError> {
+        self.dir_download_public_inner(archive_addr, to_dest, true)
+            .await
+    }
+
+    async fn dir_download_public_inner(
+        &self,
+        archive_addr: &ArchiveAddr,
+        to_dest: PathBuf,
+        verify: bool,
     ) -> Result<(), DownloadError> {
         let archive = self.archive_get_public(archive_addr).await?;
         debug!("Downloaded archive for the directory from the network at {archive_addr:?}");
-        for (path, addr, _meta) in archive.iter() {
-            self.file_download_public(addr, to_dest.join(path)).await?;
+        for (path, addr, meta) in archive.iter() {
+            if !verify {
+                self.file_download_public(addr, to_dest.join(path)).await?;
+                continue;
+            }
+            let data = self.data_get_public(addr).await?;
+            if !meta.verify_content(&data) {
+                error!("Content of {path:?} at {addr:?} does not match its hash in archive {archive_addr:?}");
+                return Err(DownloadError::HashMismatch(path.clone()));
+            }
+            let dest = to_dest.join(path);
+            if let Some(parent) = dest.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&dest, data).await?;
+            debug!("Downloaded and verified file {path:?} to {dest:?}");
         }
         debug!(
             "All files in the directory downloaded to {:?} from the network address {:?}",
@@ -99,6 +136,8 @@ impl Client {
                     return Err(err_msg);
                 }
 
+                let metadata = metadata_from_entry(&entry).with_content(&file_path, &data);
+
                 let now = ant_networking::time::Instant::now();
 
                 let (data_map_chunk, mut chunks) = encrypt(data).map_err(|err| err.to_string())?;
@@ -114,8 +153,6 @@ impl Client {
                     .map(|chunk| (*chunk.name(), chunk.size()))
                     .collect();
 
-                let metadata = metadata_from_entry(&entry);
-
                 let relative_path =
                     get_relative_file_path_from_abs_file_and_folder_path(&file_path, &dir_path);
 
@@ -331,6 +368,9 @@ pub(crate) fn metadata_from_entry(entry: &walkdir::DirEntry) -> Metadata {
                 modified: 0,
                 size: 0,
                 extra: None,
+                mime_type: None,
+                sha256: None,
+                tags: BTreeMap::new(),
             };
         }
     };
@@ -361,5 +401,8 @@ pub(crate) fn metadata_from_entry(entry: &walkdir::DirEntry) -> Metadata {
         modified,
         size: fs_metadata.len(),
         extra: None,
+        mime_type: None,
+        sha256: None,
+        tags: BTreeMap::new(),
     }
 }
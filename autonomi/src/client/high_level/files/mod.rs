@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
     sync::LazyLock,
     time::{Duration, SystemTime, UNIX_EPOCH},
@@ -13,9 +15,11 @@ pub mod archive_public;
 pub mod fs_private;
 pub mod fs_public;
 mod fs_shared;
+pub mod site;
 
 pub use archive_private::PrivateArchive;
 pub use archive_public::PublicArchive;
+pub use site::{Site, SiteError, SiteLookup, SiteRoot};
 
 /// Number of files to upload in parallel.
 ///
@@ -46,6 +50,17 @@ pub struct Metadata {
 
     /// Optional extra metadata with undefined structure, e.g. JSON.
     pub extra: Option<String>,
+
+    // The fields below were added in the V1 archives, they default to empty when reading V0 archives.
+    /// MIME type of the file, e.g. `text/html`
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    /// SHA-256 hash of the file content, to check its integrity independently of self-encryption
+    #[serde(default)]
+    pub sha256: Option<[u8; 32]>,
+    /// Custom tags, e.g. for indexing
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
 }
 
 impl Metadata {
@@ -61,10 +76,83 @@ impl Metadata {
             modified: now,
             size,
             extra: None,
+            mime_type: None,
+            sha256: None,
+            tags: BTreeMap::new(),
+        }
+    }
+
+    /// Set the MIME type and SHA-256 hash of the file from its path and content
+    /// The MIME type is guessed from the file extension, or sniffed from the content if the extension is unknown
+    pub fn with_content(mut self, path: &Path, content: &[u8]) -> Self {
+        self.mime_type = guess_mime_type(path, content);
+        self.sha256 = Some(Sha256::digest(content).into());
+        self
+    }
+
+    /// Add a custom tag
+    pub fn with_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// The SHA-256 hash of the file as a hex string, if any
+    pub fn sha256_hex(&self) -> Option<String> {
+        self.sha256.map(hex::encode)
+    }
+
+    /// Check the content of the file against the stored SHA-256 hash
+    /// Content without a stored hash is considered valid
+    pub fn verify_content(&self, content: &[u8]) -> bool {
+        match self.sha256 {
+            Some(expected) => <[u8; 32]>::from(Sha256::digest(content)) == expected,
+            None => true,
         }
     }
 }
 
+/// Guess the MIME type of a file from its extension, or from its first bytes if the extension is unknown
+pub fn guess_mime_type(path: &Path, content: &[u8]) -> Option<String> {
+    if let Some(mime) = mime_guess::from_path(path).first() {
+        return Some(mime.essence_str().to_string());
+    }
+    sniff_mime_type(content).map(str::to_string)
+}
+
+/// Sniff the MIME type of some content from the magic numbers of common formats
+fn sniff_mime_type(content: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"\0asm", "application/wasm"),
+        (b"<?xml", "application/xml"),
+        (b"<svg", "image/svg+xml"),
+    ];
+    if let Some((_, mime)) = SIGNATURES
+        .iter()
+        .find(|(magic, _)| content.starts_with(magic))
+    {
+        return Some(mime);
+    }
+    if content.len() >= 12 && &content[..4] == b"RIFF" && &content[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    let text = std::str::from_utf8(content).ok()?;
+    let start = text.trim_start().get(..15).unwrap_or(text.trim_start());
+    let lowercase = start.to_ascii_lowercase();
+    if lowercase.starts_with("<!doctype html") || lowercase.starts_with("<html") {
+        Some("text/html")
+    } else {
+        Some("text/plain")
+    }
+}
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum RenameError {
     #[error("File not found in archive: {0}")]
@@ -95,6 +183,8 @@ pub enum DownloadError {
     GetError(#[from] GetError),
     #[error("IO failure")]
     IoError(#[from] std::io::Error),
+    #[error("Content of {0:?} does not match the SHA-256 hash in the archive")]
+    HashMismatch(PathBuf),
 }
 
 /// Errors that can occur during the file cost calculation.
@@ -139,3 +229,46 @@ pub(crate) fn get_relative_file_path_from_abs_file_and_folder_path(
             .to_path_buf()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_mime_type() {
+        let mime = |path: &str, content: &[u8]| guess_mime_type(Path::new(path), content);
+
+        // the extension takes precedence over the content
+        assert_eq!(mime("index.html", b"hello").as_deref(), Some("text/html"));
+        assert_eq!(mime("style.css", b"").as_deref(), Some("text/css"));
+        assert_eq!(mime("photo.PNG", b"").as_deref(), Some("image/png"));
+
+        // without a known extension, the content is sniffed
+        assert_eq!(
+            mime("image", b"\x89PNG\r\n\x1a\n....").as_deref(),
+            Some("image/png")
+        );
+        assert_eq!(mime("doc", b"%PDF-1.7").as_deref(), Some("application/pdf"));
+        assert_eq!(
+            mime("page", b"  <!DOCTYPE html><html></html>").as_deref(),
+            Some("text/html")
+        );
+        assert_eq!(mime("README", b"hello").as_deref(), Some("text/plain"));
+        assert_eq!(mime("blob", &[0xff, 0xfe, 0x00, 0x80]), None);
+    }
+
+    #[test]
+    fn test_metadata_verify_content() {
+        let meta = Metadata::new_with_size(5).with_content(Path::new("a.txt"), b"hello");
+        assert_eq!(meta.mime_type.as_deref(), Some("text/plain"));
+        assert_eq!(
+            meta.sha256_hex().as_deref(),
+            Some("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824")
+        );
+        assert!(meta.verify_content(b"hello"));
+        assert!(!meta.verify_content(b"hellO"));
+
+        // metadata without a hash does not reject any content
+        assert!(Metadata::new_with_size(5).verify_content(b"anything"));
+    }
+}
@@ -0,0 +1,336 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use std::{
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::client::{
+    address::str_to_addr,
+    data_types::pointer::{PointerAddress, PointerError, PointerTarget},
+    high_level::{
+        data::DataAddr,
+        files::{
+            archive_public::{ArchiveAddr, PublicArchive},
+            Metadata,
+        },
+        name::{normalize_name, NameError},
+        register::{RegisterAddress, RegisterError},
+    },
+    Client, GetError,
+};
+use xor_name::XorName;
+
+/// The file served when a directory of a site is requested
+pub const SITE_INDEX_FILE: &str = "index.html";
+
+/// The maximum number of pointers followed when resolving the root of a site
+pub const SITE_MAX_POINTER_DEPTH: usize = 16;
+
+/// Prefix of the string representation of a [`SiteRoot::Pointer`]
+const POINTER_PREFIX: &str = "pointer:";
+/// Prefix of the string representation of a [`SiteRoot::Register`]
+const REGISTER_PREFIX: &str = "register:";
+/// Prefix of the string representation of a [`SiteRoot::Name`]
+const NAME_PREFIX: &str = "name:";
+
+/// Errors that can occur when resolving a site
+#[derive(Debug, thiserror::Error)]
+pub enum SiteError {
+    #[error("Invalid site address: {0}")]
+    InvalidAddress(String),
+    #[error("Failed to resolve the site pointer: {0}")]
+    Pointer(#[from] PointerError),
+    #[error("Failed to resolve the site register: {0}")]
+    Register(#[from] RegisterError),
+    #[error("Failed to resolve the site name: {0}")]
+    Name(#[from] NameError),
+    #[error("The site root targets {0:?}, expected the chunk address of an archive")]
+    InvalidTarget(PointerTarget),
+    #[error("Failed to fetch the site archive: {0}")]
+    Archive(#[from] GetError),
+}
+
+/// The root of a static website hosted on the Network.
+///
+/// A site is a [`PublicArchive`], which can be referred to directly or through a [`crate::Pointer`] or a
+/// [`crate::register`] holding its address. Archives are immutable, the latter two give a site a fixed address
+/// that can be updated to point to a new version of the site.
+///
+/// The string representation is:
+/// - `<hex>` for an archive
+/// - `pointer:<hex>` for a pointer (or chain of pointers) targeting the [`crate::ChunkAddress`] of an archive
+/// - `register:<hex>` for a register whose value is the address of an archive
+/// - `name:<name>` for a [`crate::name`] targeting the [`crate::ChunkAddress`] of an archive
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SiteRoot {
+    Archive(ArchiveAddr),
+    Pointer(PointerAddress),
+    Register(RegisterAddress),
+    Name(String),
+}
+
+impl SiteRoot {
+    /// Returns true if the site can change without its address changing
+    pub fn is_mutable(&self) -> bool {
+        !matches!(self, SiteRoot::Archive(_))
+    }
+}
+
+impl FromStr for SiteRoot {
+    type Err = SiteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SiteError::InvalidAddress(s.to_string());
+        if let Some(hex) = s.strip_prefix(POINTER_PREFIX) {
+            let addr = str_to_addr(hex).map_err(|_| invalid())?;
+            Ok(SiteRoot::Pointer(PointerAddress::new(addr)))
+        } else if let Some(hex) = s.strip_prefix(REGISTER_PREFIX) {
+            let addr = RegisterAddress::from_hex(hex).map_err(|_| invalid())?;
+            Ok(SiteRoot::Register(addr))
+        } else if let Some(name) = s.strip_prefix(NAME_PREFIX) {
+            let name = normalize_name(name).map_err(|_| invalid())?;
+            Ok(SiteRoot::Name(name))
+        } else {
+            let addr = str_to_addr(s).map_err(|_| invalid())?;
+            Ok(SiteRoot::Archive(addr))
+        }
+    }
+}
+
+impl std::fmt::Display for SiteRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SiteRoot::Archive(addr) => write!(f, "{}", hex::encode(addr)),
+            SiteRoot::Pointer(addr) => write!(f, "{POINTER_PREFIX}{}", addr.to_hex()),
+            SiteRoot::Register(addr) => write!(f, "{REGISTER_PREFIX}{}", addr.to_hex()),
+            SiteRoot::Name(name) => write!(f, "{NAME_PREFIX}{name}"),
+        }
+    }
+}
+
+/// The result of looking up a path in a [`Site`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SiteLookup<'a> {
+    /// The path is a file of the site
+    File {
+        path: &'a PathBuf,
+        addr: &'a DataAddr,
+        meta: &'a Metadata,
+    },
+    /// The path is a directory with an index file but was requested without a trailing slash.
+    /// Clients should be redirected to the path with a trailing slash for relative links to work.
+    Redirect,
+    /// Nothing at this path
+    NotFound,
+}
+
+/// A static website: a [`PublicArchive`] whose paths are served as URL paths.
+///
+/// When all the files of the archive are in a single directory, as is the case when uploading a directory
+/// with [`Client::dir_upload_public`], that directory is the root of the site.
+#[derive(Debug, Clone)]
+pub struct Site {
+    archive_addr: ArchiveAddr,
+    archive: PublicArchive,
+    prefix: PathBuf,
+}
+
+impl Site {
+    pub fn new(archive_addr: ArchiveAddr, archive: PublicArchive) -> Self {
+        let prefix = common_root_dir(&archive);
+        Self {
+            archive_addr,
+            archive,
+            prefix,
+        }
+    }
+
+    /// The address of the archive of the site
+    pub fn archive_addr(&self) -> &ArchiveAddr {
+        &self.archive_addr
+    }
+
+    /// The archive of the site
+    pub fn archive(&self) -> &PublicArchive {
+        &self.archive
+    }
+
+    /// Look up a (percent decoded) URL path in the site.
+    ///
+    /// Directories, i.e. the empty path and paths ending with `/`, are served by their [`SITE_INDEX_FILE`].
+    /// Paths containing `..` are never found.
+    pub fn lookup(&self, url_path: &str) -> SiteLookup<'_> {
+        let mut path = self.prefix.clone();
+        for segment in url_path.split('/') {
+            match segment {
+                "" | "." => continue,
+                ".." => return SiteLookup::NotFound,
+                segment => path.push(segment),
+            }
+        }
+
+        if url_path.is_empty() || url_path.ends_with('/') {
+            return self.get(&path.join(SITE_INDEX_FILE));
+        }
+        match self.get(&path) {
+            SiteLookup::NotFound
+                if self.archive.map().contains_key(&path.join(SITE_INDEX_FILE)) =>
+            {
+                SiteLookup::Redirect
+            }
+            lookup => lookup,
+        }
+    }
+
+    fn get(&self, path: &Path) -> SiteLookup<'_> {
+        match self.archive.map().get_key_value(path) {
+            Some((path, (addr, meta))) => SiteLookup::File { path, addr, meta },
+            None => SiteLookup::NotFound,
+        }
+    }
+}
+
+/// The directory all the files of the archive are in, if there is exactly one
+fn common_root_dir(archive: &PublicArchive) -> PathBuf {
+    let mut root: Option<&Path> = None;
+    for path in archive.map().keys() {
+        let mut components = path.components();
+        let (Some(Component::Normal(first)), Some(_)) = (components.next(), components.next())
+        else {
+            return PathBuf::new();
+        };
+        match root {
+            Some(root) if root != Path::new(first) => return PathBuf::new(),
+            _ => root = Some(Path::new(first)),
+        }
+    }
+    root.map(Path::to_path_buf).unwrap_or_default()
+}
+
+impl Client {
+    /// Resolve the root of a site to the address of its archive.
+    /// For an archive this is the archive address itself, pointers and registers are fetched from the network.
+    pub async fn site_resolve(&self, root: &SiteRoot) -> Result<ArchiveAddr, SiteError> {
+        match root {
+            SiteRoot::Archive(addr) => Ok(*addr),
+            SiteRoot::Pointer(addr) => {
+                let resolution = self.pointer_resolve(addr, SITE_MAX_POINTER_DEPTH).await?;
+                match resolution.target {
+                    PointerTarget::ChunkAddress(chunk_addr) => Ok(*chunk_addr.xorname()),
+                    target => Err(SiteError::InvalidTarget(target)),
+                }
+            }
+            SiteRoot::Name(name) => match self.name_resolve(name).await?.target {
+                PointerTarget::ChunkAddress(chunk_addr) => Ok(*chunk_addr.xorname()),
+                target => Err(SiteError::InvalidTarget(target)),
+            },
+            SiteRoot::Register(addr) => {
+                let value = self.register_get(addr).await?;
+                Ok(XorName(value))
+            }
+        }
+    }
+
+    /// Resolve the root of a site and fetch its archive
+    pub async fn site_get(&self, root: &SiteRoot) -> Result<Site, SiteError> {
+        let archive_addr = self.site_resolve(root).await?;
+        debug!("Site {root} resolved to archive at {archive_addr:?}");
+        let archive = self.archive_get_public(&archive_addr).await?;
+        Ok(Site::new(archive_addr, archive))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site(paths: &[&str]) -> Site {
+        let mut archive = PublicArchive::new();
+        for path in paths {
+            archive.add_file(
+                PathBuf::from(path),
+                DataAddr::random(&mut rand::thread_rng()),
+                Metadata::new_with_size(0),
+            );
+        }
+        Site::new(ArchiveAddr::random(&mut rand::thread_rng()), archive)
+    }
+
+    fn found(lookup: SiteLookup<'_>) -> Option<&Path> {
+        match lookup {
+            SiteLookup::File { path, .. } => Some(path.as_path()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_site_lookup() {
+        let site = site(&[
+            "www/index.html",
+            "www/style.css",
+            "www/blog/index.html",
+            "www/blog/post 1.html",
+        ]);
+
+        assert_eq!(found(site.lookup("")), Some(Path::new("www/index.html")));
+        assert_eq!(found(site.lookup("/")), Some(Path::new("www/index.html")));
+        assert_eq!(
+            found(site.lookup("style.css")),
+            Some(Path::new("www/style.css"))
+        );
+        assert_eq!(
+            found(site.lookup("blog/")),
+            Some(Path::new("www/blog/index.html"))
+        );
+        assert_eq!(
+            found(site.lookup("blog/post 1.html")),
+            Some(Path::new("www/blog/post 1.html"))
+        );
+        assert_eq!(site.lookup("blog"), SiteLookup::Redirect);
+        assert_eq!(site.lookup("missing.html"), SiteLookup::NotFound);
+        assert_eq!(site.lookup("blog/../style.css"), SiteLookup::NotFound);
+        assert_eq!(site.lookup("www/style.css"), SiteLookup::NotFound);
+
+        // files at the top level of the archive are served at the root
+        let site = self::site(&["index.html", "img/logo.png"]);
+        assert_eq!(found(site.lookup("")), Some(Path::new("index.html")));
+        assert_eq!(
+            found(site.lookup("img/logo.png")),
+            Some(Path::new("img/logo.png"))
+        );
+    }
+
+    #[test]
+    fn test_site_root_from_str() {
+        let addr = ArchiveAddr::random(&mut rand::thread_rng());
+        let key = bls::SecretKey::random().public_key();
+        let roots = [
+            SiteRoot::Archive(addr),
+            SiteRoot::Pointer(PointerAddress::new(addr)),
+            SiteRoot::Register(RegisterAddress::new(key)),
+            SiteRoot::Name("my-site".to_string()),
+        ];
+        for root in &roots {
+            assert_eq!(
+                root.to_string().parse::<SiteRoot>().ok().as_ref(),
+                Some(root)
+            );
+        }
+        assert!(!roots[0].is_mutable());
+        assert!(roots[1].is_mutable());
+        assert!("pointer:nothex".parse::<SiteRoot>().is_err());
+        assert!("register:00".parse::<SiteRoot>().is_err());
+        assert!("name:not a name".parse::<SiteRoot>().is_err());
+        assert_eq!(
+            "name:My-Site".parse::<SiteRoot>().ok(),
+            Some(SiteRoot::Name("my-site".to_string()))
+        );
+    }
+}
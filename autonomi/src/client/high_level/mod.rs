@@ -6,8 +6,12 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+/// Blobs are large pieces of mutable encrypted data, transparently sharded across several [`crate::Scratchpad`]s.
+/// See [`blob::BlobAddress`] for how they are laid out on the Network.
+pub mod blob;
 pub mod data;
 pub mod files;
+pub mod name;
 pub mod vault;
 
 /// Registers are a mutable piece of data on the Network.
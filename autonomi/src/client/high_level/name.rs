@@ -0,0 +1,429 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Human readable names for addresses on the Network, built on [`crate::Pointer`]s.
+//!
+//! A name is made of two pointers:
+//! - the claim, at an address derived from the name only, so that anyone can find it.
+//!   Its key is derived from a well known root key, so anyone can also sign it. It is therefore created
+//!   with the maximum counter: nodes only accept pointer updates with a strictly higher counter,
+//!   so once stored the claim can never be changed, not even by its creator.
+//! - the owner pointer, owned by the claimant and targeted by the claim. The owner can update it
+//!   to make the name point to something else.
+//!
+//! ```ignore
+//! [claim, key derived from the name] --> [owner pointer, owner's key] --> target
+//! ```
+//!
+//! Trade-offs of this scheme:
+//! - Names are first come, first served, forever. There is no expiry, no renewal and no dispute resolution,
+//!   so anyone can squat any name for the cost of two pointers.
+//! - Ownership cannot be transferred: the claim always targets the same owner pointer. Losing its key
+//!   means losing control of the name, leaking it means someone else can repoint the name.
+//! - Two concurrent claims of the same name can each be stored by part of the close group, as nodes keep
+//!   the first claim they receive. Such a contested name can fail to resolve with a split record.
+//! - As anyone can sign a claim, an attacker can also push a conflicting claim on purpose to the nodes
+//!   of the close group that don't hold the claim yet, for instance right after it was registered or
+//!   when nodes join the close group during churn, to split or block the resolution of the name.
+//!   [`Client::name_register`] mitigates this by asking every node of the close group which claim it
+//!   holds, and only succeeds once they all hold the new claim. Nodes that join the close group later
+//!   get the claim through replication, where an attacker can still race it.
+//! - A pointer at the address of a name without the maximum counter is not a valid claim, as anyone could
+//!   update it. Such names are considered free and can be claimed over.
+//! - Names are normalized to lowercase ASCII letters, digits, `-`, `_` and `.`, which rules out
+//!   lookalike unicode names but not lookalike ASCII ones (`paypa1`). Distinct names hash to distinct addresses.
+
+use std::sync::LazyLock;
+
+use crate::client::{
+    data_types::pointer::{Pointer, PointerAddress, PointerError, PointerTarget},
+    keyring::derive_from_name,
+    payment::PaymentOption,
+    Client,
+};
+use ant_evm::AttoTokens;
+use ant_networking::{GetRecordError, NetworkError};
+use ant_protocol::{
+    close_group_size,
+    messages::{Query, QueryResponse, Request, Response},
+    storage::try_deserialize_record,
+    NetworkAddress,
+};
+use bls::{PublicKey, SecretKey};
+use libp2p::{kad::Record, PeerId};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// The maximum length of a name
+pub const NAME_MAX_LEN: usize = 64;
+
+/// The counter of name claims, no update can ever replace a pointer with this counter
+const NAME_CLAIM_COUNTER: u32 = u32::MAX;
+
+/// The maximum number of pointers followed when resolving the target of a name
+const NAME_MAX_POINTER_DEPTH: usize = 16;
+
+/// Domain separation for the derivation of the root key of the name claims
+const NAMES_ROOT_KEY_DOMAIN: &[u8] = b"autonomi names root key";
+
+/// Path segment under which the owner pointer keys of names are derived
+pub const NAMES_PATH: &str = "names";
+
+/// The root key from which the key of the claim of each name is derived.
+/// It is public by design: anyone must be able to compute the address of a name.
+static NAMES_ROOT_KEY: LazyLock<SecretKey> = LazyLock::new(|| {
+    let mut bytes: [u8; 32] = Sha256::digest(NAMES_ROOT_KEY_DOMAIN).into();
+    // clear the top bits so the big endian value is always below the BLS12-381 scalar field modulus
+    bytes[0] &= 0x3f;
+    SecretKey::from_bytes(bytes).expect("a 254 bits value is a valid BLS secret key")
+});
+
+#[derive(Error, Debug)]
+pub enum NameError {
+    #[error("Invalid name {0:?}: names are 1 to {NAME_MAX_LEN} lowercase letters, digits, '-', '_' or '.'")]
+    InvalidName(String),
+    #[error("Name {0:?} is already registered")]
+    AlreadyRegistered(String),
+    #[error("Name {0:?} is not registered")]
+    NotRegistered(String),
+    #[error("Name {0:?} is not owned by this key")]
+    NotOwner(String),
+    #[error("Name {0:?} has an invalid claim: {1}")]
+    InvalidClaim(String, String),
+    #[error("The claim of name {0:?} is not held by {1} nodes of its close group yet, check it again later")]
+    ClaimNotConfirmed(String, usize),
+    #[error("Invalid cost")]
+    InvalidCost,
+    #[error("Pointer error: {0}")]
+    Pointer(Box<PointerError>),
+}
+
+impl From<PointerError> for NameError {
+    fn from(err: PointerError) -> Self {
+        NameError::Pointer(Box::new(err))
+    }
+}
+
+/// The result of resolving a name with [`Client::name_resolve`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameResolution {
+    /// The normalized name
+    pub name: String,
+    /// The address of the claim of the name
+    pub address: PointerAddress,
+    /// The public key of the owner pointer
+    pub owner: PublicKey,
+    /// The address of the owner pointer
+    pub owner_pointer: PointerAddress,
+    /// The final target of the name, after following the owner pointer and the pointers it leads to
+    pub target: PointerTarget,
+}
+
+/// Normalize a name: trimmed and lowercased.
+/// Fails if the result is empty, longer than [`NAME_MAX_LEN`] or contains other characters than
+/// ASCII letters, digits, `-`, `_` and `.`
+pub fn normalize_name(name: &str) -> Result<String, NameError> {
+    let normalized = name.trim().to_ascii_lowercase();
+    let valid = !normalized.is_empty()
+        && normalized.len() <= NAME_MAX_LEN
+        && normalized
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(NameError::InvalidName(name.to_string()));
+    }
+    Ok(normalized)
+}
+
+/// The key of the claim of a normalized name, anyone can derive it
+fn name_claim_key(normalized_name: &str) -> SecretKey {
+    derive_from_name(&NAMES_ROOT_KEY, normalized_name)
+}
+
+impl Client {
+    /// Get the address of the claim of a name, which can be resolved by anyone with [`Client::name_resolve`]
+    pub fn name_address(name: &str) -> Result<PointerAddress, NameError> {
+        let name = normalize_name(name)?;
+        Ok(PointerAddress::from_owner(
+            name_claim_key(&name).public_key(),
+        ))
+    }
+
+    /// Derive the key of the owner pointer of a name from the owner's key, at `names/<name>`.
+    /// This matches [`crate::Keyring::derive`] with the path `["names", <name>]`.
+    pub fn name_owner_key(owner: &SecretKey, name: &str) -> Result<SecretKey, NameError> {
+        let name = normalize_name(name)?;
+        Ok(derive_from_name(
+            &derive_from_name(owner, NAMES_PATH),
+            &name,
+        ))
+    }
+
+    /// Register a name pointing to the given target.
+    ///
+    /// This creates (or updates) the owner pointer at `owner`'s key, then claims the name for it.
+    /// `owner` must be the key returned by [`Client::name_owner_key`] for this name, so that the owner
+    /// pointer can be found again from the owner's main key, and isn't shared with another name or pointer.
+    ///
+    /// Fails with [`NameError::AlreadyRegistered`] if someone else owns the name, including when
+    /// someone else claimed it concurrently, and with [`NameError::ClaimNotConfirmed`] if some nodes
+    /// of the close group still don't hold the claim after the verification retries.
+    /// Check the module documentation for the trade-offs.
+    pub async fn name_register(
+        &self,
+        name: &str,
+        owner: &SecretKey,
+        target: PointerTarget,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, NameResolution), NameError> {
+        let name = normalize_name(name)?;
+        let claim_key = name_claim_key(&name);
+        let address = PointerAddress::from_owner(claim_key.public_key());
+        match self.name_claim(&name, &address).await {
+            Ok(_) => return Err(NameError::AlreadyRegistered(name)),
+            Err(NameError::NotRegistered(_)) => {}
+            Err(err) => return Err(err),
+        }
+
+        // the owner pointer first, so the claim never points to nothing
+        let owner_pointer = PointerAddress::from_owner(owner.public_key());
+        let owner_cost = if self.pointer_check_existance(&owner_pointer).await? {
+            debug!("Owner pointer {owner_pointer:?} of name {name:?} exists, updating it");
+            self.pointer_update(owner, target).await?;
+            AttoTokens::zero()
+        } else {
+            let pointer = Pointer::new(owner, 0, target);
+            let (cost, _) = self.pointer_put(pointer, payment_option.clone()).await?;
+            cost
+        };
+
+        debug!("Claiming name {name:?} at {address:?} for {owner_pointer:?}");
+        let claim = Pointer::new(
+            &claim_key,
+            NAME_CLAIM_COUNTER,
+            PointerTarget::PointerAddress(owner_pointer),
+        );
+        let (claim_cost, _) = self.pointer_put(claim, payment_option).await?;
+        let total_cost = owner_cost
+            .checked_add(claim_cost)
+            .ok_or(NameError::InvalidCost)?;
+
+        // someone else may have claimed the name at the same time, or pushed a conflicting claim to
+        // the nodes that didn't get ours yet
+        self.name_claim_verify(&name, &address, owner_pointer)
+            .await?;
+        let resolution = self.name_resolve(&name).await?;
+        if resolution.owner_pointer != owner_pointer {
+            warn!(
+                "Name {name:?} was claimed concurrently by {:?}",
+                resolution.owner_pointer
+            );
+            return Err(NameError::AlreadyRegistered(name));
+        }
+        info!("Registered name {name:?} at {address:?}");
+        Ok((total_cost, resolution))
+    }
+
+    /// Resolve a name to its owner and target
+    pub async fn name_resolve(&self, name: &str) -> Result<NameResolution, NameError> {
+        let name = normalize_name(name)?;
+        let address = PointerAddress::from_owner(name_claim_key(&name).public_key());
+        let owner_pointer = self.name_claim(&name, &address).await?;
+
+        let resolution = self
+            .pointer_resolve(&owner_pointer, NAME_MAX_POINTER_DEPTH)
+            .await?;
+        let owner = match resolution.path.first() {
+            Some(pointer) => *pointer.owner(),
+            None => {
+                let reason = "the owner pointer could not be found".to_string();
+                return Err(NameError::InvalidClaim(name, reason));
+            }
+        };
+        debug!(
+            "Resolved name {name:?} to {:?} through {owner_pointer:?}",
+            resolution.target
+        );
+
+        Ok(NameResolution {
+            name,
+            address,
+            owner,
+            owner_pointer,
+            target: resolution.target,
+        })
+    }
+
+    /// Point a name to a new target, only the owner of the name can do this.
+    /// Updating a name is free, as it only updates the owner pointer.
+    pub async fn name_update(
+        &self,
+        name: &str,
+        owner: &SecretKey,
+        target: PointerTarget,
+    ) -> Result<(), NameError> {
+        let name = normalize_name(name)?;
+        let address = PointerAddress::from_owner(name_claim_key(&name).public_key());
+        let owner_pointer = self.name_claim(&name, &address).await?;
+        if owner_pointer != PointerAddress::from_owner(owner.public_key()) {
+            return Err(NameError::NotOwner(name));
+        }
+
+        debug!("Updating name {name:?} through {owner_pointer:?} to {target:?}");
+        self.pointer_update(owner, target).await?;
+        Ok(())
+    }
+
+    /// Check that every node of the close group of a name holds the claim targeting `owner_pointer`.
+    /// The nodes that don't hold a claim yet are asked again until the verification retries run out.
+    async fn name_claim_verify(
+        &self,
+        name: &str,
+        address: &PointerAddress,
+        owner_pointer: PointerAddress,
+    ) -> Result<(), NameError> {
+        let key = NetworkAddress::from_pointer_address(*address);
+        let mut pending: Vec<PeerId> = self
+            .network
+            .client_get_all_close_peers_in_range_or_close_group(&key)
+            .await
+            .map_err(PointerError::from)?
+            .into_iter()
+            .take(close_group_size())
+            .collect();
+        let req = Request::Query(Query::GetReplicatedRecord {
+            requester: NetworkAddress::from_peer(self.network.peer_id()),
+            key: key.clone(),
+        });
+
+        let mut backoff = self.config.pointer.verification_retry.backoff().into_iter();
+        loop {
+            let responses = self
+                .network
+                .send_and_get_responses(&pending, &req, true)
+                .await;
+            let mut missing = vec![];
+            for (peer, response) in responses {
+                let claim = match response {
+                    Ok(Response::Query(QueryResponse::GetReplicatedRecord(Ok((_, bytes))))) => {
+                        let record = Record::new(key.to_record_key(), bytes.to_vec());
+                        try_deserialize_record::<Pointer>(&record).ok()
+                    }
+                    _ => None,
+                };
+                match claim {
+                    Some(claim)
+                        if claim.counter() == NAME_CLAIM_COUNTER && claim.verify_signature() =>
+                    {
+                        if claim.target() != &PointerTarget::PointerAddress(owner_pointer) {
+                            warn!(
+                                "Node {peer:?} holds a conflicting claim of name {name:?} for {:?}",
+                                claim.target()
+                            );
+                            return Err(NameError::AlreadyRegistered(name.to_string()));
+                        }
+                    }
+                    _ => missing.push(peer),
+                }
+            }
+            if missing.is_empty() {
+                return Ok(());
+            }
+
+            match backoff.next() {
+                Some(Some(duration)) => {
+                    debug!(
+                        "Claim of name {name:?} not held by {} nodes yet, checking again",
+                        missing.len()
+                    );
+                    tokio::time::sleep(duration).await;
+                }
+                _ => {
+                    return Err(NameError::ClaimNotConfirmed(
+                        name.to_string(),
+                        missing.len(),
+                    ))
+                }
+            }
+            pending = missing;
+        }
+    }
+
+    /// Get the claim of a name and return the owner pointer it targets
+    async fn name_claim(
+        &self,
+        name: &str,
+        address: &PointerAddress,
+    ) -> Result<PointerAddress, NameError> {
+        let claim = match self.pointer_get(address).await {
+            Ok(claim) => claim,
+            Err(PointerError::Network(NetworkError::GetRecordError(
+                GetRecordError::RecordNotFound,
+            ))) => return Err(NameError::NotRegistered(name.to_string())),
+            Err(err) => return Err(err.into()),
+        };
+
+        if claim.counter() != NAME_CLAIM_COUNTER {
+            // anyone can update such a pointer, so it does not prove anything
+            warn!(
+                "Ignoring pointer at the address of name {name:?} with counter {}",
+                claim.counter()
+            );
+            return Err(NameError::NotRegistered(name.to_string()));
+        }
+        match claim.target() {
+            PointerTarget::PointerAddress(owner_pointer) => Ok(*owner_pointer),
+            other => Err(NameError::InvalidClaim(
+                name.to_string(),
+                format!("expected an owner pointer, got {other:?}"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Keyring;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(
+            normalize_name(" My-Site.v2 ").ok().as_deref(),
+            Some("my-site.v2")
+        );
+        assert_eq!(normalize_name("a_b").ok().as_deref(), Some("a_b"));
+
+        assert!(normalize_name("").is_err());
+        assert!(normalize_name("with space").is_err());
+        assert!(normalize_name("naïve").is_err());
+        assert!(normalize_name("a/b").is_err());
+        assert!(normalize_name(&"a".repeat(NAME_MAX_LEN)).is_ok());
+        assert!(normalize_name(&"a".repeat(NAME_MAX_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_name_keys() -> Result<(), NameError> {
+        // names that normalize the same share an address, others don't
+        assert_eq!(
+            Client::name_address("Example")?,
+            Client::name_address("example")?
+        );
+        assert_ne!(
+            Client::name_address("example")?,
+            Client::name_address("example2")?
+        );
+
+        let keyring = Keyring::random();
+        assert_eq!(
+            Client::name_owner_key(keyring.root_secret_key(), "Example")?,
+            keyring.derive(&[NAMES_PATH, "example"])
+        );
+        Ok(())
+    }
+}
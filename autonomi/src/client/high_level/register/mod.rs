@@ -16,7 +16,6 @@ use crate::AttoTokens;
 use ant_networking::{GetRecordError, NetworkError};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use xor_name::XorName;
 
 mod history;
 
@@ -106,11 +105,9 @@ impl Client {
     ///
     /// This derives a new [`SecretKey`] from the owner's [`SecretKey`] using the name.
     /// Note that you will need to keep track of the names you used to create the register key.
+    /// See [`crate::client::keyring::Keyring`] to derive all the keys from a single root key.
     pub fn register_key_from_name(owner: &SecretKey, name: &str) -> SecretKey {
-        let main_key = MainSecretKey::new(owner.clone());
-        let derivation_index =
-            DerivationIndex::from_bytes(XorName::from_content(name.as_bytes()).0);
-        main_key.derive_key(&derivation_index).into()
+        crate::client::keyring::derive_from_name(owner, name)
     }
 
     /// Create a new [`RegisterValue`] from bytes, make sure the bytes are not longer than [`REGISTER_VALUE_SIZE`]
@@ -10,9 +10,12 @@ pub mod key;
 pub mod user_data;
 
 pub use key::{derive_vault_key, VaultSecretKey};
-pub use user_data::UserData;
+pub use user_data::{
+    EntryTimestamps, NameConflict, UserData, UserDataChange, UserDataEntry, UserDataMerge,
+};
 
 use crate::client::data_types::scratchpad::ScratchpadError;
+use crate::client::high_level::blob::{split_bytes, MAX_CONTENT_PER_SCRATCHPAD};
 use crate::client::high_level::files::FILE_UPLOAD_BATCH_SIZE;
 use crate::client::key_derivation::{DerivationIndex, MainSecretKey};
 use crate::client::payment::PaymentOption;
@@ -22,11 +25,9 @@ use crate::client::Client;
 use crate::graph::GraphError;
 use ant_evm::{AttoTokens, U256};
 use ant_networking::{GetRecordError, NetworkError};
-use ant_protocol::storage::{
-    GraphContent, GraphEntry, GraphEntryAddress, Scratchpad, ScratchpadAddress,
-};
+use ant_protocol::storage::{GraphContent, GraphEntry, GraphEntryAddress, ScratchpadAddress};
 use ant_protocol::Bytes;
-use bls::PublicKey;
+use bls::{PublicKey, SecretKey};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use tracing::info;
 
@@ -37,9 +38,6 @@ use tracing::info;
 /// The value 0 is reserved for tests
 pub type VaultContentType = u64;
 
-/// Defines the max size of content can be written into per ScratchPad
-const MAX_CONTENT_PER_SCRATCHPAD: usize = Scratchpad::MAX_SIZE - 1024;
-
 /// Defines the max number of Scratchpads that one GraphEntry can point to
 /// The current value is assuming GraphEntry max_size to be 100KB.
 const NUM_OF_SCRATCHPADS_PER_GRAPHENTRY: usize = 1_000;
@@ -55,6 +53,30 @@ pub fn app_name_to_vault_content_type<T: Hash>(s: T) -> VaultContentType {
     hasher.finish()
 }
 
+/// Capacity and usage of a vault, see [`Client::vault_info`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VaultInfo {
+    /// Content type of the data in the vault
+    pub content_type: VaultContentType,
+    /// Number of updates since the vault was created, i.e. the counter of its first Scratchpad
+    pub version: u64,
+    /// Number of GraphEntries claiming capacity for the vault
+    pub graph_entries: usize,
+    /// Number of Scratchpads claimed by the GraphEntries, they are only created (and paid for) when first used
+    pub scratchpads_claimed: usize,
+    /// Number of Scratchpads created so far
+    pub scratchpads_created: usize,
+    /// Number of Scratchpads holding the current content
+    pub scratchpads_used: usize,
+    /// Size of the current content in bytes
+    pub used_bytes: u64,
+    /// Size of the content that fits in the Scratchpads already created, in bytes
+    pub allocated_bytes: u64,
+    /// Stale content left in created Scratchpads past the current content, in bytes
+    /// See [`Client::write_bytes_to_vault_and_zero_fill`] to clear it
+    pub garbage_bytes: u64,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum VaultError {
     #[error("Vault Scratchpad related error: {0}")]
@@ -156,6 +178,59 @@ impl Client {
         }
     }
 
+    /// Get the capacity and usage of a vault
+    ///
+    /// Returns a default (all zero) [`VaultInfo`] if the vault does not exist.
+    pub async fn vault_info(&self, secret_key: &VaultSecretKey) -> Result<VaultInfo, VaultError> {
+        info!("Getting vault info...");
+        let main_secret_key = MainSecretKey::new(secret_key.clone());
+        let (_, scratchpad_derivations) = self
+            .vault_claimed_capacity(
+                &main_secret_key,
+                DerivationIndex::from_bytes(VAULT_HEAD_DERIVATION_INDEX),
+            )
+            .await?;
+
+        let mut info = VaultInfo {
+            graph_entries: scratchpad_derivations.len() / NUM_OF_SCRATCHPADS_PER_GRAPHENTRY,
+            scratchpads_claimed: scratchpad_derivations.len(),
+            ..Default::default()
+        };
+
+        // Scratchpads are created in order, so the created ones are the leading ones
+        let mut has_end_reached = false;
+        for (pub_key, derive_bytes) in scratchpad_derivations {
+            let addr = ScratchpadAddress::new(pub_key);
+            let sp = match self.scratchpad_get(&addr).await {
+                Ok(sp) => sp,
+                Err(err) if err.is_not_found() => break,
+                Err(err) => return Err(err.into()),
+            };
+            let secret_key = main_secret_key.derive_key(&DerivationIndex::from_bytes(derive_bytes));
+            let content_len = sp.decrypt_data(&secret_key.into())?.len() as u64;
+
+            if info.scratchpads_created == 0 {
+                info.version = sp.counter();
+            }
+            info.scratchpads_created += 1;
+            if has_end_reached {
+                info.garbage_bytes += content_len;
+                continue;
+            }
+            info.content_type = sp.data_encoding();
+            info.scratchpads_used += 1;
+            info.used_bytes += content_len;
+            // Same end-of-vault-content rule as when fetching the vault
+            if sp.encrypted_data().len() < MAX_CONTENT_PER_SCRATCHPAD {
+                has_end_reached = true;
+            }
+        }
+        info.allocated_bytes = (info.scratchpads_created * MAX_CONTENT_PER_SCRATCHPAD) as u64;
+
+        debug!("Vault info: {info:?}");
+        Ok(info)
+    }
+
     /// Put data into the client's VaultPacket
     ///
     /// Dynamically expand the vault capacity by paying for more space (Scratchpad) when needed.
@@ -167,6 +242,34 @@ impl Client {
         payment_option: PaymentOption,
         secret_key: &VaultSecretKey,
         content_type: VaultContentType,
+    ) -> Result<AttoTokens, VaultError> {
+        self.write_bytes_to_vault_inner(data, payment_option, secret_key, content_type, false)
+            .await
+    }
+
+    /// Put data into the client's VaultPacket, like [`Client::write_bytes_to_vault`],
+    /// then overwrite the Scratchpads left unused past the new content with empty content.
+    ///
+    /// Use this when the content shrinks, so that no stale data from previous versions is kept on the Network.
+    /// Overwriting existing Scratchpads is free, the capacity paid for is kept for later writes.
+    pub async fn write_bytes_to_vault_and_zero_fill(
+        &self,
+        data: Bytes,
+        payment_option: PaymentOption,
+        secret_key: &VaultSecretKey,
+        content_type: VaultContentType,
+    ) -> Result<AttoTokens, VaultError> {
+        self.write_bytes_to_vault_inner(data, payment_option, secret_key, content_type, true)
+            .await
+    }
+
+    async fn write_bytes_to_vault_inner(
+        &self,
+        data: Bytes,
+        payment_option: PaymentOption,
+        secret_key: &VaultSecretKey,
+        content_type: VaultContentType,
+        zero_fill: bool,
     ) -> Result<AttoTokens, VaultError> {
         if data.is_empty() {
             return Err(VaultError::VaultWithZeroContentSize);
@@ -185,6 +288,7 @@ impl Client {
             .await?;
 
         let contents = split_bytes(data);
+        let num_of_contents = contents.len();
 
         info!(
             "Current capacity is {}, meanwhile requiring {}",
@@ -271,9 +375,51 @@ impl Client {
             }
         }
 
+        if zero_fill {
+            self.vault_zero_fill_unused(
+                &main_secret_key,
+                &scratchpad_derivations[num_of_contents..],
+                content_type,
+            )
+            .await?;
+        }
+
         Ok(total_cost)
     }
 
+    // Overwrite the already created Scratchpads among the given unused ones with empty content
+    // Scratchpads are created in order, so this stops at the first one that does not exist
+    async fn vault_zero_fill_unused(
+        &self,
+        main_secret_key: &MainSecretKey,
+        unused_scratchpad_derivations: &[(PublicKey, GraphContent)],
+        content_type: VaultContentType,
+    ) -> Result<(), VaultError> {
+        let mut num_of_cleared = 0;
+        for (pub_key, derive_bytes) in unused_scratchpad_derivations {
+            let addr = ScratchpadAddress::new(*pub_key);
+            let sp = match self.scratchpad_get(&addr).await {
+                Ok(sp) => sp,
+                Err(err) if err.is_not_found() => break,
+                Err(err) => return Err(err.into()),
+            };
+            let secret_key: SecretKey = main_secret_key
+                .derive_key(&DerivationIndex::from_bytes(*derive_bytes))
+                .into();
+            if sp.decrypt_data(&secret_key)?.is_empty() {
+                continue;
+            }
+
+            info!("Zero-filling unused vault Scratchpad at {addr:?}");
+            self.scratchpad_update(&secret_key, content_type, &Bytes::new())
+                .await?;
+            num_of_cleared += 1;
+        }
+
+        info!("Zero-filled {num_of_cleared} unused vault Scratchpads");
+        Ok(())
+    }
+
     // Expand the capacity, i.e. upload one GraphEntry
     // The returned value is:
     //   * cur_free_graphentry_derivation: the output[0] of the tail of the linked GraphEntry
@@ -407,16 +553,3 @@ impl Client {
         ))
     }
 }
-
-fn split_bytes(input: Bytes) -> Vec<Bytes> {
-    let mut contents = Vec::new();
-    let mut offset = 0;
-
-    while offset < input.len() {
-        let end = (offset + MAX_CONTENT_PER_SCRATCHPAD).min(input.len());
-        contents.push(input.slice(offset..end));
-        offset = end;
-    }
-
-    contents
-}
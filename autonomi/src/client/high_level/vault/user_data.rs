@@ -6,15 +6,21 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::Hash;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::client::address::addr_to_str;
+use crate::client::data_types::graph::GraphEntryAddress;
+use crate::client::data_types::pointer::PointerAddress;
+use crate::client::data_types::scratchpad::ScratchpadAddress;
 use crate::client::high_level::files::archive_private::PrivateArchiveAccess;
 use crate::client::high_level::files::archive_public::ArchiveAddr;
 use crate::client::payment::PaymentOption;
 use crate::client::Client;
 use crate::client::GetError;
 use crate::register::RegisterAddress;
-use ant_evm::AttoTokens;
+use ant_evm::{AttoTokens, EvmAddress};
 use ant_protocol::Bytes;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
@@ -25,10 +31,17 @@ use super::{app_name_to_vault_content_type, VaultContentType, VaultError, VaultS
 pub static USER_DATA_VAULT_CONTENT_IDENTIFIER: LazyLock<VaultContentType> =
     LazyLock::new(|| app_name_to_vault_content_type("UserData"));
 
+/// Version of the [`UserData`] format written by this client
+/// - 0: file archives, private file archives and registers only
+/// - 1: adds merge timestamps, pointers, scratchpads, graph entries, wallet addresses and app data
+pub const USER_DATA_VERSION: u32 = 1;
+
 /// UserData is stored in Vaults and contains most of a user's private data:
 /// It allows users to keep track of only the key to their User Data Vault
 /// while having the rest kept on the Network encrypted in a Vault for them
 /// Using User Data Vault is optional, one can decide to keep all their data locally instead.
+///
+/// New sections are only ever appended with a default value, so that user data written by older clients can still be read.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct UserData {
     /// Owned file archive addresses, along with their names (can be empty)
@@ -37,6 +50,374 @@ pub struct UserData {
     pub private_file_archives: HashMap<PrivateArchiveAccess, String>,
     /// Owned register addresses, along with their names (can be empty)
     pub register_addresses: HashMap<RegisterAddress, String>,
+    /// When entries were last added or renamed, used when merging UserData edited on several devices
+    #[serde(default)]
+    pub modified: EntryTimestamps,
+    /// Tombstones of removed entries, so that removals propagate when merging
+    #[serde(default)]
+    pub removed: EntryTimestamps,
+    /// Version of the format this user data was written with, see [`USER_DATA_VERSION`]
+    #[serde(default)]
+    pub version: u32,
+    /// Owned pointer addresses, along with their names (can be empty)
+    #[serde(default)]
+    pub pointers: HashMap<PointerAddress, String>,
+    /// Owned scratchpad addresses, along with their names (can be empty)
+    #[serde(default)]
+    pub scratchpads: HashMap<ScratchpadAddress, String>,
+    /// Owned graph entry addresses, along with their names (can be empty)
+    #[serde(default)]
+    pub graph_entries: HashMap<GraphEntryAddress, String>,
+    /// Public addresses of the user's wallets, along with their names (can be empty)
+    #[serde(default)]
+    pub wallet_addresses: HashMap<EvmAddress, String>,
+    /// Free form key-value data of each app, keyed by [`app_name_to_vault_content_type`]
+    #[serde(default)]
+    pub app_data: BTreeMap<VaultContentType, BTreeMap<String, Bytes>>,
+}
+
+/// Times (in seconds since the UNIX epoch) attached to the entries of each section of a [`UserData`]
+/// Entries without a time are considered older than any other change
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct EntryTimestamps {
+    pub file_archives: HashMap<ArchiveAddr, u64>,
+    pub private_file_archives: HashMap<PrivateArchiveAccess, u64>,
+    pub register_addresses: HashMap<RegisterAddress, u64>,
+    #[serde(default)]
+    pub pointers: HashMap<PointerAddress, u64>,
+    #[serde(default)]
+    pub scratchpads: HashMap<ScratchpadAddress, u64>,
+    #[serde(default)]
+    pub graph_entries: HashMap<GraphEntryAddress, u64>,
+    #[serde(default)]
+    pub wallet_addresses: HashMap<EvmAddress, u64>,
+    #[serde(default)]
+    pub app_data: BTreeMap<VaultContentType, BTreeMap<String, u64>>,
+}
+
+/// An entry of a [`UserData`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UserDataEntry {
+    FileArchive(ArchiveAddr),
+    PrivateFileArchive(PrivateArchiveAccess),
+    Register(RegisterAddress),
+    Pointer(PointerAddress),
+    Scratchpad(ScratchpadAddress),
+    GraphEntry(GraphEntryAddress),
+    WalletAddress(EvmAddress),
+}
+
+impl std::fmt::Display for UserDataEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserDataEntry::FileArchive(addr) => {
+                write!(f, "public file archive {}", addr_to_str(*addr))
+            }
+            UserDataEntry::PrivateFileArchive(access) => {
+                write!(f, "private file archive {}", access.address())
+            }
+            UserDataEntry::Register(addr) => write!(f, "register {}", addr.to_hex()),
+            UserDataEntry::Pointer(addr) => write!(f, "pointer {}", addr.to_hex()),
+            UserDataEntry::Scratchpad(addr) => write!(f, "scratchpad {}", addr.to_hex()),
+            UserDataEntry::GraphEntry(addr) => write!(f, "graph entry {}", addr.to_hex()),
+            UserDataEntry::WalletAddress(addr) => write!(f, "wallet {addr}"),
+        }
+    }
+}
+
+/// A change to apply to one side of a merge to obtain the merged [`UserData`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserDataChange {
+    Added {
+        entry: UserDataEntry,
+        name: String,
+    },
+    Renamed {
+        entry: UserDataEntry,
+        from: String,
+        to: String,
+    },
+    Removed {
+        entry: UserDataEntry,
+        name: String,
+    },
+}
+
+impl std::fmt::Display for UserDataChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserDataChange::Added { entry, name } => write!(f, "+ {entry} {name:?}"),
+            UserDataChange::Renamed { entry, from, to } => {
+                write!(f, "~ {entry} {from:?} -> {to:?}")
+            }
+            UserDataChange::Removed { entry, name } => write!(f, "- {entry} {name:?}"),
+        }
+    }
+}
+
+/// An entry named differently on both sides of a merge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameConflict {
+    pub entry: UserDataEntry,
+    pub local_name: String,
+    pub remote_name: String,
+    /// The name kept in the merged [`UserData`]: the most recently changed one, or the local one if unknown
+    pub kept_name: String,
+}
+
+/// The outcome of [`UserData::merge`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserDataMerge {
+    /// The merged user data
+    pub merged: UserData,
+    /// Changes turning the local user data into the merged one
+    pub local_changes: Vec<UserDataChange>,
+    /// Changes turning the remote user data into the merged one
+    pub remote_changes: Vec<UserDataChange>,
+    /// Entries that had different names on both sides
+    pub conflicts: Vec<NameConflict>,
+    /// App data keys that had different values on both sides, the most recently set value was
+    /// kept, or the local one if unknown
+    pub app_data_conflicts: Vec<(VaultContentType, String)>,
+}
+
+/// One section of a [`UserData`] with its timestamps
+struct Section<'a, K> {
+    entries: &'a HashMap<K, String>,
+    modified: &'a HashMap<K, u64>,
+    removed: &'a HashMap<K, u64>,
+}
+
+impl<K: Clone + Eq + Hash> Section<'_, K> {
+    fn live(&self, key: &K) -> Option<(u64, &String)> {
+        self.entries
+            .get(key)
+            .map(|name| (self.modified.get(key).copied().unwrap_or(0), name))
+    }
+}
+
+/// Merge one section, returning the merged entries, modification times and tombstones
+#[allow(clippy::type_complexity)]
+fn merge_section<K: Clone + Eq + Hash>(
+    local: Section<K>,
+    remote: Section<K>,
+    to_entry: impl Fn(&K) -> UserDataEntry,
+    report: &mut UserDataMerge,
+) -> (HashMap<K, String>, HashMap<K, u64>, HashMap<K, u64>) {
+    let mut entries = HashMap::new();
+    let mut modified = HashMap::new();
+    let mut removed = HashMap::new();
+
+    let keys: HashSet<&K> = local
+        .entries
+        .keys()
+        .chain(local.removed.keys())
+        .chain(remote.entries.keys())
+        .chain(remote.removed.keys())
+        .collect();
+
+    for key in keys {
+        let local_live = local.live(key);
+        let remote_live = remote.live(key);
+        // on equal times the local side wins
+        let latest_live = match (local_live, remote_live) {
+            (Some(l), Some(r)) if r.0 > l.0 => Some(r),
+            (Some(l), _) => Some(l),
+            (None, r) => r,
+        };
+        let latest_removal = local.removed.get(key).max(remote.removed.get(key)).copied();
+
+        // a removal wins over an addition made at the same time or before it
+        if let Some(removed_at) = latest_removal {
+            if latest_live.is_none_or(|(added_at, _)| removed_at >= added_at) {
+                removed.insert(key.clone(), removed_at);
+                if let Some((_, name)) = local_live {
+                    report.local_changes.push(UserDataChange::Removed {
+                        entry: to_entry(key),
+                        name: name.clone(),
+                    });
+                }
+                if let Some((_, name)) = remote_live {
+                    report.remote_changes.push(UserDataChange::Removed {
+                        entry: to_entry(key),
+                        name: name.clone(),
+                    });
+                }
+                continue;
+            }
+        }
+
+        let Some((added_at, name)) = latest_live else {
+            continue;
+        };
+        entries.insert(key.clone(), name.clone());
+        if added_at > 0 {
+            modified.insert(key.clone(), added_at);
+        }
+
+        if let (Some((_, local_name)), Some((_, remote_name))) = (local_live, remote_live) {
+            if local_name != remote_name {
+                report.conflicts.push(NameConflict {
+                    entry: to_entry(key),
+                    local_name: local_name.clone(),
+                    remote_name: remote_name.clone(),
+                    kept_name: name.clone(),
+                });
+            }
+        }
+        for (side, changes) in [
+            (local_live, &mut report.local_changes),
+            (remote_live, &mut report.remote_changes),
+        ] {
+            match side {
+                None => changes.push(UserDataChange::Added {
+                    entry: to_entry(key),
+                    name: name.clone(),
+                }),
+                Some((_, old_name)) if old_name != name => changes.push(UserDataChange::Renamed {
+                    entry: to_entry(key),
+                    from: old_name.clone(),
+                    to: name.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+    }
+
+    (entries, modified, removed)
+}
+
+/// Merge the app data with the same rules as the sections, the values being compared instead of the names
+fn merge_app_data(
+    local: &UserData,
+    remote: &UserData,
+    merged: &mut UserData,
+    report: &mut UserDataMerge,
+) {
+    let mut keys = HashSet::new();
+    for side in [local, remote] {
+        for (content_type, data) in &side.app_data {
+            keys.extend(data.keys().map(|key| (*content_type, key)));
+        }
+        for (content_type, times) in &side.removed.app_data {
+            keys.extend(times.keys().map(|key| (*content_type, key)));
+        }
+    }
+
+    for (content_type, key) in keys {
+        let local_live = live_app_data(local, content_type, key);
+        let remote_live = live_app_data(remote, content_type, key);
+        // on equal times the local side wins
+        let latest_live = match (local_live, remote_live) {
+            (Some(l), Some(r)) if r.0 > l.0 => Some(r),
+            (Some(l), _) => Some(l),
+            (None, r) => r,
+        };
+        let latest_removal = app_data_time(&local.removed.app_data, content_type, key)
+            .max(app_data_time(&remote.removed.app_data, content_type, key));
+
+        // a removal wins over a value set at the same time or before it
+        if let Some(removed_at) = latest_removal {
+            if latest_live.is_none_or(|(set_at, _)| removed_at >= set_at) {
+                merged
+                    .removed
+                    .app_data
+                    .entry(content_type)
+                    .or_default()
+                    .insert(key.clone(), removed_at);
+                continue;
+            }
+        }
+
+        let Some((set_at, value)) = latest_live else {
+            continue;
+        };
+        if let (Some((_, local_value)), Some((_, remote_value))) = (local_live, remote_live) {
+            if local_value != remote_value {
+                report.app_data_conflicts.push((content_type, key.clone()));
+            }
+        }
+        merged
+            .app_data
+            .entry(content_type)
+            .or_default()
+            .insert(key.clone(), value.clone());
+        if set_at > 0 {
+            merged
+                .modified
+                .app_data
+                .entry(content_type)
+                .or_default()
+                .insert(key.clone(), set_at);
+        }
+    }
+}
+
+/// The value of a key of the app data, along with the time it was set (zero if unknown)
+fn live_app_data<'a>(
+    side: &'a UserData,
+    content_type: VaultContentType,
+    key: &str,
+) -> Option<(u64, &'a Bytes)> {
+    let value = side.app_data.get(&content_type)?.get(key)?;
+    let set_at = app_data_time(&side.modified.app_data, content_type, key).unwrap_or(0);
+    Some((set_at, value))
+}
+
+/// The time attached to a key of the app data, if any
+fn app_data_time(
+    times: &BTreeMap<VaultContentType, BTreeMap<String, u64>>,
+    content_type: VaultContentType,
+    key: &str,
+) -> Option<u64> {
+    times.get(&content_type)?.get(key).copied()
+}
+
+/// Remove the time attached to a key of the app data, if any
+fn remove_app_data_time(
+    times: &mut BTreeMap<VaultContentType, BTreeMap<String, u64>>,
+    content_type: VaultContentType,
+    key: &str,
+) {
+    if let Some(app_times) = times.get_mut(&content_type) {
+        app_times.remove(key);
+        if app_times.is_empty() {
+            times.remove(&content_type);
+        }
+    }
+}
+
+/// Add an entry to a section, clearing its tombstone
+fn add_entry<K: Clone + Eq + Hash>(
+    entries: &mut HashMap<K, String>,
+    modified: &mut HashMap<K, u64>,
+    removed: &mut HashMap<K, u64>,
+    key: K,
+    name: String,
+) -> Option<String> {
+    removed.remove(&key);
+    modified.insert(key.clone(), now());
+    entries.insert(key, name)
+}
+
+/// Remove an entry from a section, leaving a tombstone
+fn remove_entry<K: Clone + Eq + Hash>(
+    entries: &mut HashMap<K, String>,
+    modified: &mut HashMap<K, u64>,
+    removed: &mut HashMap<K, u64>,
+    key: &K,
+) -> Option<String> {
+    modified.remove(key);
+    removed.insert(key.clone(), now());
+    entries.remove(key)
+}
+
+/// Current time in seconds since the UNIX epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
 }
 
 /// Errors that can occur during the get operation.
@@ -50,6 +431,8 @@ pub enum UserDataVaultError {
     Serialization(String),
     #[error("Get error: {0}")]
     GetError(#[from] GetError),
+    #[error("Unsupported user data version: {0}, the latest supported version is {USER_DATA_VERSION}, please update your client")]
+    UnsupportedVersion(u32),
 }
 
 impl UserData {
@@ -60,12 +443,18 @@ impl UserData {
 
     /// Add a register. Returning `Option::Some` with the old name if the register was already in the set.
     pub fn add_register(&mut self, register: RegisterAddress, name: String) -> Option<String> {
-        self.register_addresses.insert(register, name)
+        add_entry(
+            &mut self.register_addresses,
+            &mut self.modified.register_addresses,
+            &mut self.removed.register_addresses,
+            register,
+            name,
+        )
     }
 
     /// Add an archive. Returning `Option::Some` with the old name if the archive was already in the set.
     pub fn add_file_archive(&mut self, archive: ArchiveAddr) -> Option<String> {
-        self.file_archives.insert(archive, "".into())
+        self.add_file_archive_with_name(archive, "".into())
     }
 
     /// Add an archive. Returning `Option::Some` with the old name if the archive was already in the set.
@@ -74,12 +463,18 @@ impl UserData {
         archive: ArchiveAddr,
         name: String,
     ) -> Option<String> {
-        self.file_archives.insert(archive, name)
+        add_entry(
+            &mut self.file_archives,
+            &mut self.modified.file_archives,
+            &mut self.removed.file_archives,
+            archive,
+            name,
+        )
     }
 
     /// Add a private archive. Returning `Option::Some` with the old name if the archive was already in the set.
     pub fn add_private_file_archive(&mut self, archive: PrivateArchiveAccess) -> Option<String> {
-        self.private_file_archives.insert(archive, "".into())
+        self.add_private_file_archive_with_name(archive, "".into())
     }
 
     /// Add a private archive with a name. Returning `Option::Some` with the old name if the archive was already in the set.
@@ -88,22 +483,242 @@ impl UserData {
         archive: PrivateArchiveAccess,
         name: String,
     ) -> Option<String> {
-        self.private_file_archives.insert(archive, name)
+        add_entry(
+            &mut self.private_file_archives,
+            &mut self.modified.private_file_archives,
+            &mut self.removed.private_file_archives,
+            archive,
+            name,
+        )
+    }
+
+    /// Add a pointer. Returning `Option::Some` with the old name if the pointer was already in the set.
+    pub fn add_pointer(&mut self, pointer: PointerAddress, name: String) -> Option<String> {
+        add_entry(
+            &mut self.pointers,
+            &mut self.modified.pointers,
+            &mut self.removed.pointers,
+            pointer,
+            name,
+        )
+    }
+
+    /// Add a scratchpad. Returning `Option::Some` with the old name if the scratchpad was already in the set.
+    pub fn add_scratchpad(
+        &mut self,
+        scratchpad: ScratchpadAddress,
+        name: String,
+    ) -> Option<String> {
+        add_entry(
+            &mut self.scratchpads,
+            &mut self.modified.scratchpads,
+            &mut self.removed.scratchpads,
+            scratchpad,
+            name,
+        )
+    }
+
+    /// Add a graph entry. Returning `Option::Some` with the old name if the graph entry was already in the set.
+    pub fn add_graph_entry(
+        &mut self,
+        graph_entry: GraphEntryAddress,
+        name: String,
+    ) -> Option<String> {
+        add_entry(
+            &mut self.graph_entries,
+            &mut self.modified.graph_entries,
+            &mut self.removed.graph_entries,
+            graph_entry,
+            name,
+        )
+    }
+
+    /// Add a wallet's public address. Returning `Option::Some` with the old name if the address was already in the set.
+    pub fn add_wallet_address(&mut self, address: EvmAddress, name: String) -> Option<String> {
+        add_entry(
+            &mut self.wallet_addresses,
+            &mut self.modified.wallet_addresses,
+            &mut self.removed.wallet_addresses,
+            address,
+            name,
+        )
     }
 
     /// Remove an archive. Returning `Option::Some` with the old name if the archive was already in the set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
     pub fn remove_file_archive(&mut self, archive: ArchiveAddr) -> Option<String> {
-        self.file_archives.remove(&archive)
+        remove_entry(
+            &mut self.file_archives,
+            &mut self.modified.file_archives,
+            &mut self.removed.file_archives,
+            &archive,
+        )
     }
 
     /// Remove a private archive. Returning `Option::Some` with the old name if the archive was already in the set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
     pub fn remove_private_file_archive(&mut self, archive: PrivateArchiveAccess) -> Option<String> {
-        self.private_file_archives.remove(&archive)
+        remove_entry(
+            &mut self.private_file_archives,
+            &mut self.modified.private_file_archives,
+            &mut self.removed.private_file_archives,
+            &archive,
+        )
+    }
+
+    /// Remove a register. Returning `Option::Some` with the old name if the register was already in the set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
+    pub fn remove_register(&mut self, register: RegisterAddress) -> Option<String> {
+        remove_entry(
+            &mut self.register_addresses,
+            &mut self.modified.register_addresses,
+            &mut self.removed.register_addresses,
+            &register,
+        )
+    }
+
+    /// Remove a pointer. Returning `Option::Some` with the old name if the pointer was already in the set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
+    pub fn remove_pointer(&mut self, pointer: PointerAddress) -> Option<String> {
+        remove_entry(
+            &mut self.pointers,
+            &mut self.modified.pointers,
+            &mut self.removed.pointers,
+            &pointer,
+        )
+    }
+
+    /// Remove a scratchpad. Returning `Option::Some` with the old name if the scratchpad was already in the set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
+    pub fn remove_scratchpad(&mut self, scratchpad: ScratchpadAddress) -> Option<String> {
+        remove_entry(
+            &mut self.scratchpads,
+            &mut self.modified.scratchpads,
+            &mut self.removed.scratchpads,
+            &scratchpad,
+        )
+    }
+
+    /// Remove a graph entry. Returning `Option::Some` with the old name if the graph entry was already in the set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
+    pub fn remove_graph_entry(&mut self, graph_entry: GraphEntryAddress) -> Option<String> {
+        remove_entry(
+            &mut self.graph_entries,
+            &mut self.modified.graph_entries,
+            &mut self.removed.graph_entries,
+            &graph_entry,
+        )
+    }
+
+    /// Remove a wallet's public address. Returning `Option::Some` with the old name if the address was already in the set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
+    pub fn remove_wallet_address(&mut self, address: EvmAddress) -> Option<String> {
+        remove_entry(
+            &mut self.wallet_addresses,
+            &mut self.modified.wallet_addresses,
+            &mut self.removed.wallet_addresses,
+            &address,
+        )
+    }
+
+    /// Get the data of an app, see [`app_name_to_vault_content_type`]
+    pub fn app_data(&self, app_name: &str) -> Option<&BTreeMap<String, Bytes>> {
+        self.app_data.get(&app_name_to_vault_content_type(app_name))
+    }
+
+    /// Set a value in the data of an app. Returning `Option::Some` with the old value if the key was already set.
+    pub fn insert_app_data(&mut self, app_name: &str, key: String, value: Bytes) -> Option<Bytes> {
+        let content_type = app_name_to_vault_content_type(app_name);
+        remove_app_data_time(&mut self.removed.app_data, content_type, &key);
+        self.modified
+            .app_data
+            .entry(content_type)
+            .or_default()
+            .insert(key.clone(), now());
+        self.app_data
+            .entry(content_type)
+            .or_default()
+            .insert(key, value)
+    }
+
+    /// Remove a value from the data of an app. Returning `Option::Some` with the old value if the key was set.
+    /// The removal is remembered so that it propagates when merging, see [`UserData::merge`].
+    pub fn remove_app_data(&mut self, app_name: &str, key: &str) -> Option<Bytes> {
+        let content_type = app_name_to_vault_content_type(app_name);
+        remove_app_data_time(&mut self.modified.app_data, content_type, key);
+        self.removed
+            .app_data
+            .entry(content_type)
+            .or_default()
+            .insert(key.to_string(), now());
+        let app_data = self.app_data.get_mut(&content_type)?;
+        let old = app_data.remove(key);
+        if app_data.is_empty() {
+            self.app_data.remove(&content_type);
+        }
+        old
+    }
+
+    /// Merge this (local) user data with a remote one, typically fetched from a vault.
+    ///
+    /// The result is the union of both sides, where:
+    /// - an entry removed on either side after its last addition or rename is removed
+    /// - an entry named differently on both sides keeps the most recently set name, or the local one if unknown
+    /// - the app data follows the same rules, its values being compared instead of the names
+    ///
+    /// Returns the merged user data along with the changes it implies for each side.
+    pub fn merge(&self, remote: &UserData) -> UserDataMerge {
+        let mut report = UserDataMerge::default();
+
+        let mut merged = UserData {
+            version: USER_DATA_VERSION,
+            ..Default::default()
+        };
+
+        macro_rules! merge_sections {
+            ($($section:ident => $entry:expr),* $(,)?) => {$(
+                let (entries, modified, removed) = merge_section(
+                    Section {
+                        entries: &self.$section,
+                        modified: &self.modified.$section,
+                        removed: &self.removed.$section,
+                    },
+                    Section {
+                        entries: &remote.$section,
+                        modified: &remote.modified.$section,
+                        removed: &remote.removed.$section,
+                    },
+                    $entry,
+                    &mut report,
+                );
+                merged.$section = entries;
+                merged.modified.$section = modified;
+                merged.removed.$section = removed;
+            )*};
+        }
+        merge_sections!(
+            file_archives => |addr| UserDataEntry::FileArchive(*addr),
+            private_file_archives => |access| UserDataEntry::PrivateFileArchive(access.clone()),
+            register_addresses => |addr| UserDataEntry::Register(addr.clone()),
+            pointers => |addr| UserDataEntry::Pointer(*addr),
+            scratchpads => |addr| UserDataEntry::Scratchpad(*addr),
+            graph_entries => |addr| UserDataEntry::GraphEntry(*addr),
+            wallet_addresses => |addr| UserDataEntry::WalletAddress(*addr),
+        );
+
+        merge_app_data(self, remote, &mut merged, &mut report);
+
+        report.merged = merged;
+        report
     }
 
-    /// To bytes
+    /// To bytes, written with the latest [`USER_DATA_VERSION`]
     pub fn to_bytes(&self) -> Result<Bytes, rmp_serde::encode::Error> {
-        let bytes = rmp_serde::to_vec(&self)?;
+        let user_data = UserData {
+            version: USER_DATA_VERSION,
+            ..self.clone()
+        };
+        let bytes = rmp_serde::to_vec(&user_data)?;
         Ok(Bytes::from(bytes))
     }
 
@@ -131,6 +746,9 @@ impl Client {
         let vault = UserData::from_bytes(bytes).map_err(|e| {
             UserDataVaultError::Serialization(format!("Failed to deserialize vault content: {e}"))
         })?;
+        if vault.version > USER_DATA_VERSION {
+            return Err(UserDataVaultError::UnsupportedVersion(vault.version));
+        }
 
         Ok(vault)
     }
@@ -158,3 +776,207 @@ impl Client {
         Ok(total_cost)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xor_name::XorName;
+
+    fn archive(n: u8) -> ArchiveAddr {
+        XorName([n; 32])
+    }
+
+    #[test]
+    fn test_merge_is_a_union() {
+        let mut local = UserData::new();
+        local.add_file_archive_with_name(archive(1), "local".into());
+        let mut remote = UserData::new();
+        remote.add_file_archive_with_name(archive(2), "remote".into());
+
+        let merge = local.merge(&remote);
+        assert_eq!(merge.merged.file_archives.len(), 2);
+        assert_eq!(
+            merge.local_changes,
+            vec![UserDataChange::Added {
+                entry: UserDataEntry::FileArchive(archive(2)),
+                name: "remote".into()
+            }]
+        );
+        assert_eq!(
+            merge.remote_changes,
+            vec![UserDataChange::Added {
+                entry: UserDataEntry::FileArchive(archive(1)),
+                name: "local".into()
+            }]
+        );
+        assert!(merge.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_merge_name_conflicts() {
+        let mut local = UserData::new();
+        local.add_file_archive_with_name(archive(1), "old".into());
+        let mut remote = local.clone();
+        remote.add_file_archive_with_name(archive(1), "new".into());
+        remote.modified.file_archives.insert(archive(1), now() + 10);
+
+        // the most recent name wins
+        let merge = local.merge(&remote);
+        assert_eq!(merge.merged.file_archives[&archive(1)], "new");
+        assert_eq!(merge.conflicts[0].kept_name, "new");
+        assert_eq!(merge.local_changes.len(), 1);
+        assert!(merge.remote_changes.is_empty());
+
+        // without times, the local name wins
+        local.modified = EntryTimestamps::default();
+        remote.modified = EntryTimestamps::default();
+        let merge = local.merge(&remote);
+        assert_eq!(merge.merged.file_archives[&archive(1)], "old");
+        assert_eq!(merge.conflicts[0].kept_name, "old");
+    }
+
+    #[test]
+    fn test_merge_propagates_removals() {
+        let mut local = UserData::new();
+        local.add_file_archive_with_name(archive(1), "a".into());
+        local.modified = EntryTimestamps::default();
+        let mut remote = local.clone();
+        remote.remove_file_archive(archive(1));
+
+        let merge = local.merge(&remote);
+        assert!(merge.merged.file_archives.is_empty());
+        assert!(merge.merged.removed.file_archives.contains_key(&archive(1)));
+        assert_eq!(
+            merge.local_changes,
+            vec![UserDataChange::Removed {
+                entry: UserDataEntry::FileArchive(archive(1)),
+                name: "a".into()
+            }]
+        );
+
+        // adding it back after the removal wins over the tombstone
+        let mut local = merge.merged;
+        local.add_file_archive_with_name(archive(1), "b".into());
+        local.modified.file_archives.insert(archive(1), now() + 10);
+        let merge = local.merge(&remote);
+        assert_eq!(merge.merged.file_archives[&archive(1)], "b");
+        assert!(merge.merged.removed.file_archives.is_empty());
+    }
+
+    #[test]
+    fn test_user_data_without_timestamps_can_be_read() {
+        #[derive(Serialize)]
+        struct LegacyUserData {
+            file_archives: HashMap<ArchiveAddr, String>,
+            private_file_archives: HashMap<PrivateArchiveAccess, String>,
+            register_addresses: HashMap<RegisterAddress, String>,
+        }
+
+        let legacy = LegacyUserData {
+            file_archives: HashMap::from([(archive(1), "a".to_string())]),
+            private_file_archives: HashMap::new(),
+            register_addresses: HashMap::new(),
+        };
+        let bytes = Bytes::from(rmp_serde::to_vec(&legacy).expect("serialize"));
+        let user_data = UserData::from_bytes(bytes).expect("deserialize");
+        assert_eq!(user_data.file_archives[&archive(1)], "a");
+        assert_eq!(user_data.removed, EntryTimestamps::default());
+    }
+
+    #[test]
+    fn test_merge_new_sections_and_app_data() {
+        let pointer = PointerAddress::new(XorName([2; 32]));
+        let wallet = EvmAddress::new([3; 20]);
+
+        let mut local = UserData::new();
+        local.add_pointer(pointer, "head".into());
+        local.insert_app_data("notes", "theme".into(), Bytes::from("dark"));
+        local.insert_app_data("notes", "font".into(), Bytes::from("mono"));
+        let mut remote = UserData::new();
+        remote.add_wallet_address(wallet, "savings".into());
+        remote.insert_app_data("notes", "theme".into(), Bytes::from("light"));
+        remote.insert_app_data("chat", "nick".into(), Bytes::from("ant"));
+        // the local theme was set last
+        let notes = app_name_to_vault_content_type("notes");
+        local
+            .modified
+            .app_data
+            .get_mut(&notes)
+            .expect("notes times")
+            .insert("theme".into(), now() + 10);
+
+        let merge = local.merge(&remote);
+        let merged = &merge.merged;
+        assert_eq!(merged.pointers[&pointer], "head");
+        assert_eq!(merged.wallet_addresses[&wallet], "savings");
+        let notes = merged.app_data("notes").expect("notes app data");
+        assert_eq!(notes["theme"], Bytes::from("dark"));
+        assert_eq!(notes["font"], Bytes::from("mono"));
+        assert_eq!(
+            merged.app_data("chat").expect("chat app data")["nick"],
+            Bytes::from("ant")
+        );
+        assert_eq!(
+            merge.app_data_conflicts,
+            vec![(app_name_to_vault_content_type("notes"), "theme".to_string())]
+        );
+
+        // round trip through the vault format
+        let bytes = merged.to_bytes().expect("serialize");
+        let decoded = UserData::from_bytes(bytes).expect("deserialize");
+        assert_eq!(decoded.version, USER_DATA_VERSION);
+        assert_eq!(decoded.pointers, merged.pointers);
+        assert_eq!(decoded.app_data, merged.app_data);
+        assert_eq!(decoded.modified, merged.modified);
+    }
+
+    #[test]
+    fn test_merge_propagates_app_data_removals() {
+        let pointer = PointerAddress::new(XorName([2; 32]));
+        let mut remote = UserData::new();
+        remote.add_pointer(pointer, "head".into());
+        remote.insert_app_data("notes", "theme".into(), Bytes::from("dark"));
+        remote.insert_app_data("notes", "font".into(), Bytes::from("mono"));
+
+        // synced, then removed locally
+        let mut local = remote.clone();
+        assert_eq!(local.remove_pointer(pointer), Some("head".into()));
+        assert_eq!(
+            local.remove_app_data("notes", "theme"),
+            Some(Bytes::from("dark"))
+        );
+
+        let merge = local.merge(&remote);
+        let merged = &merge.merged;
+        assert!(merged.pointers.is_empty());
+        let notes = merged.app_data("notes").expect("notes app data");
+        assert!(!notes.contains_key("theme"));
+        assert_eq!(notes["font"], Bytes::from("mono"));
+        assert!(merge.app_data_conflicts.is_empty());
+
+        // the removal doesn't come back on the next sync
+        let merge = remote.merge(merged);
+        assert!(!merge
+            .merged
+            .app_data("notes")
+            .expect("notes app data")
+            .contains_key("theme"));
+
+        // a value set again after the removal wins
+        let mut remote = merge.merged;
+        remote.insert_app_data("notes", "theme".into(), Bytes::from("light"));
+        let notes = app_name_to_vault_content_type("notes");
+        remote
+            .modified
+            .app_data
+            .get_mut(&notes)
+            .expect("notes times")
+            .insert("theme".into(), now() + 10);
+        let merge = local.merge(&remote);
+        assert_eq!(
+            merge.merged.app_data("notes").expect("notes app data")["theme"],
+            Bytes::from("light")
+        );
+        assert!(merge.merged.removed.app_data.is_empty());
+    }
+}
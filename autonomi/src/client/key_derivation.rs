@@ -6,7 +6,8 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use bls::{serde_impl::SerdeSecret, PublicKey, SecretKey, PK_SIZE};
+use bip39::Mnemonic;
+use bls::{serde_impl::SerdeSecret, PublicKey, SecretKey, PK_SIZE, SK_SIZE};
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -23,6 +24,43 @@ pub enum KeyDecodeError {
     InvalidKeyLength,
 }
 
+/// Errors that can occur when restoring a key from a mnemonic phrase
+#[derive(Error, Debug)]
+pub enum KeyMnemonicError {
+    #[error("Invalid mnemonic: {0}")]
+    InvalidMnemonic(#[from] bip39::Error),
+    #[error("Invalid mnemonic length: expected the {SECRET_KEY_MNEMONIC_WORDS} words of a secret key backup, got {0} bytes of entropy. Shorter mnemonics are keyring mnemonics")]
+    InvalidEntropyLength(usize),
+    #[error("The mnemonic does not encode a valid BLS secret key")]
+    InvalidSecretKey,
+}
+
+/// Number of words of the mnemonic phrase of a secret key, see [`secret_key_to_mnemonic`]
+pub const SECRET_KEY_MNEMONIC_WORDS: usize = 24;
+
+/// Encode a BLS [`SecretKey`] as a 24 words BIP-39 mnemonic phrase.
+/// The phrase is a lossless encoding of the key, with the BIP-39 checksum in its last word,
+/// so the key can be restored from it with [`secret_key_from_mnemonic`].
+///
+/// Keyring mnemonics have 12 words, so the two can't be mistaken for one another: each is rejected
+/// when given in place of the other, see [`crate::Keyring::from_mnemonic`].
+pub fn secret_key_to_mnemonic(secret_key: &SecretKey) -> Mnemonic {
+    Mnemonic::from_entropy(&secret_key.to_bytes())
+        .expect("32 bytes of entropy is a valid BIP-39 mnemonic length")
+}
+
+/// Restore a BLS [`SecretKey`] from the mnemonic phrase created by [`secret_key_to_mnemonic`].
+/// Fails if a word is unknown, the checksum doesn't match or the phrase does not encode a BLS key.
+pub fn secret_key_from_mnemonic(phrase: &str) -> Result<SecretKey, KeyMnemonicError> {
+    let mnemonic = Mnemonic::parse_normalized(phrase)?;
+    let entropy = mnemonic.to_entropy();
+    let bytes: [u8; SK_SIZE] = entropy
+        .as_slice()
+        .try_into()
+        .map_err(|_| KeyMnemonicError::InvalidEntropyLength(entropy.len()))?;
+    SecretKey::from_bytes(bytes).map_err(|_| KeyMnemonicError::InvalidSecretKey)
+}
+
 /// This is used to generate a new DerivedPubkey
 /// from a MainPubkey, and the corresponding
 /// DerivedSecretKey from the MainSecretKey of that MainPubkey.
@@ -329,6 +367,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_secret_key_mnemonic_roundtrip() -> eyre::Result<()> {
+        let sk = SecretKey::random();
+        let mnemonic = secret_key_to_mnemonic(&sk);
+        assert_eq!(mnemonic.word_count(), 24);
+        assert_eq!(secret_key_from_mnemonic(&mnemonic.to_string())?, sk);
+
+        // changing a checksum bit of the last word is detected
+        let mut words: Vec<&str> = mnemonic.words().collect();
+        let last_index = mnemonic.word_indices().last().unwrap_or_default();
+        words[23] = mnemonic.language().word_list()[last_index ^ 1];
+        assert!(matches!(
+            secret_key_from_mnemonic(&words.join(" ")),
+            Err(KeyMnemonicError::InvalidMnemonic(_))
+        ));
+
+        // valid mnemonics of another length are not keys
+        let short = Mnemonic::from_entropy(&[0u8; 16])?;
+        assert!(matches!(
+            secret_key_from_mnemonic(&short.to_string()),
+            Err(KeyMnemonicError::InvalidEntropyLength(16))
+        ));
+
+        // values above the BLS scalar field modulus are not keys
+        let above_modulus = Mnemonic::from_entropy(&[0xff; 32])?;
+        assert!(matches!(
+            secret_key_from_mnemonic(&above_modulus.to_string()),
+            Err(KeyMnemonicError::InvalidSecretKey)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn verification_using_child_key() -> eyre::Result<()> {
         let msg = "just a test string".as_bytes();
@@ -0,0 +1,229 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::key_derivation::{DerivationIndex, MainSecretKey, SECRET_KEY_MNEMONIC_WORDS};
+use bip39::Mnemonic;
+use bls::{PublicKey, SecretKey};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use xor_name::XorName;
+
+/// Path segment under which register keys are derived
+pub const REGISTERS_PATH: &str = "registers";
+/// Path segment under which pointer keys are derived
+pub const POINTERS_PATH: &str = "pointers";
+/// Path segment under which scratchpad keys are derived
+pub const SCRATCHPADS_PATH: &str = "scratchpads";
+/// Path segment under which graph entry keys are derived
+pub const GRAPH_ENTRIES_PATH: &str = "graph_entries";
+
+/// Domain separation for the derivation of a root key from a BIP-39 seed
+const MNEMONIC_ROOT_KEY_DOMAIN: &[u8] = b"autonomi keyring root key";
+
+/// Entropy of the mnemonics generated by [`Keyring::random_with_mnemonic`], 16 bytes give 12 words.
+/// This sets them apart from the 24 words mnemonics of secret keys, see
+/// [`crate::client::key_derivation::secret_key_to_mnemonic`].
+const MNEMONIC_ENTROPY_LEN: usize = 16;
+
+#[derive(Debug, Error)]
+pub enum KeyringError {
+    #[error("Invalid mnemonic: {0}")]
+    InvalidMnemonic(#[from] bip39::Error),
+    #[error("This {SECRET_KEY_MNEMONIC_WORDS} words mnemonic is the backup of a secret key, not a keyring mnemonic")]
+    SecretKeyMnemonic,
+}
+
+/// A Keyring derives the keys of all the mutable data a user owns from a single root key.
+///
+/// Keys are derived along paths of names, each segment being a derivation of the previous key:
+/// - `registers/<name>` for registers
+/// - `pointers/<name>` for pointers
+/// - `scratchpads/<app>/<name>` for scratchpads
+/// - `graph_entries/<name>` for graph entries
+///
+/// Backing up the root key, or the mnemonic it was created from, is thus enough to recover every object.
+/// Deriving a single segment from a key matches [`crate::Client::register_key_from_name`], so the keys
+/// of the registers created from the key at `registers` are exactly the ones at `registers/<name>`.
+///
+/// ```ignore
+/// root --> registers --> <name>
+///      --> pointers --> <name>
+///      --> scratchpads --> <app> --> <name>
+///      --> graph_entries --> <name>
+/// ```
+#[derive(Clone)]
+pub struct Keyring {
+    root: SecretKey,
+}
+
+impl std::fmt::Debug for Keyring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keyring")
+            .field("root", &self.root.public_key())
+            .finish()
+    }
+}
+
+impl Keyring {
+    /// Create a Keyring rooted in the given secret key
+    pub fn new(root: SecretKey) -> Self {
+        Self { root }
+    }
+
+    /// Create a Keyring with a random root key
+    pub fn random() -> Self {
+        Self::new(SecretKey::random())
+    }
+
+    /// Create a Keyring rooted in a new random mnemonic.
+    /// The mnemonic, along with the passphrase, is all that is needed to recover the Keyring with [`Keyring::from_mnemonic`].
+    pub fn random_with_mnemonic(passphrase: &str) -> Result<(Self, Mnemonic), KeyringError> {
+        let entropy: [u8; MNEMONIC_ENTROPY_LEN] = rand::random();
+        let mnemonic = Mnemonic::from_entropy(&entropy)?;
+        let keyring = Self::from_mnemonic(&mnemonic.to_string(), passphrase)?;
+        Ok((keyring, mnemonic))
+    }
+
+    /// Recover a Keyring from a BIP-39 mnemonic phrase and an optional passphrase (can be empty)
+    ///
+    /// A 24 words mnemonic is rejected, as it is the backup of a secret key, to be restored with
+    /// [`crate::client::key_derivation::secret_key_from_mnemonic`] instead.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self, KeyringError> {
+        let mnemonic = Mnemonic::parse_normalized(phrase)?;
+        if mnemonic.word_count() == SECRET_KEY_MNEMONIC_WORDS {
+            return Err(KeyringError::SecretKeyMnemonic);
+        }
+        let seed = mnemonic.to_seed_normalized(passphrase);
+        Ok(Self::new(root_key_from_seed(&seed)))
+    }
+
+    /// The root secret key of the Keyring
+    pub fn root_secret_key(&self) -> &SecretKey {
+        &self.root
+    }
+
+    /// The root public key of the Keyring
+    pub fn root_public_key(&self) -> PublicKey {
+        self.root.public_key()
+    }
+
+    /// Derive the key at the given path, each segment being derived from the key of the previous one
+    /// An empty path returns the root key
+    pub fn derive<S: AsRef<str>>(&self, path: &[S]) -> SecretKey {
+        path.iter().fold(self.root.clone(), |key, segment| {
+            derive_from_name(&key, segment.as_ref())
+        })
+    }
+
+    /// Derive the key of the register with the given name, at `registers/<name>`
+    pub fn register_key(&self, name: &str) -> SecretKey {
+        self.derive(&[REGISTERS_PATH, name])
+    }
+
+    /// Derive the key of the pointer with the given name, at `pointers/<name>`
+    pub fn pointer_key(&self, name: &str) -> SecretKey {
+        self.derive(&[POINTERS_PATH, name])
+    }
+
+    /// Derive the key of the scratchpad of an app with the given name, at `scratchpads/<app>/<name>`
+    pub fn scratchpad_key(&self, app: &str, name: &str) -> SecretKey {
+        self.derive(&[SCRATCHPADS_PATH, app, name])
+    }
+
+    /// Derive the key of the graph entry with the given name, at `graph_entries/<name>`
+    pub fn graph_entry_key(&self, name: &str) -> SecretKey {
+        self.derive(&[GRAPH_ENTRIES_PATH, name])
+    }
+}
+
+/// Derive a key from a name, the name is hashed into a [`DerivationIndex`]
+pub(crate) fn derive_from_name(key: &SecretKey, name: &str) -> SecretKey {
+    let main_key = MainSecretKey::new(key.clone());
+    let derivation_index = DerivationIndex::from_bytes(XorName::from_content(name.as_bytes()).0);
+    main_key.derive_key(&derivation_index).into()
+}
+
+/// Derive a BLS secret key from a BIP-39 seed
+fn root_key_from_seed(seed: &[u8]) -> SecretKey {
+    let mut hasher = Sha256::new();
+    hasher.update(MNEMONIC_ROOT_KEY_DOMAIN);
+    hasher.update(seed);
+    let mut bytes: [u8; 32] = hasher.finalize().into();
+    // clear the top bits so the big endian value is always below the BLS12-381 scalar field modulus
+    bytes[0] &= 0x3f;
+    SecretKey::from_bytes(bytes).expect("a 254 bits value is a valid BLS secret key")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Client;
+
+    #[test]
+    fn test_keyring_paths() {
+        let keyring = Keyring::random();
+
+        assert_eq!(
+            keyring.register_key("notes"),
+            Client::register_key_from_name(&keyring.derive(&[REGISTERS_PATH]), "notes")
+        );
+        assert_eq!(
+            keyring.scratchpad_key("app", "state"),
+            keyring.derive(&["scratchpads", "app", "state"])
+        );
+        assert_eq!(keyring.derive::<&str>(&[]), *keyring.root_secret_key());
+
+        // the same name in different namespaces gives different keys
+        let keys = [
+            keyring.register_key("x"),
+            keyring.pointer_key("x"),
+            keyring.scratchpad_key("app", "x"),
+            keyring.scratchpad_key("other", "x"),
+            keyring.graph_entry_key("x"),
+        ];
+        for (i, a) in keys.iter().enumerate() {
+            for b in keys.iter().skip(i + 1) {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyring_from_mnemonic() -> Result<(), KeyringError> {
+        let (keyring, mnemonic) = Keyring::random_with_mnemonic("")?;
+        assert_eq!(mnemonic.word_count(), 12);
+
+        let recovered = Keyring::from_mnemonic(&mnemonic.to_string(), "")?;
+        assert_eq!(recovered.root_public_key(), keyring.root_public_key());
+        assert_eq!(recovered.pointer_key("p"), keyring.pointer_key("p"));
+
+        // the passphrase is part of the seed
+        let other = Keyring::from_mnemonic(&mnemonic.to_string(), "passphrase")?;
+        assert_ne!(other.root_public_key(), keyring.root_public_key());
+
+        assert!(Keyring::from_mnemonic("not a valid mnemonic", "").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_keyring_and_secret_key_mnemonics_are_not_interchangeable() -> Result<(), KeyringError> {
+        let sk = SecretKey::random();
+        let key_mnemonic = crate::client::key_derivation::secret_key_to_mnemonic(&sk);
+        assert!(matches!(
+            Keyring::from_mnemonic(&key_mnemonic.to_string(), ""),
+            Err(KeyringError::SecretKeyMnemonic)
+        ));
+
+        let (_, keyring_mnemonic) = Keyring::random_with_mnemonic("")?;
+        assert!(matches!(
+            crate::client::key_derivation::secret_key_from_mnemonic(&keyring_mnemonic.to_string()),
+            Err(crate::client::key_derivation::KeyMnemonicError::InvalidEntropyLength(16))
+        ));
+        Ok(())
+    }
+}
@@ -23,14 +23,17 @@ pub use data_types::scratchpad;
 /// High-level types built on top of the basic Network data types.
 /// Includes data, files and personnal data vaults
 mod high_level;
+pub use high_level::blob;
 pub use high_level::data;
 pub use high_level::files;
+pub use high_level::name;
 pub use high_level::register;
 pub use high_level::vault;
 
 pub mod address;
 pub mod config;
 pub mod key_derivation;
+pub mod keyring;
 pub mod payment;
 pub mod quote;
 
@@ -46,13 +49,18 @@ pub use ant_evm::Amount;
 use ant_evm::EvmNetwork;
 use ant_networking::{
     interval, multiaddr_is_global, Network, NetworkBuilder, NetworkError, NetworkEvent,
+    PrivateNetwork, QueryTrace, QueryTracer, TransportConfig, VerifiedClosestPeers,
 };
-use ant_protocol::{version::IDENTIFY_PROTOCOL_STR, NetworkAddress};
+use ant_protocol::{messages::PutRecordRejection, version::IDENTIFY_PROTOCOL_STR, NetworkAddress};
 use config::{ClientConfig, ClientOperatingStrategy};
-use libp2p::{identity::Keypair, Multiaddr};
+use libp2p::{identity::Keypair, Multiaddr, PeerId};
 use payment::PayError;
 use quote::CostError;
-use std::{collections::HashSet, time::Duration};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::PathBuf,
+    time::Duration,
+};
 use tokio::sync::{mpsc, watch};
 
 /// Time before considering the connection timed out.
@@ -61,7 +69,7 @@ pub const CONNECT_TIMEOUT_SECS: u64 = 10;
 const CLIENT_EVENT_CHANNEL_SIZE: usize = 100;
 
 // Amount of peers to confirm into our routing table before we consider the client ready.
-pub use ant_protocol::CLOSE_GROUP_SIZE;
+pub use ant_protocol::{close_group_size, CLOSE_GROUP_SIZE};
 
 /// Represents a client for the Autonomi network.
 ///
@@ -86,7 +94,73 @@ pub struct Client {
     /// The configuration for operations on the client.
     config: ClientOperatingStrategy,
     // Shutdown signal for child tasks. Sends signal when dropped.
-    _shutdown_tx: watch::Sender<bool>,
+    shutdown_tx: watch::Sender<bool>,
+}
+
+/// A connection to the network, that can be shared by multiple [`Client`]s.
+///
+/// Connecting is the costly part of initializing a client, as the routing table has to be
+/// populated first. The connection stays alive for as long as the handle, or any client using it,
+/// is alive.
+///
+/// See [`Client::init_with_network`].
+#[derive(Clone)]
+pub struct NetworkHandle {
+    network: Network,
+    // Shutdown signal for child tasks. Sends signal when dropped.
+    shutdown_tx: watch::Sender<bool>,
+}
+
+impl NetworkHandle {
+    /// Connect to the network.
+    ///
+    /// This will block until [`close_group_size`] peers have been added to the routing table. The
+    /// [`ClientConfig::evm_network`] and [`ClientConfig::strategy`] are not used by the connection.
+    pub async fn connect(config: &ClientConfig) -> Result<Self, ConnectError> {
+        let (shutdown_tx, network, event_receiver) = build_client_and_run_swarm(
+            config.local,
+            config.transports,
+            config.routing_table_snapshot.clone(),
+            config.private_network.clone(),
+        );
+
+        let peers_args = PeersArgs {
+            disable_mainnet_contacts: config.local,
+            addrs: config.peers.clone().unwrap_or_default(),
+            local: config.local,
+            ..Default::default()
+        };
+
+        let peers = match peers_args.get_addrs(None, None).await {
+            Ok(peers) => peers,
+            Err(e) => return Err(e.into()),
+        };
+
+        let network_clone = network.clone();
+        let peers = peers.to_vec();
+        let _handle = ant_networking::time::spawn(async move {
+            for addr in peers {
+                if let Err(err) = network_clone.dial(addr.clone()).await {
+                    error!("Failed to dial addr={addr} with err: {err:?}");
+                };
+            }
+        });
+
+        // Wait until we have added a few peers to our routing table.
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        ant_networking::time::spawn(handle_event_receiver(
+            event_receiver,
+            sender,
+            shutdown_tx.subscribe(),
+        ));
+        receiver.await.expect("sender should not close")?;
+        debug!("Enough peers were added to our routing table, initialization complete");
+
+        Ok(Self {
+            network,
+            shutdown_tx,
+        })
+    }
 }
 
 /// Error returned by [`Client::init`].
@@ -111,7 +185,9 @@ pub enum PutError {
     #[error("Failed to self-encrypt data.")]
     SelfEncryption(#[from] crate::self_encryption::Error),
     #[error("A network error occurred.")]
-    Network(#[from] NetworkError),
+    Network(NetworkError),
+    #[error(transparent)]
+    QuorumFailed(#[from] QuorumFailure),
     #[error("Error occurred during cost estimation.")]
     CostError(#[from] CostError),
     #[error("Error occurred during payment.")]
@@ -128,6 +204,52 @@ pub enum PutError {
     PayeesMissing,
 }
 
+impl From<NetworkError> for PutError {
+    fn from(err: NetworkError) -> Self {
+        match QuorumFailure::try_from(err) {
+            Ok(failure) => PutError::QuorumFailed(failure),
+            Err(err) => PutError::Network(err),
+        }
+    }
+}
+
+/// A record was not stored by enough nodes, this is returned by the puts of all the data types.
+#[derive(Debug, thiserror::Error)]
+#[error("Record {address:?} was only stored by {stored} nodes out of the {quorum} required. Rejected by: {rejections:?}")]
+pub struct QuorumFailure {
+    pub address: NetworkAddress,
+    pub stored: usize,
+    pub quorum: usize,
+    /// The reasons given by the nodes that refused to store the record
+    pub rejections: BTreeMap<PeerId, PutRecordRejection>,
+    /// The nodes that could not be reached or sent back an invalid acknowledgement
+    pub unacknowledged: Vec<PeerId>,
+}
+
+impl TryFrom<NetworkError> for QuorumFailure {
+    type Error = NetworkError;
+
+    /// Take the quorum failure out of a network error, other errors are given back as is
+    fn try_from(err: NetworkError) -> Result<Self, Self::Error> {
+        match err {
+            NetworkError::PutRecordQuorumFailed {
+                address,
+                stored,
+                quorum,
+                rejections,
+                unacknowledged,
+            } => Ok(QuorumFailure {
+                address,
+                stored,
+                quorum,
+                rejections,
+                unacknowledged,
+            }),
+            err => Err(err),
+        }
+    }
+}
+
 /// Errors that can occur during the get operation.
 #[derive(Debug, thiserror::Error)]
 pub enum GetError {
@@ -180,13 +302,16 @@ impl Client {
             peers: Some(peers),
             evm_network: EvmNetwork::new(local).unwrap_or_default(),
             strategy: Default::default(),
+            transports: Default::default(),
+            routing_table_snapshot: None,
+            private_network: Default::default(),
         })
         .await
     }
 
     /// Initialize the client with the given configuration.
     ///
-    /// This will block until [`CLOSE_GROUP_SIZE`] have been added to the routing table.
+    /// This will block until [`close_group_size`] peers have been added to the routing table.
     ///
     /// See [`ClientConfig`].
     ///
@@ -199,47 +324,53 @@ impl Client {
     /// # }
     /// ```
     pub async fn init_with_config(config: ClientConfig) -> Result<Self, ConnectError> {
-        let (shutdown_tx, network, event_receiver) = build_client_and_run_swarm(config.local);
-
-        let peers_args = PeersArgs {
-            disable_mainnet_contacts: config.local,
-            addrs: config.peers.unwrap_or_default(),
-            local: config.local,
-            ..Default::default()
-        };
-
-        let peers = match peers_args.get_addrs(None, None).await {
-            Ok(peers) => peers,
-            Err(e) => return Err(e.into()),
-        };
-
-        let network_clone = network.clone();
-        let peers = peers.to_vec();
-        let _handle = ant_networking::time::spawn(async move {
-            for addr in peers {
-                if let Err(err) = network_clone.dial(addr.clone()).await {
-                    error!("Failed to dial addr={addr} with err: {err:?}");
-                };
-            }
-        });
-
-        // Wait until we have added a few peers to our routing table.
-        let (sender, receiver) = futures::channel::oneshot::channel();
-        ant_networking::time::spawn(handle_event_receiver(
-            event_receiver,
-            sender,
-            shutdown_tx.subscribe(),
-        ));
-        receiver.await.expect("sender should not close")?;
-        debug!("Enough peers were added to our routing table, initialization complete");
-
-        Ok(Self {
+        let network = NetworkHandle::connect(&config).await?;
+        Ok(Self::init_with_network(
             network,
+            config.evm_network,
+            config.strategy,
+        ))
+    }
+
+    /// Initialize a client on top of an existing connection to the network.
+    ///
+    /// This doesn't wait for anything, which makes it cheap to create many clients, possibly with
+    /// different strategies, that share the same connection.
+    ///
+    /// ```no_run
+    /// use autonomi::{Client, ClientConfig, NetworkHandle};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ClientConfig::default();
+    /// let network = NetworkHandle::connect(&config).await?;
+    ///
+    /// let client = Client::init_with_network(network.clone(), config.evm_network.clone(), Default::default());
+    /// let other_client = Client::init_with_network(network, config.evm_network, Default::default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn init_with_network(
+        network: NetworkHandle,
+        evm_network: EvmNetwork,
+        strategy: ClientOperatingStrategy,
+    ) -> Self {
+        Self {
+            network: network.network,
             client_event_sender: None,
-            evm_network: config.evm_network,
-            config: config.strategy,
-            _shutdown_tx: shutdown_tx,
-        })
+            evm_network,
+            config: strategy,
+            shutdown_tx: network.shutdown_tx,
+        }
+    }
+
+    /// The connection to the network used by this client, to share it with other clients.
+    ///
+    /// See [`Client::init_with_network`].
+    pub fn network_handle(&self) -> NetworkHandle {
+        NetworkHandle {
+            network: self.network.clone(),
+            shutdown_tx: self.shutdown_tx.clone(),
+        }
     }
 
     /// Receive events from the client.
@@ -252,15 +383,65 @@ impl Client {
         client_event_receiver
     }
 
+    /// Trace the network queries made by the client, i.e. which peers were contacted, in what
+    /// order, how long they took to answer and the retries, each sent as a
+    /// [`ClientEvent::QueryTrace`].
+    ///
+    /// Requires the client events to be enabled first, see [`Client::enable_client_events`].
+    /// The other clients sharing the connection are left untraced.
+    pub fn enable_query_tracing(&mut self) {
+        let Some(client_event_sender) = self.client_event_sender.clone() else {
+            warn!("Client events are not enabled, the queries won't be traced");
+            return;
+        };
+        let tracer = QueryTracer::new(move |trace| {
+            let client_event_sender = client_event_sender.clone();
+            let _handle = ant_networking::time::spawn(async move {
+                if let Err(err) = client_event_sender
+                    .send(ClientEvent::QueryTrace(trace))
+                    .await
+                {
+                    error!("Failed to send client event: {err:?}");
+                }
+            });
+        });
+        self.network = self.network.with_query_tracer(tracer);
+        debug!("Query tracing is enabled");
+    }
+
     pub fn evm_network(&self) -> &EvmNetwork {
         &self.evm_network
     }
+
+    /// Get the closest peers to the address, cross checked between the signed answers of the
+    /// peers closest to it.
+    ///
+    /// Use [`VerifiedClosestPeers::is_consistent`] to tell whether some of the peers lied about
+    /// the neighbourhood of the address, or didn't answer with a valid signature.
+    pub async fn get_verified_closest_peers(
+        &self,
+        address: &NetworkAddress,
+    ) -> Result<VerifiedClosestPeers, GetError> {
+        Ok(self.network.get_verified_closest_peers(address).await?)
+    }
 }
 
 fn build_client_and_run_swarm(
     local: bool,
+    transports: TransportConfig,
+    routing_table_snapshot: Option<PathBuf>,
+    private_network: PrivateNetwork,
 ) -> (watch::Sender<bool>, Network, mpsc::Receiver<NetworkEvent>) {
-    let mut network_builder = NetworkBuilder::new(Keypair::generate_ed25519(), local);
+    let keypair = private_network
+        .identity
+        .clone()
+        .unwrap_or_else(Keypair::generate_ed25519);
+    let mut network_builder = NetworkBuilder::new(keypair, local);
+    network_builder.transports(transports);
+    network_builder.private_network(private_network);
+    if let Some(path) = routing_table_snapshot {
+        network_builder.routing_table_snapshot(path);
+    }
 
     if let Ok(mut config) = BootstrapCacheConfig::default_config(local) {
         if local {
@@ -334,7 +515,7 @@ async fn handle_event_receiver(
                     NetworkEvent::PeerAdded(_peer_id, peers_len) => {
                         tracing::trace!("Peer added: {peers_len} in routing table");
 
-                        if peers_len >= CLOSE_GROUP_SIZE {
+                        if peers_len >= close_group_size() {
                             if let Some(sender) = sender.take() {
                                 sender.send(Ok(())).expect("receiver should not close");
                             }
@@ -360,6 +541,8 @@ async fn handle_event_receiver(
 #[derive(Debug, Clone)]
 pub enum ClientEvent {
     UploadComplete(UploadSummary),
+    /// The trace of a network query, see [`Client::enable_query_tracing`]
+    QueryTrace(QueryTrace),
 }
 
 /// Summary of an upload operation.
@@ -26,6 +26,8 @@ pub enum PayError {
     SelfEncryption(#[from] crate::self_encryption::Error),
     #[error("Cost error: {0:?}")]
     Cost(#[from] CostError),
+    #[error("No payment was made for {0:?}, it is missing from the receipt")]
+    MissingPayment(XorName),
 }
 
 pub fn receipt_from_store_quotes(quotes: StoreQuote) -> Receipt {
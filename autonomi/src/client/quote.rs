@@ -12,7 +12,10 @@ use crate::client::utils::process_tasks_with_max_concurrency;
 use ant_evm::payment_vault::get_market_price;
 use ant_evm::{Amount, PaymentQuote, QuotePayment, QuotingMetrics};
 use ant_networking::{Network, NetworkError};
-use ant_protocol::{storage::ChunkAddress, NetworkAddress, CLOSE_GROUP_SIZE};
+use ant_protocol::{
+    close_group_size, min_quotes_to_pay, network_params::PAID_QUOTES, storage::ChunkAddress,
+    NetworkAddress,
+};
 use libp2p::PeerId;
 use std::collections::HashMap;
 use xor_name::XorName;
@@ -125,11 +128,11 @@ impl Client {
 
             let target_addr = NetworkAddress::from_chunk_address(ChunkAddress::new(content_addr));
 
-            // Only keep the quotes of the 5 closest nodes
+            // Only keep the quotes of the close group
             raw_quotes.sort_by_key(|(peer_id, _)| {
                 NetworkAddress::from_peer(*peer_id).distance(&target_addr)
             });
-            raw_quotes.truncate(CLOSE_GROUP_SIZE);
+            raw_quotes.truncate(close_group_size());
 
             for (peer_id, quote) in raw_quotes.into_iter() {
                 all_quotes.push((content_addr, peer_id, quote));
@@ -171,28 +174,31 @@ impl Client {
 
         let mut quotes_to_pay_per_addr = HashMap::new();
 
-        const MINIMUM_QUOTES_TO_PAY: usize = 5;
+        let minimum_quotes_to_pay = min_quotes_to_pay();
+        // Only the most expensive quotes are paid, the cheaper ones are part of the payment for free
+        let free_quotes = minimum_quotes_to_pay.saturating_sub(PAID_QUOTES);
 
         for (content_addr, quotes) in quotes_per_addr {
-            if quotes.len() >= MINIMUM_QUOTES_TO_PAY {
-                let (p1, q1, _) = &quotes[0];
-                let (p2, q2, _) = &quotes[1];
-
-                quotes_to_pay_per_addr.insert(
-                    content_addr,
-                    QuoteForAddress(vec![
-                        (*p1, q1.clone(), Amount::ZERO),
-                        (*p2, q2.clone(), Amount::ZERO),
-                        quotes[2].clone(),
-                        quotes[3].clone(),
-                        quotes[4].clone(),
-                    ]),
-                );
+            if quotes.len() >= minimum_quotes_to_pay {
+                let quotes_to_pay = quotes
+                    .into_iter()
+                    .take(minimum_quotes_to_pay)
+                    .enumerate()
+                    .map(|(index, (peer_id, quote, price))| {
+                        if index < free_quotes {
+                            (peer_id, quote, Amount::ZERO)
+                        } else {
+                            (peer_id, quote, price)
+                        }
+                    })
+                    .collect();
+
+                quotes_to_pay_per_addr.insert(content_addr, QuoteForAddress(quotes_to_pay));
             } else {
                 return Err(CostError::NotEnoughNodeQuotes(
                     content_addr,
                     quotes.len(),
-                    MINIMUM_QUOTES_TO_PAY,
+                    minimum_quotes_to_pay,
                 ));
             }
         }
@@ -234,10 +240,10 @@ async fn fetch_store_quote_with_retries(
                     // Empty quotes indicates the record already exists.
                     break Ok((content_addr, quote));
                 }
-                if quote.len() < CLOSE_GROUP_SIZE {
+                if quote.len() < close_group_size() {
                     retries += 1;
-                    error!("Error while fetching store quote: not enough quotes ({}/{CLOSE_GROUP_SIZE}), retry #{retries}, quotes {quote:?}",
-                        quote.len());
+                    error!("Error while fetching store quote: not enough quotes ({}/{}), retry #{retries}, quotes {quote:?}",
+                        quote.len(), close_group_size());
                     if retries > 2 {
                         break Err(CostError::CouldNotGetStoreQuote(content_addr));
                     }
@@ -6,7 +6,7 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures::stream::{self, FuturesUnordered, StreamExt};
 use std::future::Future;
 
 pub(crate) async fn process_tasks_with_max_concurrency<I, R>(tasks: I, batch_size: usize) -> Vec<R>
@@ -35,3 +35,16 @@ where
 
     results
 }
+
+/// Like [`process_tasks_with_max_concurrency`], but the results are returned in the order of the tasks
+pub(crate) async fn process_ordered_tasks_with_max_concurrency<I, R>(
+    tasks: I,
+    batch_size: usize,
+) -> Vec<R>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = R> + Send,
+    R: Send,
+{
+    stream::iter(tasks).buffered(batch_size).collect().await
+}
@@ -56,7 +56,16 @@ pub mod client;
 pub mod self_encryption;
 
 /// Client Operation config types
-pub use ant_networking::{ResponseQuorum, RetryStrategy};
+pub use ant_networking::{PrivateNetwork, ResponseQuorum, RetryStrategy, TransportConfig};
+
+/// The view of the network around an address
+pub use ant_networking::VerifiedClosestPeers;
+pub use ant_protocol::NetworkAddress;
+
+/// The traces of the network queries
+pub use ant_networking::{
+    AttemptTrace, KadQueryStats, PeerOutcome, PeerTrace, QueryKind, QueryTrace,
+};
 
 // The Network data types
 pub use client::data_types::chunk;
@@ -65,8 +74,10 @@ pub use client::data_types::pointer;
 pub use client::data_types::scratchpad;
 
 // The high-level data types
+pub use client::blob;
 pub use client::data;
 pub use client::files;
+pub use client::name;
 pub use client::register;
 pub use client::vault;
 
@@ -102,8 +113,12 @@ pub use client::{
     data_types::scratchpad::Scratchpad,
     data_types::scratchpad::ScratchpadAddress,
 
+    // Key management
+    keyring::Keyring,
+
     // Client
     Client,
+    NetworkHandle,
 };
 
 #[cfg(feature = "extension-module")]
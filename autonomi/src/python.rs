@@ -1,4 +1,4 @@
-use std::{path::PathBuf, str::FromStr, sync::Arc};
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr, sync::Arc};
 
 use crate::{
     client::{
@@ -1688,6 +1688,40 @@ impl PyMetadata {
     fn set_size(&mut self, value: u64) {
         self.inner.size = value;
     }
+
+    /// Get the MIME type of the file, if known
+    #[getter]
+    fn get_mime_type(&self) -> Option<String> {
+        self.inner.mime_type.clone()
+    }
+
+    /// Set the MIME type of the file
+    #[setter]
+    fn set_mime_type(&mut self, value: Option<String>) {
+        self.inner.mime_type = value;
+    }
+
+    /// Get the SHA-256 hash of the file content as a hex string, if known
+    #[getter]
+    fn get_sha256(&self) -> Option<String> {
+        self.inner.sha256_hex()
+    }
+
+    /// Get the custom tags of the file
+    #[getter]
+    fn get_tags(&self) -> BTreeMap<String, String> {
+        self.inner.tags.clone()
+    }
+
+    /// Set a custom tag
+    fn set_tag(&mut self, key: String, value: String) {
+        self.inner.tags.insert(key, value);
+    }
+
+    /// Check the content of the file against the stored SHA-256 hash, content without a stored hash is considered valid
+    fn verify_content(&self, content: Vec<u8>) -> bool {
+        self.inner.verify_content(&content)
+    }
 }
 
 /// A public archive containing files that can be accessed by anyone on the network.
@@ -1936,6 +1970,30 @@ impl PyClientConfig {
         self.inner.evm_network = network.inner;
     }
 
+    /// Whether the TCP transport is enabled alongside QUIC.
+    #[getter]
+    fn get_tcp(&self) -> bool {
+        self.inner.transports.tcp.is_some()
+    }
+
+    /// Enable the TCP transport alongside QUIC.
+    #[setter]
+    fn set_tcp(&mut self, value: bool) {
+        self.inner.transports.tcp = value.then_some(0);
+    }
+
+    /// Whether the WebSocket transport is enabled alongside QUIC.
+    #[getter]
+    fn get_websocket(&self) -> bool {
+        self.inner.transports.websocket.is_some()
+    }
+
+    /// Enable the WebSocket transport alongside QUIC.
+    #[setter]
+    fn set_websocket(&mut self, value: bool) {
+        self.inner.transports.websocket = value.then_some(0);
+    }
+
     // TODO
     // fn strategy() { }
 }
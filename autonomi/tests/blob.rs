@@ -0,0 +1,144 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_logging::LogBuilder;
+use autonomi::blob::{BlobAddress, BlobError};
+use autonomi::client::payment::PaymentOption;
+use autonomi::{Bytes, Client};
+use eyre::Result;
+use serial_test::serial;
+use test_utils::{evm::get_funded_wallet, gen_random_data};
+
+#[tokio::test]
+#[serial]
+async fn blob_create_update_get() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("blob", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let payment_option = PaymentOption::from(&wallet);
+
+    let key = bls::SecretKey::random();
+    let content_type = 42;
+
+    // larger than a single scratchpad
+    let content = gen_random_data(10 * 1024 * 1024);
+    let cost = client
+        .blob_cost(&key.public_key(), content.len() as u64)
+        .await?;
+    println!("blob cost: {cost}");
+
+    let (cost, addr) = client
+        .blob_create(&key, content_type, content.clone(), payment_option.clone())
+        .await?;
+    assert_eq!(addr, BlobAddress::new(key.public_key()));
+    println!("blob create cost: {cost}");
+
+    // a key can only own one blob
+    let res = client
+        .blob_create(&key, content_type, content.clone(), payment_option.clone())
+        .await;
+    assert!(matches!(res, Err(BlobError::AlreadyExists(a)) if a == addr));
+
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got = client.blob_get(&key).await?;
+    assert_eq!(got.data, content);
+    assert_eq!(got.content_type, content_type);
+    assert_eq!(got.version, 0);
+
+    // only the second generation of shards is left to pay for, until the blob grows
+    let cost_same_size = client
+        .blob_cost(&key.public_key(), content.len() as u64)
+        .await?;
+    let cost_larger = client
+        .blob_cost(&key.public_key(), 2 * content.len() as u64)
+        .await?;
+    println!("existing blob cost: {cost_same_size}, when growing: {cost_larger}");
+    assert!(!cost_same_size.is_zero());
+    assert!(cost_larger > cost_same_size);
+
+    // shrink the blob
+    let smaller = Bytes::from("Secure Access For Everyone");
+    let cost = client
+        .blob_update(&key, content_type, smaller.clone(), payment_option.clone())
+        .await?;
+    println!("blob update 1 cost: {cost}");
+
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got = client.blob_get(&key).await?;
+    assert_eq!(got.data, smaller);
+    assert_eq!(got.version, 1);
+
+    // grow the blob beyond its previous size
+    let larger = gen_random_data(14 * 1024 * 1024);
+    let cost = client
+        .blob_update(&key, content_type, larger.clone(), payment_option.clone())
+        .await?;
+    println!("blob update 2 cost: {cost}");
+
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got = client.blob_get(&key).await?;
+    assert_eq!(got.data, larger);
+    assert_eq!(got.version, 2);
+
+    // zero-filling leaves no stale data behind
+    let info = client.blob_info(&key).await?;
+    assert!(info.garbage_bytes > 0);
+    let _ = client
+        .blob_update_and_zero_fill(&key, content_type, smaller.clone(), payment_option)
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    let info = client.blob_info(&key).await?;
+    assert_eq!(info.version, 3);
+    assert_eq!(info.size, smaller.len() as u64);
+    assert_eq!(info.garbage_bytes, 0);
+    assert_eq!(client.blob_get(&key).await?.data, smaller);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn blob_concurrent_updates() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("blob", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let payment_option = PaymentOption::from(&wallet);
+
+    let key = bls::SecretKey::random();
+    let content = gen_random_data(5 * 1024 * 1024);
+    let _ = client
+        .blob_create(&key, 0, content, payment_option.clone())
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // two writers starting from the same version: only one of them writes its shards
+    let first = gen_random_data(5 * 1024 * 1024);
+    let second = gen_random_data(5 * 1024 * 1024);
+    let (first_res, second_res) = tokio::join!(
+        client.blob_update(&key, 0, first.clone(), payment_option.clone()),
+        client.blob_update(&key, 0, second.clone(), payment_option),
+    );
+    println!("first update: {first_res:?}, second update: {second_res:?}");
+    let winner = match (first_res, second_res) {
+        (Ok(_), Err(_)) => first,
+        (Err(_), Ok(_)) => second,
+        results => eyre::bail!("exactly one update should succeed: {results:?}"),
+    };
+
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    let got = client.blob_get(&key).await?;
+    assert_eq!(got.version, 1);
+    assert_eq!(got.data, winner);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_logging::LogBuilder;
+use autonomi::{chunk::ChunkAddress, Client, NetworkAddress};
+use eyre::Result;
+use serial_test::serial;
+use xor_name::XorName;
+
+#[tokio::test]
+#[serial]
+async fn verified_closest_peers() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("closest_peers", false);
+
+    let client = Client::init_local().await?;
+    let address = NetworkAddress::from_chunk_address(ChunkAddress::new(XorName::random(
+        &mut rand::thread_rng(),
+    )));
+
+    let verified = client.get_verified_closest_peers(&address).await?;
+    println!("Verified closest peers: {verified:?}");
+    assert!(!verified.closest_peers.is_empty());
+    // the nodes of a local network are all honest
+    assert!(verified.is_consistent());
+
+    Ok(())
+}
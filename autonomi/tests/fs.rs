@@ -7,7 +7,9 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use ant_logging::LogBuilder;
-use autonomi::Client;
+use autonomi::client::files::{SiteLookup, SiteRoot};
+use autonomi::client::pointer::PointerTarget;
+use autonomi::{ChunkAddress, Client, SecretKey};
 use eyre::Result;
 use serial_test::serial;
 use sha2::{Digest, Sha256};
@@ -111,3 +113,36 @@ async fn file_into_vault() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn site_through_pointer() -> Result<()> {
+    let _log_appender_guard =
+        LogBuilder::init_single_threaded_tokio_test("site_through_pointer", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let (_cost, archive_addr) = client
+        .dir_and_archive_upload_public("tests/file/test_dir".into(), &wallet)
+        .await?;
+
+    let key = SecretKey::random();
+    let target = PointerTarget::ChunkAddress(ChunkAddress::new(archive_addr));
+    let (_cost, pointer_addr) = client.pointer_create(&key, target, wallet.into()).await?;
+
+    sleep(Duration::from_secs(5)).await;
+
+    let root = SiteRoot::Pointer(pointer_addr);
+    let site = client.site_get(&root).await?;
+    assert_eq!(site.archive_addr(), &archive_addr);
+
+    // the uploaded directory is the root of the site
+    let SiteLookup::File { addr, meta, .. } = site.lookup("dir_a/example_file_c") else {
+        panic!("file not found in site");
+    };
+    let content = client.data_get_public(addr).await?;
+    assert!(meta.verify_content(&content));
+    assert_eq!(site.lookup("dir_a/missing"), SiteLookup::NotFound);
+    Ok(())
+}
@@ -9,7 +9,7 @@
 use ant_logging::LogBuilder;
 use autonomi::{
     client::{
-        graph::{GraphEntry, GraphError},
+        graph::{GraphEntry, GraphError, GraphTraversal, TraversalOrder},
         payment::PaymentOption,
     },
     Client,
@@ -81,3 +81,126 @@ async fn graph_entry_put() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn graph_traverse() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("graph_traverse", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    // a diamond: root -> left, right -> tail, with the tail linking to a missing entry
+    let [root, left, right, tail, missing] = std::array::from_fn(|_| bls::SecretKey::random());
+    let entries = [
+        GraphEntry::new(
+            &root,
+            vec![],
+            [0; 32],
+            vec![(left.public_key(), [0; 32]), (right.public_key(), [0; 32])],
+        ),
+        GraphEntry::new(
+            &left,
+            vec![root.public_key()],
+            [1; 32],
+            vec![(tail.public_key(), [0; 32])],
+        ),
+        GraphEntry::new(
+            &right,
+            vec![root.public_key()],
+            [2; 32],
+            vec![(tail.public_key(), [0; 32])],
+        ),
+        GraphEntry::new(
+            &tail,
+            vec![left.public_key(), right.public_key()],
+            [3; 32],
+            vec![(missing.public_key(), [0; 32])],
+        ),
+    ];
+    for entry in entries.iter() {
+        client
+            .graph_entry_put(entry.clone(), PaymentOption::from(&wallet))
+            .await?;
+    }
+
+    // wait for the graph entries to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let root_addr = entries[0].address();
+    let dag = client
+        .graph_traverse(&root_addr, GraphTraversal::descendants())
+        .await?;
+    assert_eq!(dag.nodes.len(), 4);
+    assert_eq!(dag.order[0], root_addr);
+    assert_eq!(dag.order[3], entries[3].address());
+    assert_eq!(dag.missing.len(), 1);
+    assert_eq!(dag.edges.len(), 5);
+    assert!(dag.cycles.is_empty());
+    assert_eq!(dag.forks().count(), 0);
+
+    let dfs = client
+        .graph_traverse(
+            &root_addr,
+            GraphTraversal::descendants()
+                .with_order(TraversalOrder::DepthFirst)
+                .with_max_depth(1),
+        )
+        .await?;
+    assert_eq!(dfs.nodes.len(), 3);
+    assert!(dfs.truncated);
+
+    let ancestors = client.graph_ancestors(&entries[3].address(), None).await?;
+    assert_eq!(ancestors.nodes.len(), 4);
+    assert_eq!(ancestors.heads(), vec![root_addr]);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn graph_entry_put_batch() -> Result<()> {
+    let _log_appender_guard =
+        LogBuilder::init_single_threaded_tokio_test("graph_entry_put_batch", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    // a chain of entries
+    let keys: Vec<_> = (0..10).map(|_| bls::SecretKey::random()).collect();
+    let entries: Vec<_> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let parents = if i > 0 {
+                vec![keys[i - 1].public_key()]
+            } else {
+                vec![]
+            };
+            let descendants = keys
+                .get(i + 1)
+                .map(|next| vec![(next.public_key(), [0; 32])])
+                .unwrap_or_default();
+            GraphEntry::new(key, parents, [i as u8; 32], descendants)
+        })
+        .collect();
+
+    let results = client
+        .graph_entry_put_batch(entries.clone(), PaymentOption::from(&wallet))
+        .await?;
+    assert_eq!(results.len(), entries.len());
+    for (entry, result) in entries.iter().zip(results) {
+        let (_cost, addr) = result?;
+        assert_eq!(addr, entry.address());
+    }
+
+    // wait for the graph entries to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let dag = client
+        .graph_traverse(&entries[0].address(), GraphTraversal::descendants())
+        .await?;
+    assert_eq!(dag.nodes.len(), entries.len());
+
+    Ok(())
+}
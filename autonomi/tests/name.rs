@@ -0,0 +1,65 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_logging::LogBuilder;
+use autonomi::{
+    chunk::ChunkAddress, client::pointer::PointerTarget, name::NameError, Client, SecretKey,
+};
+use eyre::Result;
+use serial_test::serial;
+use std::time::Duration;
+use test_utils::evm::get_funded_wallet;
+use tokio::time::sleep;
+use xor_name::XorName;
+
+#[tokio::test]
+#[serial]
+async fn name_register_resolve_update() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("name", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let name = format!("test-name-{}", rand::random::<u32>());
+    let owner = Client::name_owner_key(&SecretKey::random(), &name)?;
+    let target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+
+    let (cost, resolution) = client
+        .name_register(&name, &owner, target.clone(), wallet.clone().into())
+        .await?;
+    println!("name registered for {cost}");
+    assert_eq!(resolution.owner, owner.public_key());
+    assert_eq!(resolution.target, target);
+
+    sleep(Duration::from_secs(5)).await;
+
+    // names are case insensitive
+    let resolution = client.name_resolve(&name.to_uppercase()).await?;
+    assert_eq!(resolution.target, target);
+
+    // only the owner can update the name
+    let new_target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+    let other = SecretKey::random();
+    let res = client.name_update(&name, &other, new_target.clone()).await;
+    assert!(matches!(res, Err(NameError::NotOwner(_))));
+    client
+        .name_update(&name, &owner, new_target.clone())
+        .await?;
+
+    sleep(Duration::from_secs(5)).await;
+    assert_eq!(client.name_resolve(&name).await?.target, new_target);
+
+    // the name cannot be registered again
+    let res = client
+        .name_register(&name, &other, target, wallet.into())
+        .await;
+    assert!(matches!(res, Err(NameError::AlreadyRegistered(_))));
+    Ok(())
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_logging::LogBuilder;
+use autonomi::client::config::ClientOperatingStrategy;
+use autonomi::client::payment::PaymentOption;
+use autonomi::{client::chunk::Chunk, Bytes, Client, ClientConfig, NetworkHandle};
+use eyre::Result;
+use serial_test::serial;
+use std::time::{Duration, Instant};
+use test_utils::evm::get_funded_wallet;
+
+#[tokio::test]
+#[serial]
+async fn clients_share_network_handle() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("network_handle", false);
+
+    let config = ClientConfig::local(None);
+    let network = NetworkHandle::connect(&config).await?;
+
+    let client = Client::init_with_network(
+        network.clone(),
+        config.evm_network.clone(),
+        ClientOperatingStrategy::default(),
+    );
+    // the second client doesn't have to bootstrap, and uses its own strategy
+    let start = Instant::now();
+    let other_client = Client::init_with_network(
+        client.network_handle(),
+        config.evm_network.clone(),
+        ClientOperatingStrategy::new(),
+    );
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    let wallet = get_funded_wallet();
+    let chunk = Chunk::new(Bytes::from("Hello, shared network!"));
+    let (_cost, addr) = client
+        .chunk_put(&chunk, PaymentOption::from(&wallet))
+        .await?;
+
+    // wait for the chunk to be replicated
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    // the connection outlives the clients that are dropped
+    drop(client);
+    drop(network);
+    let got = other_client.chunk_get(&addr).await?;
+    assert_eq!(got, chunk);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn client_warm_start_from_routing_table_snapshot() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("network_handle", false);
+
+    let snapshot_path =
+        std::env::temp_dir().join(format!("routing_table_snapshot_{}", std::process::id()));
+    let mut config = ClientConfig::local(None);
+    config.routing_table_snapshot = Some(snapshot_path.clone());
+
+    // the snapshot is saved when the connection is closed
+    let client = Client::init_with_config(config.clone()).await?;
+    drop(client);
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert!(snapshot_path.exists());
+
+    // the routing table is seeded from the snapshot, no need to wait for the bootstrap
+    let start = Instant::now();
+    let client = Client::init_with_config(config).await?;
+    println!("Warm start took {:?}", start.elapsed());
+    let _ = client
+        .chunk_cost(Chunk::new(Bytes::from("Hello, warm start!")).address())
+        .await?;
+
+    std::fs::remove_file(snapshot_path)?;
+    Ok(())
+}
@@ -7,11 +7,13 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use ant_logging::LogBuilder;
+use ant_protocol::messages::PutRecordRejection;
 use autonomi::client::payment::PaymentOption;
 use autonomi::AttoTokens;
 use autonomi::{
     chunk::ChunkAddress,
-    client::pointer::{Pointer, PointerTarget},
+    client::pointer::{Pointer, PointerError, PointerTarget},
+    client::QuorumFailure,
     Client,
 };
 use eyre::Result;
@@ -116,3 +118,160 @@ async fn pointer_put() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn pointer_put_outdated_is_rejected() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("pointer", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let key = bls::SecretKey::random();
+    let target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+    let (_, addr) = client
+        .pointer_create(&key, target, PaymentOption::from(&wallet))
+        .await?;
+    client
+        .pointer_update(&key, PointerTarget::PointerAddress(addr))
+        .await?;
+
+    // wait for the pointer to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // nodes refuse a pointer with an older counter and say why
+    let outdated = Pointer::new(
+        &key,
+        0,
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng()))),
+    );
+    let res = client
+        .pointer_put(outdated, PaymentOption::from(&wallet))
+        .await;
+    match res {
+        Err(PointerError::QuorumFailed(QuorumFailure {
+            stored, rejections, ..
+        })) => {
+            assert_eq!(stored, 0);
+            assert!(!rejections.is_empty());
+            assert!(rejections
+                .values()
+                .all(|rejection| *rejection == PutRecordRejection::OutdatedCounter));
+        }
+        other => panic!("Expected the outdated pointer to be rejected, got: {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn pointer_resolve() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("pointer", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    // create a chain of two pointers leading to a chunk address
+    let chunk_target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+    let tail_key = bls::SecretKey::random();
+    let (_, tail_addr) = client
+        .pointer_create(
+            &tail_key,
+            chunk_target.clone(),
+            PaymentOption::from(&wallet),
+        )
+        .await?;
+    let head_key = bls::SecretKey::random();
+    let (_, head_addr) = client
+        .pointer_create(
+            &head_key,
+            PointerTarget::PointerAddress(tail_addr),
+            PaymentOption::from(&wallet),
+        )
+        .await?;
+
+    // wait for the pointers to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // resolve the chain down to the chunk
+    let resolution = client.pointer_resolve(&head_addr, 8).await?;
+    assert_eq!(resolution.target, chunk_target);
+    let path: Vec<_> = resolution.path.iter().map(|p| p.address()).collect();
+    assert_eq!(path, vec![head_addr, tail_addr]);
+    println!("pointer chain resolved");
+
+    // a max depth smaller than the chain fails
+    let res = client.pointer_resolve(&head_addr, 1).await;
+    assert!(matches!(res, Err(PointerError::MaxDepthExceeded(1))));
+
+    // make the tail point back to the head to create a cycle
+    client
+        .pointer_update(&tail_key, PointerTarget::PointerAddress(head_addr))
+        .await?;
+
+    // wait for the pointer to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let res = client.pointer_resolve(&head_addr, 8).await;
+    match res {
+        Err(PointerError::Cycle(cycle)) => {
+            assert_eq!(cycle, vec![head_addr, tail_addr, head_addr]);
+        }
+        other => panic!("Expected a pointer cycle error, got: {other:?}"),
+    }
+    println!("pointer cycle detected");
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn pointer_create_batch() -> Result<()> {
+    let _log_appender_guard =
+        LogBuilder::init_single_threaded_tokio_test("pointer_create_batch", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    // create an existing pointer
+    let existing = bls::SecretKey::random();
+    let target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+    client
+        .pointer_create(&existing, target.clone(), PaymentOption::from(&wallet))
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // create a batch of new pointers along with the existing one
+    let mut pointers: Vec<_> = (0..10)
+        .map(|_| (bls::SecretKey::random(), target.clone()))
+        .collect();
+    pointers.push((existing.clone(), target.clone()));
+    let results = client
+        .pointer_create_batch(pointers.clone(), PaymentOption::from(&wallet))
+        .await?;
+    assert_eq!(results.len(), pointers.len());
+
+    // wait for the pointers to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    for ((owner, _), result) in pointers.iter().zip(results).take(10) {
+        let (_cost, addr) = result?;
+        let got = client.pointer_get(&addr).await?;
+        assert_eq!(got.owner(), &owner.public_key());
+        assert_eq!(got.target(), &target);
+    }
+    let existing_addr = autonomi::PointerAddress::from_owner(existing.public_key());
+    assert!(matches!(
+        client
+            .pointer_create_batch(vec![(existing, target)], PaymentOption::from(&wallet))
+            .await?
+            .as_slice(),
+        [Err(PointerError::PointerAlreadyExists(addr))] if *addr == existing_addr
+    ));
+
+    Ok(())
+}
@@ -211,3 +211,183 @@ async fn scratchpad_errors() -> Result<()> {
     assert_eq!(got_content, content);
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn scratchpad_compare_and_swap() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("scratchpad", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let key = bls::SecretKey::random();
+    let content_type = 42;
+
+    // create the scratchpad holding a counter
+    let payment_option = PaymentOption::from(&wallet);
+    let (_cost, addr) = client
+        .scratchpad_create(&key, content_type, &Bytes::from("0"), payment_option)
+        .await?;
+
+    // wait for the scratchpad to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // update with the wrong expected counter fails
+    let res = client
+        .scratchpad_update_if(&key, content_type, &Bytes::from("1"), 5)
+        .await;
+    println!("Scratchpad update_if should fail here: {res:?}");
+    assert!(matches!(
+        res,
+        Err(ScratchpadError::Conflict {
+            expected: 5,
+            found: 0,
+            ..
+        })
+    ));
+
+    // update with the right expected counter succeeds
+    let new_counter = client
+        .scratchpad_update_if(&key, content_type, &Bytes::from("1"), 0)
+        .await?;
+    assert_eq!(new_counter, 1);
+
+    // wait for the scratchpad to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // the same expected counter is now outdated
+    let res = client
+        .scratchpad_update_if(&key, content_type, &Bytes::from("1"), 0)
+        .await;
+    assert!(matches!(
+        res,
+        Err(ScratchpadError::Conflict {
+            expected: 0,
+            found: 1,
+            ..
+        })
+    ));
+
+    // read-modify-write increments the stored counter
+    let written = client
+        .scratchpad_update_with(&key, content_type, 3, |data| {
+            let n: u64 = String::from_utf8_lossy(data).parse().unwrap_or(0);
+            Bytes::from((n + 1).to_string())
+        })
+        .await?;
+    assert_eq!(written, Bytes::from("2"));
+
+    // wait for the scratchpad to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got = client.scratchpad_get(&addr).await?;
+    assert_eq!(got.counter(), 2);
+    assert_eq!(got.decrypt_data(&key), Ok(Bytes::from("2")));
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn scratchpad_shared_readers() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("scratchpad", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let payment_option = PaymentOption::from(&wallet);
+
+    let owner = bls::SecretKey::random();
+    let alice = bls::SecretKey::random();
+    let bob = bls::SecretKey::random();
+    let content_type = 42;
+    let content = Bytes::from("shared with alice");
+
+    let (_cost, addr) = client
+        .scratchpad_create_shared(
+            &owner,
+            content_type,
+            &content,
+            &[alice.public_key()],
+            payment_option,
+        )
+        .await?;
+
+    // wait for the scratchpad to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    assert_eq!(client.scratchpad_get_shared(&addr, &owner).await?, content);
+    assert_eq!(client.scratchpad_get_shared(&addr, &alice).await?, content);
+    let res = client.scratchpad_get_shared(&addr, &bob).await;
+    assert!(matches!(res, Err(ScratchpadError::NotAReader(_))));
+
+    // grant access to bob
+    client
+        .scratchpad_add_reader(&owner, bob.public_key())
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    assert_eq!(client.scratchpad_get_shared(&addr, &bob).await?, content);
+    let readers = client.scratchpad_readers(&addr).await?;
+    assert_eq!(readers, vec![alice.public_key(), bob.public_key()]);
+
+    // revoke alice, the content stays readable by bob
+    client
+        .scratchpad_revoke_reader(&owner, &alice.public_key())
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    let res = client.scratchpad_get_shared(&addr, &alice).await;
+    assert!(matches!(res, Err(ScratchpadError::NotAReader(_))));
+    assert_eq!(client.scratchpad_get_shared(&addr, &bob).await?, content);
+
+    // updates keep the current readers
+    let content2 = Bytes::from("shared with bob");
+    client
+        .scratchpad_update_shared(&owner, content_type, &content2)
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    assert_eq!(client.scratchpad_get_shared(&addr, &bob).await?, content2);
+    assert_eq!(
+        client.scratchpad_readers(&addr).await?,
+        vec![bob.public_key()]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn scratchpad_create_batch() -> Result<()> {
+    let _log_appender_guard =
+        LogBuilder::init_single_threaded_tokio_test("scratchpad_create_batch", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let scratchpads: Vec<_> = (0..10u64)
+        .map(|i| {
+            let data = Bytes::from(format!("scratchpad {i}"));
+            (bls::SecretKey::random(), i, data)
+        })
+        .collect();
+    let results = client
+        .scratchpad_create_batch(scratchpads.clone(), PaymentOption::from(&wallet))
+        .await?;
+    assert_eq!(results.len(), scratchpads.len());
+
+    // wait for the scratchpads to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    for ((owner, content_type, data), result) in scratchpads.iter().zip(results) {
+        let (_cost, addr) = result?;
+        let got = client.scratchpad_get(&addr).await?;
+        assert_eq!(got.data_encoding(), *content_type);
+        assert_eq!(&got.decrypt_data(owner)?, data);
+    }
+
+    // creating them again fails for each of them
+    let results = client
+        .scratchpad_create_batch(scratchpads, PaymentOption::from(&wallet))
+        .await?;
+    assert!(results
+        .iter()
+        .all(|res| matches!(res, Err(ScratchpadError::ScratchpadAlreadyExists(_)))));
+
+    Ok(())
+}
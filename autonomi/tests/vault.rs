@@ -98,3 +98,73 @@ async fn vault_expand() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn vault_info_and_zero_fill() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("vault", false);
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let main_key = bls::SecretKey::random();
+
+    // no vault yet
+    let info = client.vault_info(&main_key).await?;
+    assert_eq!(info.scratchpads_created, 0);
+
+    let content_type = app_name_to_vault_content_type("TestData");
+    let large_content = gen_random_data(10 * 1024 * 1024);
+    let _ = client
+        .write_bytes_to_vault(
+            large_content.clone(),
+            wallet.clone().into(),
+            &main_key,
+            content_type,
+        )
+        .await?;
+
+    let info = client.vault_info(&main_key).await?;
+    println!("Vault info after 10MB write: {info:?}");
+    assert_eq!(info.content_type, content_type);
+    assert_eq!(info.used_bytes, large_content.len() as u64);
+    assert_eq!(info.scratchpads_used, 3);
+    assert_eq!(info.scratchpads_created, 3);
+    assert_eq!(info.graph_entries, 1);
+    assert_eq!(info.garbage_bytes, 0);
+
+    // shrinking leaves stale data behind
+    let small_content = gen_random_data(1024);
+    let _ = client
+        .write_bytes_to_vault(
+            small_content.clone(),
+            wallet.clone().into(),
+            &main_key,
+            content_type,
+        )
+        .await?;
+    let info = client.vault_info(&main_key).await?;
+    println!("Vault info after shrinking: {info:?}");
+    assert_eq!(info.used_bytes, small_content.len() as u64);
+    assert_eq!(info.scratchpads_used, 1);
+    assert_eq!(info.scratchpads_created, 3);
+    assert!(info.garbage_bytes > 0);
+
+    // zero-filling clears it, keeping the allocated capacity
+    let cost = client
+        .write_bytes_to_vault_and_zero_fill(
+            small_content.clone(),
+            wallet.clone().into(),
+            &main_key,
+            content_type,
+        )
+        .await?;
+    assert_eq!(cost, AttoTokens::zero());
+    let info = client.vault_info(&main_key).await?;
+    println!("Vault info after zero-fill: {info:?}");
+    assert_eq!(info.used_bytes, small_content.len() as u64);
+    assert_eq!(info.scratchpads_created, 3);
+    assert_eq!(info.garbage_bytes, 0);
+
+    let (fetched_content, _) = client.fetch_and_decrypt_vault(&main_key).await?;
+    assert_eq!(fetched_content, small_content);
+    Ok(())
+}